@@ -405,8 +405,16 @@ pub struct NativeOptions {
 
     /// The folder where `eframe` will store the app state. If not set, eframe will use a default
     /// data storage path for each target system.
+    ///
+    /// Depending on [`Self::storage_backend`] this is the path of the RON file,
+    /// the directory, or the `SQLite` database.
     pub persistence_path: Option<std::path::PathBuf>,
 
+    /// Where to store the app state (only if the "persistence" feature is enabled).
+    ///
+    /// Default: [`StorageBackend::RonFile`].
+    pub storage_backend: StorageBackend,
+
     /// Controls whether to apply dithering to minimize banding artifacts.
     ///
     /// Dithering assumes an sRGB output and thus will apply noise to any input value that lies between
@@ -444,6 +452,8 @@ impl Clone for NativeOptions {
 
             persistence_path: self.persistence_path.clone(),
 
+            storage_backend: self.storage_backend.clone(),
+
             #[cfg(target_os = "android")]
             android_app: self.android_app.clone(),
 
@@ -487,6 +497,8 @@ impl Default for NativeOptions {
 
             persistence_path: None,
 
+            storage_backend: StorageBackend::default(),
+
             dithering: true,
 
             #[cfg(target_os = "android")]
@@ -497,6 +509,63 @@ impl Default for NativeOptions {
 
 // ----------------------------------------------------------------------------
 
+/// Creates a custom [`Storage`], given the app id.
+///
+/// See [`StorageBackend::Custom`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type StorageCreator = dyn Fn(&str) -> Option<Box<dyn Storage>> + Send + Sync;
+
+/// Where a native `eframe` app stores its state.
+///
+/// Only used if the "persistence" feature is enabled.
+///
+/// See [`NativeOptions::storage_backend`] and [`NativeOptions::persistence_path`].
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Default)]
+pub enum StorageBackend {
+    /// All keys in a single [RON](https://github.com/ron-rs/ron) file, `app.ron`.
+    ///
+    /// This is the default.
+    #[default]
+    RonFile,
+
+    /// Each key in its own file in a directory, `app/`.
+    ///
+    /// Namespaced keys (see [`crate::storage::NamespacedStorage`]) are put in subdirectories.
+    /// See [`crate::DirectoryStorage`].
+    Directory,
+
+    /// An [SQLite](https://sqlite.org) database, `app.sqlite`.
+    ///
+    /// See [`crate::SqliteStorage`].
+    #[cfg(feature = "sqlite")]
+    Sqlite,
+
+    /// Keep everything in memory, i.e. nothing is persisted between runs.
+    ///
+    /// Useful for tests.
+    InMemory,
+
+    /// Your own [`Storage`], created from the app id.
+    Custom(std::sync::Arc<StorageCreator>),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl std::fmt::Debug for StorageBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RonFile => write!(f, "RonFile"),
+            Self::Directory => write!(f, "Directory"),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite => write!(f, "Sqlite"),
+            Self::InMemory => write!(f, "InMemory"),
+            Self::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Options when using `eframe` in a web page.
 #[cfg(target_arch = "wasm32")]
 pub struct WebOptions {
@@ -918,7 +987,10 @@ impl IntegrationInfo {
 /// A place where you can store custom data in a way that persists when you restart the app.
 ///
 /// On the web this is backed by [local storage](https://developer.mozilla.org/en-US/docs/Web/API/Window/localStorage).
-/// On desktop this is backed by the file system.
+/// On desktop this is backed by the file system by default,
+/// but you can pick another backend with [`NativeOptions::storage_backend`].
+///
+/// Keys can be grouped into namespaces with [`crate::storage::NamespacedStorage`].
 ///
 /// See [`CreationContext::storage`] and [`App::save`].
 pub trait Storage {
@@ -928,11 +1000,46 @@ pub trait Storage {
     /// Set the value for the given key.
     fn set_string(&mut self, key: &str, value: String);
 
+    /// Get the binary value for the given key.
+    ///
+    /// The default implementation reads a hex-encoded string written by the default [`Self::set_bytes`].
+    fn get_bytes(&self, key: &str) -> Option<Vec<u8>> {
+        crate::storage::decode_hex(&self.get_string(key)?)
+    }
+
+    /// Set a binary value for the given key.
+    ///
+    /// The default implementation stores the bytes as a hex-encoded string.
+    fn set_bytes(&mut self, key: &str, value: &[u8]) {
+        self.set_string(key, crate::storage::encode_hex(value));
+    }
+
+    /// Remove the value for the given key, if any.
+    ///
+    /// The default implementation does nothing.
+    /// All storages that come with `eframe` implement this.
+    fn remove(&mut self, key: &str) {
+        _ = key;
+    }
+
+    /// All keys that have a value, in no particular order.
+    ///
+    /// The default implementation returns nothing.
+    /// All storages that come with `eframe` implement this.
+    fn keys(&self) -> Vec<String> {
+        Vec::new()
+    }
+
     /// write-to-disk or similar
     fn flush(&mut self);
 }
 
 /// Get and deserialize the [RON](https://github.com/ron-rs/ron) stored at the given key.
+///
+/// Returns `None` (and logs a warning) if the value no longer deserializes,
+/// e.g. because its format changed.
+/// For state stored in [`egui::Memory::data`] you can instead register migrations
+/// with [`egui::util::IdTypeMap::register_migration`].
 #[cfg(feature = "ron")]
 pub fn get_value<T: serde::de::DeserializeOwned>(storage: &dyn Storage, key: &str) -> Option<T> {
     profiling::function_scope!(key);
@@ -941,7 +1048,7 @@ pub fn get_value<T: serde::de::DeserializeOwned>(storage: &dyn Storage, key: &st
         Ok(value) => Some(value),
         Err(err) => {
             // This happens on when we break the format, e.g. when updating egui.
-            log::debug!("Failed to decode RON: {err}");
+            log::warn!("Failed to decode RON of {key:?}: {err}");
             None
         }
     }
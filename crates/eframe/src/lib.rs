@@ -166,6 +166,8 @@ mod epi;
 // Re-export everything in `epi` so `eframe` users don't have to care about what `epi` is:
 pub use epi::*;
 
+pub mod storage;
+
 pub(crate) mod stopwatch;
 
 // ----------------------------------------------------------------------------
@@ -204,7 +206,12 @@ pub use native::run::EframePumpStatus;
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg(any(feature = "glow", feature = "wgpu_no_default_features"))]
 #[cfg(feature = "persistence")]
-pub use native::file_storage::storage_dir;
+pub use native::{directory_storage::DirectoryStorage, file_storage::storage_dir};
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(any(feature = "glow", feature = "wgpu_no_default_features"))]
+#[cfg(feature = "sqlite")]
+pub use native::sqlite_storage::SqliteStorage;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod icon_data;
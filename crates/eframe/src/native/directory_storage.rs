@@ -0,0 +1,199 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+    io::Write as _,
+    path::{Path, PathBuf},
+};
+
+use crate::storage::NAMESPACE_SEPARATOR;
+
+use super::file_storage::write_atomically;
+
+/// A key-value store with one file per key, in a directory on disk.
+///
+/// Namespaced keys (`namespace/key`, see [`crate::storage::NamespacedStorage`])
+/// are stored in subdirectories, so the files of e.g. different windows are kept apart.
+/// This means a key cannot be used both for a value and as a namespace.
+///
+/// Values are stored verbatim, so values written with [`crate::set_value`] are human-readable RON files.
+/// Each file is written atomically on [`crate::Storage::flush`], and only if it changed.
+pub struct DirectoryStorage {
+    dir: PathBuf,
+    kv: BTreeMap<String, Vec<u8>>,
+
+    /// Keys that were changed or removed since the last flush.
+    dirty: BTreeSet<String>,
+}
+
+impl DirectoryStorage {
+    /// Store the state in this directory, creating it if needed.
+    pub fn from_dir(dir: impl Into<PathBuf>) -> Self {
+        profiling::function_scope!();
+        let dir = dir.into();
+        log::debug!("Loading app state from {}…", dir.display());
+        let mut kv = BTreeMap::new();
+        read_dir_recursive(&dir, "", &mut kv);
+        Self {
+            dir,
+            kv,
+            dirty: Default::default(),
+        }
+    }
+
+    /// The directory the files are stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn set(&mut self, key: &str, value: Vec<u8>) {
+        if self.kv.get(key) != Some(&value) {
+            self.kv.insert(key.to_owned(), value);
+            self.dirty.insert(key.to_owned());
+        }
+    }
+}
+
+impl crate::Storage for DirectoryStorage {
+    fn get_string(&self, key: &str) -> Option<String> {
+        String::from_utf8(self.kv.get(key)?.clone()).ok()
+    }
+
+    fn set_string(&mut self, key: &str, value: String) {
+        self.set(key, value.into_bytes());
+    }
+
+    fn get_bytes(&self, key: &str) -> Option<Vec<u8>> {
+        self.kv.get(key).cloned()
+    }
+
+    fn set_bytes(&mut self, key: &str, value: &[u8]) {
+        self.set(key, value.to_vec());
+    }
+
+    fn remove(&mut self, key: &str) {
+        if self.kv.remove(key).is_some() {
+            self.dirty.insert(key.to_owned());
+        }
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.kv.keys().cloned().collect()
+    }
+
+    fn flush(&mut self) {
+        if self.dirty.is_empty() {
+            return;
+        }
+        profiling::scope!("DirectoryStorage::flush");
+
+        for key in std::mem::take(&mut self.dirty) {
+            let path = self.dir.join(key_to_path(&key));
+            if let Some(value) = self.kv.get(&key) {
+                if let Err(err) = write_atomically(&path, |writer| writer.write_all(value)) {
+                    log::warn!("Failed to save {}: {err}", path.display());
+                }
+            } else if let Err(err) = std::fs::remove_file(&path)
+                && err.kind() != std::io::ErrorKind::NotFound
+            {
+                log::warn!("Failed to remove {}: {err}", path.display());
+            }
+        }
+    }
+}
+
+/// Escape each part of a key, so that it is a safe file name.
+///
+/// Only ascii alphanumerics, `-` and `_` are kept as is, everything else is %-encoded.
+/// This also means no part can be `.` or `..`.
+fn key_to_path(key: &str) -> PathBuf {
+    key.split(NAMESPACE_SEPARATOR)
+        .map(|part| {
+            let mut escaped = String::new();
+            for byte in part.bytes() {
+                if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_' {
+                    escaped.push(byte as char);
+                } else {
+                    write!(escaped, "%{byte:02X}").ok();
+                }
+            }
+            escaped
+        })
+        .collect()
+}
+
+fn unescape(part: &str) -> Option<String> {
+    let mut bytes = vec![];
+    let mut rest = part.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_' {
+            bytes.push(byte);
+            rest = tail;
+        } else {
+            return None; // Not escaped by us
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+fn read_dir_recursive(dir: &Path, prefix: &str, kv: &mut BTreeMap<String, Vec<u8>>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return; // Directory probably doesn't exist yet. That's fine.
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(key) = entry.file_name().to_str().and_then(unescape) else {
+            continue; // Leftover temporary file, or something we didn't write.
+        };
+        let key = format!("{prefix}{key}");
+        if path.is_dir() {
+            read_dir_recursive(&path, &format!("{key}{NAMESPACE_SEPARATOR}"), kv);
+        } else {
+            match std::fs::read(&path) {
+                Ok(value) => {
+                    kv.insert(key, value);
+                }
+                Err(err) => log::warn!("Failed to read {}: {err}", path.display()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Storage as _;
+
+    #[test]
+    fn key_escaping() {
+        assert_eq!(key_to_path("a/b c"), PathBuf::from("a").join("b%20c"));
+        assert_eq!(key_to_path("../x"), PathBuf::from("%2E%2E").join("x"));
+        assert_eq!(unescape("b%20c").as_deref(), Some("b c"));
+        assert_eq!(unescape("app.tmp"), None);
+        assert_eq!(unescape("%2"), None);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut storage = DirectoryStorage::from_dir(dir.path());
+        storage.set_string("app", "(x: 1)".to_owned());
+        storage.set_bytes("window 1/icon", &[0, 255]);
+        storage.set_string("gone", String::new());
+        storage.flush();
+        storage.remove("gone");
+        storage.flush();
+
+        assert!(dir.path().join("window%201").join("icon").is_file());
+        assert!(!dir.path().join("gone").exists());
+
+        let storage = DirectoryStorage::from_dir(dir.path());
+        assert_eq!(storage.keys(), vec!["app", "window 1/icon"]);
+        assert_eq!(storage.get_string("app").as_deref(), Some("(x: 1)"));
+        assert_eq!(storage.get_bytes("window 1/icon"), Some(vec![0, 255]));
+    }
+}
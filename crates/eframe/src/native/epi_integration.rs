@@ -2,7 +2,6 @@
 
 use web_time::Instant;
 
-use std::path::PathBuf;
 use winit::event_loop::ActiveEventLoop;
 
 use raw_window_handle::{HasDisplayHandle as _, HasWindowHandle as _};
@@ -127,21 +126,75 @@ fn largest_monitor_point_size(egui_zoom_factor: f32, event_loop: &ActiveEventLoo
 // ----------------------------------------------------------------------------
 
 /// For loading/saving app state and/or egui memory to disk.
-pub fn create_storage(_app_name: &str) -> Option<Box<dyn epi::Storage>> {
-    #[cfg(feature = "persistence")]
-    if let Some(storage) = super::file_storage::FileStorage::from_app_id(_app_name) {
-        return Some(Box::new(storage));
+///
+/// Uses [`epi::NativeOptions::storage_backend`], stored at [`epi::NativeOptions::persistence_path`]
+/// or in the [`crate::storage_dir`] of the app.
+#[cfg(feature = "persistence")]
+pub fn create_storage(
+    native_options: &epi::NativeOptions,
+    app_name: &str,
+) -> Option<Box<dyn epi::Storage>> {
+    use epi::StorageBackend;
+
+    let app_id = native_options
+        .viewport
+        .app_id
+        .as_deref()
+        .unwrap_or(app_name);
+
+    // The default name of the file or directory in the storage dir:
+    let file_name = match &native_options.storage_backend {
+        StorageBackend::RonFile => "app.ron",
+        StorageBackend::Directory => "app",
+        #[cfg(feature = "sqlite")]
+        StorageBackend::Sqlite => "app.sqlite",
+        StorageBackend::InMemory => {
+            return Some(Box::new(crate::storage::MemoryStorage::default()));
+        }
+        StorageBackend::Custom(create) => return create(app_id),
+    };
+
+    let path = if let Some(path) = &native_options.persistence_path {
+        path.clone()
+    } else {
+        let Some(data_dir) = super::file_storage::storage_dir(app_id) else {
+            log::warn!("Saving disabled: Failed to find path to data_dir.");
+            return None;
+        };
+        if let Err(err) = std::fs::create_dir_all(&data_dir) {
+            log::warn!(
+                "Saving disabled: Failed to create app path at {}: {err}",
+                data_dir.display()
+            );
+            return None;
+        }
+        data_dir.join(file_name)
+    };
+
+    match &native_options.storage_backend {
+        StorageBackend::RonFile => Some(Box::new(
+            super::file_storage::FileStorage::from_ron_filepath(path),
+        )),
+        StorageBackend::Directory => Some(Box::new(
+            super::directory_storage::DirectoryStorage::from_dir(path),
+        )),
+        #[cfg(feature = "sqlite")]
+        StorageBackend::Sqlite => match super::sqlite_storage::SqliteStorage::open(&path) {
+            Ok(storage) => Some(Box::new(storage)),
+            Err(err) => {
+                log::warn!("Saving disabled: Failed to open {}: {err}", path.display());
+                None
+            }
+        },
+        StorageBackend::InMemory | StorageBackend::Custom(_) => unreachable!("handled above"),
     }
-    None
 }
 
-#[allow(clippy::allow_attributes, clippy::unnecessary_wraps)]
-pub fn create_storage_with_file(_file: impl Into<PathBuf>) -> Option<Box<dyn epi::Storage>> {
-    #[cfg(feature = "persistence")]
-    return Some(Box::new(
-        super::file_storage::FileStorage::from_ron_filepath(_file),
-    ));
-    #[cfg(not(feature = "persistence"))]
+#[cfg(not(feature = "persistence"))]
+pub fn create_storage(
+    _native_options: &epi::NativeOptions,
+    _app_name: &str,
+) -> Option<Box<dyn epi::Storage>> {
     None
 }
 
@@ -405,7 +458,10 @@ pub fn load_egui_memory(_storage: Option<&dyn epi::Storage>) -> Option<egui::Mem
     profiling::function_scope!();
     #[cfg(feature = "persistence")]
     {
-        epi::get_value(_storage?, STORAGE_EGUI_MEMORY_KEY)
+        // Keeps whatever it can if the format has changed, e.g. after an egui update.
+        // Whatever was discarded is reported in `Memory::data`.
+        let ron = _storage?.get_string(STORAGE_EGUI_MEMORY_KEY)?;
+        Some(egui::Memory::from_ron_lenient(&ron))
     }
     #[cfg(not(feature = "persistence"))]
     None
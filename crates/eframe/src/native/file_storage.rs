@@ -126,25 +126,6 @@ impl FileStorage {
             last_save_join_handle: None,
         }
     }
-
-    /// Find a good place to put the files that the OS likes.
-    pub fn from_app_id(app_id: &str) -> Option<Self> {
-        profiling::function_scope!();
-        if let Some(data_dir) = storage_dir(app_id) {
-            if let Err(err) = std::fs::create_dir_all(&data_dir) {
-                log::warn!(
-                    "Saving disabled: Failed to create app path at {}: {err}",
-                    data_dir.display()
-                );
-                None
-            } else {
-                Some(Self::from_ron_filepath(data_dir.join("app.ron")))
-            }
-        } else {
-            log::warn!("Saving disabled: Failed to find path to data_dir.");
-            None
-        }
-    }
 }
 
 impl crate::Storage for FileStorage {
@@ -159,6 +140,16 @@ impl crate::Storage for FileStorage {
         }
     }
 
+    fn remove(&mut self, key: &str) {
+        if self.kv.remove(key).is_some() {
+            self.dirty = true;
+        }
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.kv.keys().cloned().collect()
+    }
+
     fn flush(&mut self) {
         if self.dirty {
             profiling::scope!("FileStorage::flush");
@@ -189,33 +180,53 @@ impl crate::Storage for FileStorage {
     }
 }
 
-fn save_to_disk(file_path: &PathBuf, kv: &HashMap<String, String>) {
+fn save_to_disk(file_path: &Path, kv: &HashMap<String, String>) {
     profiling::function_scope!();
 
+    let result = write_atomically(file_path, |writer| {
+        let config = Default::default();
+
+        profiling::scope!("ron::serialize");
+        ron::Options::default()
+            .to_io_writer_pretty(&mut *writer, &kv, config)
+            .map_err(std::io::Error::other)
+    });
+
+    match result {
+        Ok(()) => log::trace!("Persisted to {}", file_path.display()),
+        Err(err) => log::warn!("Failed to save app state to {}: {err}", file_path.display()),
+    }
+}
+
+/// Write a file by first writing to a temporary file next to it, and then renaming it.
+///
+/// This means that the file will never be left half-written, e.g. if the app crashes while saving.
+pub(crate) fn write_atomically(
+    file_path: &Path,
+    write: impl FnOnce(&mut std::io::BufWriter<std::fs::File>) -> std::io::Result<()>,
+) -> std::io::Result<()> {
     if let Some(parent_dir) = file_path.parent()
         && !parent_dir.exists()
-        && let Err(err) = std::fs::create_dir_all(parent_dir)
     {
-        log::warn!("Failed to create directory {}: {err}", parent_dir.display());
+        std::fs::create_dir_all(parent_dir)?;
     }
 
-    match std::fs::File::create(file_path) {
-        Ok(file) => {
-            let mut writer = std::io::BufWriter::new(file);
-            let config = Default::default();
-
-            profiling::scope!("ron::serialize");
-            if let Err(err) = ron::Options::default()
-                .to_io_writer_pretty(&mut writer, &kv, config)
-                .and_then(|()| writer.flush().map_err(|err| err.into()))
-            {
-                log::warn!("Failed to serialize app state: {err}");
-            } else {
-                log::trace!("Persisted to {}", file_path.display());
-            }
-        }
+    let mut tmp_path = file_path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+
+    let result = std::fs::File::create(&tmp_path).and_then(|file| {
+        let mut writer = std::io::BufWriter::new(file);
+        write(&mut writer)?;
+        writer.flush()?;
+        writer.get_ref().sync_all()
+    });
+
+    match result {
+        Ok(()) => std::fs::rename(&tmp_path, file_path),
         Err(err) => {
-            log::warn!("Failed to create file {}: {err}", file_path.display());
+            std::fs::remove_file(&tmp_path).ok();
+            Err(err)
         }
     }
 }
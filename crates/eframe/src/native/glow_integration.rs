@@ -197,17 +197,7 @@ impl<'app> GlowWinitApp<'app> {
     ) -> Result<&mut GlowWinitRunning<'app>> {
         profiling::function_scope!();
 
-        let storage = if let Some(file) = &self.native_options.persistence_path {
-            epi_integration::create_storage_with_file(file)
-        } else {
-            epi_integration::create_storage(
-                self.native_options
-                    .viewport
-                    .app_id
-                    .as_ref()
-                    .unwrap_or(&self.app_name),
-            )
-        };
+        let storage = epi_integration::create_storage(&self.native_options, &self.app_name);
 
         let egui_ctx = create_egui_context(storage.as_deref());
 
@@ -404,7 +394,9 @@ impl WinitApp for GlowWinitApp<'_> {
         window_id: WindowId,
     ) -> Result<EventResult> {
         if let Some(running) = &mut self.running {
-            running.run_ui_and_paint(event_loop, window_id)
+            let result = running.run_ui_and_paint(event_loop, window_id);
+            running.create_custom_cursors(event_loop);
+            result
         } else {
             Ok(EventResult::Wait)
         }
@@ -507,6 +499,20 @@ impl WinitApp for GlowWinitApp<'_> {
 }
 
 impl GlowWinitRunning<'_> {
+    /// See [`egui_winit::State::create_custom_cursor`].
+    fn create_custom_cursors(&self, event_loop: &ActiveEventLoop) {
+        for viewport in self.glutin.borrow_mut().viewports.values_mut() {
+            if let Viewport {
+                window: Some(window),
+                egui_winit: Some(egui_winit),
+                ..
+            } = viewport
+            {
+                egui_winit.create_custom_cursor(event_loop, window);
+            }
+        }
+    }
+
     fn run_ui_and_paint(
         &mut self,
         event_loop: &ActiveEventLoop,
@@ -716,15 +722,7 @@ impl GlowWinitRunning<'_> {
                             egui_winit.egui_input_mut().events.push(egui::Event::Copy);
                         }
                         ActionRequested::Paste => {
-                            if let Some(contents) = egui_winit.clipboard_text() {
-                                let contents = contents.replace("\r\n", "\n");
-                                if !contents.is_empty() {
-                                    egui_winit
-                                        .egui_input_mut()
-                                        .events
-                                        .push(egui::Event::Paste(contents));
-                                }
-                            }
+                            egui_winit.paste();
                         }
                     }
                 }
@@ -10,6 +10,14 @@ pub(crate) mod macos;
 #[cfg(feature = "persistence")]
 pub mod file_storage;
 
+/// Storage with one file per key, which can be used by native backends.
+#[cfg(feature = "persistence")]
+pub mod directory_storage;
+
+/// `SQLite` storage which can be used by native backends.
+#[cfg(feature = "sqlite")]
+pub mod sqlite_storage;
+
 pub(crate) mod winit_integration;
 
 #[cfg(feature = "glow")]
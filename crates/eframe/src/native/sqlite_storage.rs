@@ -0,0 +1,179 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use rusqlite::{Connection, OptionalExtension as _, params};
+
+/// A key-value store backed by an [SQLite](https://sqlite.org) database on disk.
+///
+/// Changes are kept in memory until [`crate::Storage::flush`],
+/// when they are all written in a single transaction.
+///
+/// Since `SQLite` handles concurrent access, several windows or processes can share the same database.
+/// Each read goes to the database, so you see what others have flushed.
+pub struct SqliteStorage {
+    db_path: PathBuf,
+    connection: Connection,
+
+    /// Changes since the last flush. `None` means removed.
+    pending: BTreeMap<String, Option<Vec<u8>>>,
+}
+
+impl SqliteStorage {
+    /// Open (or create) the database at the given path.
+    ///
+    /// # Errors
+    /// If the database cannot be opened or the table cannot be created.
+    pub fn open(db_path: impl Into<PathBuf>) -> rusqlite::Result<Self> {
+        profiling::function_scope!();
+        let db_path = db_path.into();
+        log::debug!("Loading app state from {}…", db_path.display());
+
+        if let Some(parent_dir) = db_path.parent()
+            && !parent_dir.exists()
+            && let Err(err) = std::fs::create_dir_all(parent_dir)
+        {
+            log::warn!("Failed to create directory {}: {err}", parent_dir.display());
+        }
+
+        let connection = Connection::open(&db_path)?;
+        connection.execute(
+            "CREATE TABLE IF NOT EXISTS eframe_storage (key TEXT PRIMARY KEY, value BLOB NOT NULL)",
+            (),
+        )?;
+
+        Ok(Self {
+            db_path,
+            connection,
+            pending: Default::default(),
+        })
+    }
+
+    /// Where the database is stored.
+    pub fn db_path(&self) -> &std::path::Path {
+        &self.db_path
+    }
+
+    fn write_pending(&mut self) -> rusqlite::Result<()> {
+        let transaction = self.connection.transaction()?;
+        for (key, value) in &self.pending {
+            if let Some(value) = value {
+                transaction.execute(
+                    "INSERT OR REPLACE INTO eframe_storage (key, value) VALUES (?1, ?2)",
+                    params![key, value],
+                )?;
+            } else {
+                transaction.execute("DELETE FROM eframe_storage WHERE key = ?1", params![key])?;
+            }
+        }
+        transaction.commit()
+    }
+}
+
+impl crate::Storage for SqliteStorage {
+    fn get_string(&self, key: &str) -> Option<String> {
+        String::from_utf8(self.get_bytes(key)?).ok()
+    }
+
+    fn set_string(&mut self, key: &str, value: String) {
+        self.pending
+            .insert(key.to_owned(), Some(value.into_bytes()));
+    }
+
+    fn get_bytes(&self, key: &str) -> Option<Vec<u8>> {
+        if let Some(value) = self.pending.get(key) {
+            return value.clone();
+        }
+        self.connection
+            .query_row(
+                "SELECT value FROM eframe_storage WHERE key = ?1",
+                params![key],
+                |row| row.get(0),
+            )
+            .optional()
+            .unwrap_or_else(|err| {
+                log::warn!(
+                    "Failed to read {key:?} from {}: {err}",
+                    self.db_path.display()
+                );
+                None
+            })
+    }
+
+    fn set_bytes(&mut self, key: &str, value: &[u8]) {
+        self.pending.insert(key.to_owned(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.pending.insert(key.to_owned(), None);
+    }
+
+    fn keys(&self) -> Vec<String> {
+        let stored = self
+            .connection
+            .prepare("SELECT key FROM eframe_storage")
+            .and_then(|mut statement| {
+                statement
+                    .query_map((), |row| row.get::<_, String>(0))?
+                    .collect::<rusqlite::Result<Vec<_>>>()
+            })
+            .unwrap_or_else(|err| {
+                log::warn!("Failed to list keys of {}: {err}", self.db_path.display());
+                vec![]
+            });
+
+        let mut keys: std::collections::BTreeSet<String> = stored.into_iter().collect();
+        for (key, value) in &self.pending {
+            if value.is_some() {
+                keys.insert(key.clone());
+            } else {
+                keys.remove(key);
+            }
+        }
+        keys.into_iter().collect()
+    }
+
+    fn flush(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+        profiling::scope!("SqliteStorage::flush");
+
+        match self.write_pending() {
+            Ok(()) => {
+                self.pending.clear();
+                log::trace!("Persisted to {}", self.db_path.display());
+            }
+            Err(err) => {
+                log::warn!(
+                    "Failed to save app state to {}: {err}",
+                    self.db_path.display()
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Storage as _;
+
+    #[test]
+    fn roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("app.sqlite");
+
+        let mut storage = SqliteStorage::open(&db_path).unwrap();
+        storage.set_string("app", "(x: 1)".to_owned());
+        storage.set_bytes("window/icon", &[0, 255]);
+        storage.set_string("gone", String::new());
+        storage.flush();
+        storage.remove("gone");
+        assert_eq!(storage.keys(), vec!["app", "window/icon"]);
+        storage.flush();
+
+        let other = SqliteStorage::open(&db_path).unwrap();
+        assert_eq!(other.keys(), vec!["app", "window/icon"]);
+        assert_eq!(other.get_string("app").as_deref(), Some("(x: 1)"));
+        assert_eq!(other.get_bytes("window/icon"), Some(vec![0, 255]));
+    }
+}
@@ -403,7 +403,9 @@ impl WinitApp for WgpuWinitApp<'_> {
         self.initialized_all_windows(event_loop);
 
         if let Some(running) = &mut self.running {
-            running.run_ui_and_paint(window_id)
+            let result = running.run_ui_and_paint(window_id);
+            running.create_custom_cursors(event_loop);
+            result
         } else {
             Ok(EventResult::Wait)
         }
@@ -417,17 +419,7 @@ impl WinitApp for WgpuWinitApp<'_> {
             self.recreate_window(event_loop, running);
             running
         } else {
-            let storage = if let Some(file) = &self.native_options.persistence_path {
-                epi_integration::create_storage_with_file(file)
-            } else {
-                epi_integration::create_storage(
-                    self.native_options
-                        .viewport
-                        .app_id
-                        .as_ref()
-                        .unwrap_or(&self.app_name),
-                )
-            };
+            let storage = epi_integration::create_storage(&self.native_options, &self.app_name);
             let egui_ctx = winit_integration::create_egui_context(storage.as_deref());
             let (window, builder) = create_window(
                 &egui_ctx,
@@ -531,6 +523,20 @@ impl WinitApp for WgpuWinitApp<'_> {
 }
 
 impl WgpuWinitRunning<'_> {
+    /// See [`egui_winit::State::create_custom_cursor`].
+    fn create_custom_cursors(&self, event_loop: &ActiveEventLoop) {
+        for viewport in self.shared.borrow_mut().viewports.values_mut() {
+            if let Viewport {
+                window: Some(window),
+                egui_winit: Some(egui_winit),
+                ..
+            } = viewport
+            {
+                egui_winit.create_custom_cursor(event_loop, window);
+            }
+        }
+    }
+
     /// Saves the application state
     fn save(&mut self) {
         let shared = self.shared.borrow();
@@ -736,15 +742,7 @@ impl WgpuWinitRunning<'_> {
                         egui_winit.egui_input_mut().events.push(egui::Event::Copy);
                     }
                     ActionRequested::Paste => {
-                        if let Some(contents) = egui_winit.clipboard_text() {
-                            let contents = contents.replace("\r\n", "\n");
-                            if !contents.is_empty() {
-                                egui_winit
-                                    .egui_input_mut()
-                                    .events
-                                    .push(egui::Event::Paste(contents));
-                            }
-                        }
+                        egui_winit.paste();
                     }
                 }
             }
@@ -0,0 +1,200 @@
+//! Helpers for working with [`Storage`].
+//!
+//! The storage backends that persist to disk are only available on native,
+//! see [`crate::StorageBackend`].
+
+use std::collections::BTreeMap;
+
+use crate::Storage;
+
+/// Separates the namespace from the key in [`NamespacedStorage`].
+pub const NAMESPACE_SEPARATOR: char = '/';
+
+// ----------------------------------------------------------------------------
+
+/// A [`Storage`] that only lives in memory.
+///
+/// Nothing is persisted, which makes it useful for tests.
+///
+/// ```
+/// use eframe::{Storage as _, storage::MemoryStorage};
+///
+/// let mut storage = MemoryStorage::default();
+/// storage.set_string("key", "value".to_owned());
+/// assert_eq!(storage.get_string("key").as_deref(), Some("value"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage {
+    kv: BTreeMap<String, Vec<u8>>,
+}
+
+impl Storage for MemoryStorage {
+    fn get_string(&self, key: &str) -> Option<String> {
+        String::from_utf8(self.kv.get(key)?.clone()).ok()
+    }
+
+    fn set_string(&mut self, key: &str, value: String) {
+        self.kv.insert(key.to_owned(), value.into_bytes());
+    }
+
+    fn get_bytes(&self, key: &str) -> Option<Vec<u8>> {
+        self.kv.get(key).cloned()
+    }
+
+    fn set_bytes(&mut self, key: &str, value: &[u8]) {
+        self.kv.insert(key.to_owned(), value.to_vec());
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.kv.remove(key);
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.kv.keys().cloned().collect()
+    }
+
+    fn flush(&mut self) {}
+}
+
+// ----------------------------------------------------------------------------
+
+/// Gives access to the keys of another [`Storage`] that are in a namespace.
+///
+/// A key `key` in namespace `namespace` is stored as `namespace/key` in the underlying storage.
+/// Namespaces can be nested.
+///
+/// This lets different parts of an app (e.g. different windows) share one storage
+/// without stepping on each other's keys.
+///
+/// ```
+/// use eframe::{Storage as _, storage::{MemoryStorage, NamespacedStorage}};
+///
+/// let mut storage = MemoryStorage::default();
+/// {
+///     let mut settings = NamespacedStorage::new(&mut storage, "settings");
+///     settings.set_string("theme", "dark".to_owned());
+///     assert_eq!(settings.keys(), vec!["theme".to_owned()]);
+/// }
+/// assert_eq!(storage.get_string("settings/theme").as_deref(), Some("dark"));
+/// ```
+pub struct NamespacedStorage<'a> {
+    storage: &'a mut dyn Storage,
+    prefix: String,
+}
+
+impl<'a> NamespacedStorage<'a> {
+    /// Access the keys of `storage` in the given namespace.
+    pub fn new(storage: &'a mut dyn Storage, namespace: &str) -> Self {
+        Self {
+            storage,
+            prefix: format!("{namespace}{NAMESPACE_SEPARATOR}"),
+        }
+    }
+
+    /// The namespace, including the trailing [`NAMESPACE_SEPARATOR`].
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    fn full_key(&self, key: &str) -> String {
+        format!("{}{key}", self.prefix)
+    }
+}
+
+impl Storage for NamespacedStorage<'_> {
+    fn get_string(&self, key: &str) -> Option<String> {
+        self.storage.get_string(&self.full_key(key))
+    }
+
+    fn set_string(&mut self, key: &str, value: String) {
+        let key = self.full_key(key);
+        self.storage.set_string(&key, value);
+    }
+
+    fn get_bytes(&self, key: &str) -> Option<Vec<u8>> {
+        self.storage.get_bytes(&self.full_key(key))
+    }
+
+    fn set_bytes(&mut self, key: &str, value: &[u8]) {
+        let key = self.full_key(key);
+        self.storage.set_bytes(&key, value);
+    }
+
+    fn remove(&mut self, key: &str) {
+        let key = self.full_key(key);
+        self.storage.remove(&key);
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.storage
+            .keys()
+            .into_iter()
+            .filter_map(|key| key.strip_prefix(&self.prefix).map(ToOwned::to_owned))
+            .collect()
+    }
+
+    fn flush(&mut self) {
+        self.storage.flush();
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    use std::fmt::Write as _;
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        write!(hex, "{byte:02x}").ok();
+        hex
+    })
+}
+
+pub(crate) fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.is_ascii() || !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_roundtrip() {
+        let bytes = [0, 1, 0x7f, 0x80, 0xff];
+        assert_eq!(encode_hex(&bytes), "00017f80ff");
+        assert_eq!(decode_hex(&encode_hex(&bytes)), Some(bytes.to_vec()));
+        assert_eq!(decode_hex("0"), None);
+        assert_eq!(decode_hex("zz"), None);
+    }
+
+    #[test]
+    fn namespaces() {
+        let mut storage = MemoryStorage::default();
+        storage.set_string("a", "root".to_owned());
+
+        let mut window = NamespacedStorage::new(&mut storage, "window");
+        window.set_string("a", "window".to_owned());
+        window.set_bytes("b", &[1, 2, 3]);
+
+        let mut nested = NamespacedStorage::new(&mut window, "nested");
+        nested.set_string("a", "nested".to_owned());
+        assert_eq!(nested.keys(), vec!["a".to_owned()]);
+
+        let mut keys = window.keys();
+        keys.sort();
+        assert_eq!(keys, vec!["a", "b", "nested/a"]);
+        assert_eq!(window.get_bytes("b"), Some(vec![1, 2, 3]));
+        window.remove("a");
+        assert_eq!(window.get_string("a"), None);
+
+        assert_eq!(storage.get_string("a").as_deref(), Some("root"));
+        assert_eq!(
+            storage.get_string("window/nested/a").as_deref(),
+            Some("nested")
+        );
+    }
+}
@@ -25,6 +25,9 @@ pub struct AppRunner {
     // Output for the last run:
     textures_delta: TexturesDelta,
     clipped_primitives: Option<Vec<egui::ClippedPrimitive>>,
+
+    /// CSS `cursor` values for [`egui::CursorIcon::Custom`], and the images they were created from.
+    custom_cursors: egui::IdMap<(Arc<egui::CursorImage>, String)>,
 }
 
 impl Drop for AppRunner {
@@ -161,6 +164,7 @@ impl AppRunner {
             screenshot_commands_with_frame_delay: vec![],
             textures_delta: Default::default(),
             clipped_primitives: None,
+            custom_cursors: Default::default(),
         };
 
         runner.input.raw.max_texture_side = Some(runner.painter.max_texture_side());
@@ -362,15 +366,32 @@ impl AppRunner {
         self.frame.info.cpu_usage = Some(cpu_usage_seconds);
     }
 
-    fn handle_platform_output(&self, platform_output: egui::PlatformOutput) {
+    fn custom_cursor_css(&mut self, id: egui::Id, image: Arc<egui::CursorImage>) -> String {
+        if let Some((created_from, css)) = self.custom_cursors.get(&id)
+            && (Arc::ptr_eq(created_from, &image) || *created_from == image)
+        {
+            return css.clone();
+        }
+
+        let css = super::custom_cursor_css(&image).unwrap_or_else(|err| {
+            log::warn!("Failed to create custom cursor: {err}");
+            super::cursor_web_name(image.fallback).to_owned()
+        });
+        self.custom_cursors.insert(id, (image, css.clone()));
+        css
+    }
+
+    fn handle_platform_output(&mut self, platform_output: egui::PlatformOutput) {
         #[cfg(feature = "web_screen_reader")]
         if self.egui_ctx.options(|o| o.screen_reader) {
-            super::screen_reader::speak(&platform_output.events_description());
+            let description = platform_output.events_description(&self.egui_ctx);
+            super::screen_reader::speak(&description);
         }
 
         let egui::PlatformOutput {
             commands,
             cursor_icon,
+            cursor_image,
             events: _,                    // already handled
             mutable_text_under_cursor: _, // TODO(#4569): https://github.com/emilk/egui/issues/4569
             ime,
@@ -387,13 +408,26 @@ impl AppRunner {
                 egui::OutputCommand::CopyImage(image) => {
                     super::set_clipboard_image(&image);
                 }
+                egui::OutputCommand::CopyRich { text, html } => {
+                    super::set_clipboard_data(
+                        &egui::ClipboardData::from_text(text).with_html(html),
+                    );
+                }
+                egui::OutputCommand::CopyData(data) => {
+                    super::set_clipboard_data(&data);
+                }
                 egui::OutputCommand::OpenUrl(open_url) => {
                     super::open_url(&open_url.url, open_url.new_tab);
                 }
             }
         }
 
-        super::set_cursor_icon(self.canvas(), cursor_icon);
+        if let (egui::CursorIcon::Custom(id), Some(image)) = (cursor_icon, cursor_image) {
+            let css = self.custom_cursor_css(id, image);
+            super::set_cursor_css(self.canvas(), &css);
+        } else {
+            super::set_cursor_icon(self.canvas(), cursor_icon);
+        }
 
         if self.has_focus() {
             // The eframe app has focus.
@@ -433,5 +467,13 @@ impl epi::Storage for LocalStorage {
         super::storage::local_storage_set(key, &value);
     }
 
+    fn remove(&mut self, key: &str) {
+        super::storage::local_storage_remove(key);
+    }
+
+    fn keys(&self) -> Vec<String> {
+        super::storage::local_storage_keys()
+    }
+
     fn flush(&mut self) {}
 }
@@ -315,33 +315,56 @@ pub(crate) fn on_keyup(event: web_sys::KeyboardEvent, runner: &mut AppRunner) {
 }
 
 fn install_copy_cut_paste(runner_ref: &WebRunner, target: &EventTarget) -> Result<(), JsValue> {
-    runner_ref.add_event_listener(target, "paste", |event: web_sys::ClipboardEvent, runner| {
-        if !runner.input.raw.focused {
-            return; // The eframe app is not interested
-        }
-
-        if let Some(data) = event.clipboard_data()
-            && let Ok(text) = data.get_data("text")
-        {
-            let text = text.replace("\r\n", "\n");
+    runner_ref.add_event_listener(target, "paste", {
+        let runner_ref = runner_ref.clone();
 
-            let mut should_stop_propagation = true;
-            let mut should_prevent_default = true;
-            if !text.is_empty() {
-                let egui_event = egui::Event::Paste(text);
-                should_stop_propagation = (runner.web_options.should_stop_propagation)(&egui_event);
-                should_prevent_default = (runner.web_options.should_prevent_default)(&egui_event);
-                runner.input.raw.events.push(egui_event);
-                runner.needs_repaint.repaint_asap();
+        move |event: web_sys::ClipboardEvent, runner| {
+            if !runner.input.raw.focused {
+                return; // The eframe app is not interested
             }
 
-            // Use web options to tell if the web event should be propagated to parent elements based on the egui event.
-            if should_stop_propagation {
-                event.stop_propagation();
-            }
+            if let Some(data) = event.clipboard_data()
+                && let Ok(text) = data.get_data("text")
+            {
+                let text = text.replace("\r\n", "\n");
+
+                let mut should_stop_propagation = true;
+                let mut should_prevent_default = true;
+                if !text.is_empty() {
+                    let egui_event = egui::Event::Paste(text);
+                    should_stop_propagation =
+                        (runner.web_options.should_stop_propagation)(&egui_event);
+                    should_prevent_default =
+                        (runner.web_options.should_prevent_default)(&egui_event);
+                    runner.input.raw.events.push(egui_event);
+                    runner.needs_repaint.repaint_asap();
+                }
 
-            if should_prevent_default {
-                event.prevent_default();
+                if let Ok(html) = data.get_data("text/html")
+                    && !html.is_empty()
+                {
+                    runner.input.raw.events.push(egui::Event::PasteData {
+                        mime: "text/html".to_owned(),
+                        bytes: html.into_bytes().into(),
+                    });
+                }
+
+                if let Some(files) = data.files() {
+                    for i in 0..files.length() {
+                        if let Some(file) = files.get(i) {
+                            read_pasted_file(&runner_ref, &file);
+                        }
+                    }
+                }
+
+                // Use web options to tell if the web event should be propagated to parent elements based on the egui event.
+                if should_stop_propagation {
+                    event.stop_propagation();
+                }
+
+                if should_prevent_default {
+                    event.prevent_default();
+                }
             }
         }
     })?;
@@ -1014,6 +1037,48 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
     Ok(())
 }
 
+/// Read a pasted file, e.g. an image, and send it as [`egui::Event::PasteImage`] if we can decode
+/// it, or else as [`egui::Event::PasteData`].
+fn read_pasted_file(runner_ref: &WebRunner, file: &web_sys::File) {
+    let mime = file.type_();
+    let future = wasm_bindgen_futures::JsFuture::from(file.array_buffer());
+
+    let runner_ref = runner_ref.clone();
+    let future = async move {
+        match future.await {
+            Ok(array_buffer) => {
+                let bytes = js_sys::Uint8Array::new(&array_buffer).to_vec();
+                let event = if mime.starts_with("image/")
+                    && let Ok(image) = image::load_from_memory(&bytes)
+                {
+                    let image = image.to_rgba8();
+                    egui::Event::PasteImage(
+                        egui::ColorImage::from_rgba_unmultiplied(
+                            [image.width() as usize, image.height() as usize],
+                            image.as_raw(),
+                        )
+                        .into(),
+                    )
+                } else {
+                    egui::Event::PasteData {
+                        mime,
+                        bytes: bytes.into(),
+                    }
+                };
+
+                if let Some(mut runner_lock) = runner_ref.try_lock() {
+                    runner_lock.input.raw.events.push(event);
+                    runner_lock.needs_repaint.repaint_asap();
+                }
+            }
+            Err(err) => {
+                log::error!("Failed to read pasted file: {}", string_from_js_value(&err));
+            }
+        }
+    };
+    wasm_bindgen_futures::spawn_local(future);
+}
+
 /// A `ResizeObserver` is used to observe changes to the size of the canvas.
 ///
 /// The resize observer is called the by the browser at `observe` time, instead of just on the first actual resize.
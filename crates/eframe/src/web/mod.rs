@@ -179,10 +179,22 @@ fn canvas_size_in_points(canvas: &web_sys::HtmlCanvasElement, ctx: &egui::Contex
 
 /// Set the cursor icon.
 fn set_cursor_icon(canvas: &web_sys::HtmlCanvasElement, cursor: egui::CursorIcon) -> Option<()> {
-    canvas
-        .style()
-        .set_property("cursor", cursor_web_name(cursor))
-        .ok()
+    set_cursor_css(canvas, cursor_web_name(cursor))
+}
+
+fn set_cursor_css(canvas: &web_sys::HtmlCanvasElement, cursor: &str) -> Option<()> {
+    canvas.style().set_property("cursor", cursor).ok()
+}
+
+/// A CSS `cursor` value showing the image, or [`egui::CursorImage::fallback`] if the browser can't.
+fn custom_cursor_css(cursor: &egui::CursorImage) -> Result<String, String> {
+    let png_bytes = to_image(&cursor.image).and_then(|image| to_png_bytes(&image))?;
+    let data = percent_encoding::percent_encode(&png_bytes, percent_encoding::NON_ALPHANUMERIC);
+    let [x, y] = cursor.hotspot;
+    Ok(format!(
+        "url(\"data:image/png,{data}\") {x} {y}, {}",
+        cursor_web_name(cursor.fallback)
+    ))
 }
 
 /// Set the clipboard text.
@@ -228,7 +240,7 @@ fn set_clipboard_image(image: &egui::ColorImage) {
 
         let mime = "image/png";
 
-        let item = match create_clipboard_item(mime, &png_bytes) {
+        let item = match create_clipboard_item(&[(mime, &png_bytes)]) {
             Ok(item) => item,
             Err(err) => {
                 log::error!("Failed to copy image: {}", string_from_js_value(&err));
@@ -250,6 +262,58 @@ fn set_clipboard_image(image: &egui::ColorImage) {
     }
 }
 
+/// Set the clipboard data, in all the representations the browser supports.
+fn set_clipboard_data(data: &egui::ClipboardData) {
+    if let Some(window) = web_sys::window() {
+        if !window.is_secure_context() {
+            log::error!(
+                "Clipboard is not available because we are not in a secure context. \
+                See https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts"
+            );
+            return;
+        }
+
+        let png_bytes = data.image.as_ref().and_then(|image| {
+            to_image(image)
+                .and_then(|image| to_png_bytes(&image))
+                .map_err(|err| log::error!("Failed to encode image to png: {err}"))
+                .ok()
+        });
+
+        let mut representations: Vec<(&str, &[u8])> = Vec::new();
+        if let Some(text) = &data.text {
+            representations.push(("text/plain", text.as_bytes()));
+        }
+        if let Some(html) = &data.html {
+            representations.push(("text/html", html.as_bytes()));
+        }
+        if let Some(png_bytes) = &png_bytes {
+            representations.push(("image/png", png_bytes));
+        }
+        if !data.data.is_empty() {
+            // Browsers refuse to write the whole item if it has a type they don't support.
+            log::debug!("Custom clipboard formats are not supported on web");
+        }
+
+        let item = match create_clipboard_item(&representations) {
+            Ok(item) => item,
+            Err(err) => {
+                log::error!("Failed to copy: {}", string_from_js_value(&err));
+                return;
+            }
+        };
+        let items = js_sys::Array::of1(&item);
+        let promise = window.navigator().clipboard().write(&items);
+        let future = wasm_bindgen_futures::JsFuture::from(promise);
+        let future = async move {
+            if let Err(err) = future.await {
+                log::error!("Copy/cut action failed: {}", string_from_js_value(&err));
+            }
+        };
+        wasm_bindgen_futures::spawn_local(future);
+    }
+}
+
 fn to_image(image: &egui::ColorImage) -> Result<image::RgbaImage, String> {
     profiling::function_scope!();
     image::RgbaImage::from_raw(
@@ -272,23 +336,28 @@ fn to_png_bytes(image: &image::RgbaImage) -> Result<Vec<u8>, String> {
     Ok(png_bytes)
 }
 
-fn create_clipboard_item(mime: &str, bytes: &[u8]) -> Result<web_sys::ClipboardItem, JsValue> {
-    let array = js_sys::Uint8Array::from(bytes);
-    let blob_parts = js_sys::Array::new();
-    blob_parts.push(&array);
+/// A clipboard item with one blob per (mime type, bytes) representation.
+fn create_clipboard_item(
+    representations: &[(&str, &[u8])],
+) -> Result<web_sys::ClipboardItem, JsValue> {
+    let items = js_sys::Object::new();
 
-    let options = web_sys::BlobPropertyBag::new();
-    options.set_type(mime);
+    for &(mime, bytes) in representations {
+        let array = js_sys::Uint8Array::from(bytes);
+        let blob_parts = js_sys::Array::new();
+        blob_parts.push(&array);
 
-    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&blob_parts, &options)?;
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type(mime);
 
-    let items = js_sys::Object::new();
+        let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&blob_parts, &options)?;
 
-    #[expect(unsafe_code, unused_unsafe)] // Weird false positive
-    // SAFETY: I hope so
-    unsafe {
-        js_sys::Reflect::set(&items, &JsValue::from_str(mime), &blob)?
-    };
+        #[expect(unsafe_code, unused_unsafe)] // Weird false positive
+        // SAFETY: I hope so
+        unsafe {
+            js_sys::Reflect::set(&items, &JsValue::from_str(mime), &blob)?
+        };
+    }
 
     let clipboard_item = web_sys::ClipboardItem::new_with_record_from_str_to_blob_promise(&items)?;
 
@@ -303,7 +372,8 @@ fn cursor_web_name(cursor: egui::CursorIcon) -> &'static str {
         egui::CursorIcon::ContextMenu => "context-menu",
         egui::CursorIcon::Copy => "copy",
         egui::CursorIcon::Crosshair => "crosshair",
-        egui::CursorIcon::Default => "default",
+        // `Custom` is handled by `AppRunner`:
+        egui::CursorIcon::Default | egui::CursorIcon::Custom(_) => "default",
         egui::CursorIcon::Grab => "grab",
         egui::CursorIcon::Grabbing => "grabbing",
         egui::CursorIcon::Help => "help",
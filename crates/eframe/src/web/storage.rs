@@ -12,17 +12,28 @@ pub fn local_storage_set(key: &str, value: &str) {
     local_storage().map(|storage| storage.set_item(key, value));
 }
 
+/// Remove data from local storage.
+pub fn local_storage_remove(key: &str) {
+    local_storage().map(|storage| storage.remove_item(key));
+}
+
+/// All keys in local storage.
+pub fn local_storage_keys() -> Vec<String> {
+    let Some(storage) = local_storage() else {
+        return Vec::new();
+    };
+    let len = storage.length().unwrap_or_default();
+    (0..len)
+        .filter_map(|index| storage.key(index).ok().flatten())
+        .collect()
+}
+
 #[cfg(feature = "persistence")]
 pub(crate) fn load_memory(ctx: &egui::Context) {
     if let Some(memory_string) = local_storage_get("egui_memory_ron") {
-        match ron::from_str(&memory_string) {
-            Ok(memory) => {
-                ctx.memory_mut(|m| *m = memory);
-            }
-            Err(err) => {
-                log::warn!("Failed to parse memory RON: {err}");
-            }
-        }
+        // Keeps whatever it can if the format has changed, e.g. after an egui update:
+        let memory = egui::Memory::from_ron_lenient(&memory_string);
+        ctx.memory_mut(|m| *m = memory);
     }
 }
 
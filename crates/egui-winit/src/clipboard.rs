@@ -25,6 +25,13 @@ pub struct Clipboard {
 
     /// Fallback manual clipboard.
     clipboard: String,
+
+    /// What we last copied with [`Self::set_data`],
+    /// so we can paste the representations the OS clipboard doesn't support.
+    last_copy: Option<egui::ClipboardData>,
+
+    /// See [`Self::set_paste_rich_data`].
+    paste_rich_data: bool,
 }
 
 impl Clipboard {
@@ -50,9 +57,19 @@ impl Clipboard {
             smithay: init_smithay_clipboard(_raw_display_handle),
 
             clipboard: Default::default(),
+            last_copy: None,
+            paste_rich_data: false,
         }
     }
 
+    /// Should [`Self::paste_events`] fetch the HTML and image on the clipboard
+    /// even if there is text on it?
+    ///
+    /// Off by default, since some platforms decode the whole image whenever it is fetched.
+    pub fn set_paste_rich_data(&mut self, paste_rich_data: bool) {
+        self.paste_rich_data = paste_rich_data;
+    }
+
     pub fn get(&mut self) -> Option<String> {
         #[cfg(all(
             any(
@@ -90,7 +107,97 @@ impl Clipboard {
         Some(self.clipboard.clone())
     }
 
+    /// Fetches HTML from the clipboard, if there is any.
+    pub fn get_html(&mut self) -> Option<String> {
+        #[cfg(all(
+            not(any(target_os = "android", target_os = "ios")),
+            feature = "arboard",
+        ))]
+        if let Some(clipboard) = &mut self.arboard {
+            return match clipboard.get().html() {
+                Ok(html) => Some(html),
+                Err(err) => {
+                    log::debug!("arboard: no html to paste: {err}");
+                    None
+                }
+            };
+        }
+
+        self.last_copy.as_ref()?.html.clone()
+    }
+
+    /// Fetches an image from the clipboard, if there is any.
+    pub fn get_image(&mut self) -> Option<egui::ColorImage> {
+        #[cfg(all(
+            not(any(target_os = "android", target_os = "ios")),
+            feature = "arboard",
+        ))]
+        if let Some(clipboard) = &mut self.arboard {
+            return match clipboard.get_image() {
+                Ok(image) => Some(egui::ColorImage::from_rgba_unmultiplied(
+                    [image.width, image.height],
+                    &image.bytes,
+                )),
+                Err(err) => {
+                    log::debug!("arboard: no image to paste: {err}");
+                    None
+                }
+            };
+        }
+
+        self.last_copy.as_ref()?.image.clone()
+    }
+
+    /// The events to send when the user pastes:
+    /// the text, and any HTML, image, or custom representation of the same content.
+    ///
+    /// Custom representations are only available if we copied them ourselves with
+    /// [`Self::set_data`], and the text on the clipboard is still what we copied.
+    ///
+    /// The HTML and image on the OS clipboard are only fetched if there is no text,
+    /// unless [`Self::set_paste_rich_data`] is on.
+    pub fn paste_events(&mut self) -> Vec<egui::Event> {
+        let text = self
+            .get()
+            .map(|text| text.replace("\r\n", "\n"))
+            .filter(|text| !text.is_empty());
+
+        let last_copy = self
+            .last_copy
+            .clone()
+            .filter(|data| data.text.is_some() && data.text == text);
+
+        let (html, image, data) = if let Some(last_copy) = last_copy {
+            (last_copy.html, last_copy.image, last_copy.data)
+        } else if text.is_none() || self.paste_rich_data {
+            (self.get_html(), self.get_image(), Vec::new())
+        } else {
+            Default::default()
+        };
+
+        let mut events = Vec::new();
+        if let Some(text) = text {
+            events.push(egui::Event::Paste(text));
+        }
+        if let Some(html) = html {
+            events.push(egui::Event::PasteData {
+                mime: "text/html".to_owned(),
+                bytes: html.into_bytes().into(),
+            });
+        }
+        if let Some(image) = image {
+            events.push(egui::Event::PasteImage(image.into()));
+        }
+        events.extend(
+            data.into_iter()
+                .map(|(mime, bytes)| egui::Event::PasteData { mime, bytes }),
+        );
+        events
+    }
+
     pub fn set_text(&mut self, text: String) {
+        self.last_copy = None;
+
         #[cfg(all(
             any(
                 target_os = "linux",
@@ -121,6 +228,8 @@ impl Clipboard {
     }
 
     pub fn set_image(&mut self, image: &egui::ColorImage) {
+        self.last_copy = None;
+
         #[cfg(all(
             not(any(target_os = "android", target_os = "ios")),
             feature = "arboard",
@@ -142,6 +251,56 @@ impl Clipboard {
         );
         _ = image;
     }
+
+    /// Put the data on the clipboard.
+    ///
+    /// The OS clipboard gets the HTML together with the text, or else the image, or else the text.
+    /// All representations can be pasted within this app, see [`Self::paste_events`].
+    pub fn set_data(&mut self, data: egui::ClipboardData) {
+        let stored_html = data
+            .html
+            .as_deref()
+            .is_some_and(|html| self.set_html(html, data.text.as_deref()));
+        if !stored_html {
+            if let Some(image) = &data.image {
+                self.set_image(image);
+            } else if let Some(text) = &data.text {
+                self.set_text(text.clone());
+            }
+        }
+        self.last_copy = Some(data);
+    }
+
+    /// Returns `false` if the OS clipboard doesn't support HTML.
+    fn set_html(&mut self, html: &str, alt_text: Option<&str>) -> bool {
+        #[cfg(all(
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ),
+            feature = "smithay-clipboard"
+        ))]
+        if self.smithay.is_some() {
+            return false;
+        }
+
+        #[cfg(all(
+            not(any(target_os = "android", target_os = "ios")),
+            feature = "arboard",
+        ))]
+        if let Some(clipboard) = &mut self.arboard {
+            if let Err(err) = clipboard.set_html(html, alt_text) {
+                log::error!("arboard copy/cut error: {err}");
+            }
+            return true;
+        }
+
+        _ = (html, alt_text);
+        false
+    }
 }
 
 #[cfg(all(
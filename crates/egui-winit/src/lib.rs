@@ -88,6 +88,17 @@ pub struct State {
     any_pointer_button_down: bool,
     current_cursor_icon: Option<egui::CursorIcon>,
 
+    /// The custom cursors created by [`Self::create_custom_cursor`], and the images they were created from.
+    ///
+    /// `None` if the image couldn't be turned into a cursor.
+    custom_cursors: egui::IdMap<(
+        std::sync::Arc<egui::CursorImage>,
+        Option<winit::window::CustomCursor>,
+    )>,
+
+    /// A custom cursor waiting for [`Self::create_custom_cursor`].
+    pending_cursor_image: Option<(egui::Id, std::sync::Arc<egui::CursorImage>)>,
+
     clipboard: clipboard::Clipboard,
 
     /// If `true`, mouse inputs will be treated as touches.
@@ -142,6 +153,8 @@ impl State {
             pointer_pos_in_points: None,
             any_pointer_button_down: false,
             current_cursor_icon: None,
+            custom_cursors: Default::default(),
+            pending_cursor_image: None,
 
             clipboard: clipboard::Clipboard::new(
                 display_target.display_handle().ok().map(|h| h.as_raw()),
@@ -201,6 +214,24 @@ impl State {
         self.clipboard.get()
     }
 
+    /// Pastes the contents of the clipboard, as if the user pressed Cmd+V.
+    ///
+    /// This pushes an [`egui::Event::Paste`] with the text, and [`egui::Event::PasteImage`] and
+    /// [`egui::Event::PasteData`] for the other representations of it on the clipboard.
+    /// See [`clipboard::Clipboard::paste_events`] for when the HTML and image are included.
+    pub fn paste(&mut self) {
+        let events = self.clipboard.paste_events();
+        self.egui_input.events.extend(events);
+    }
+
+    /// Should pasting fetch the HTML and image on the clipboard, even if there is text on it?
+    ///
+    /// Off by default, since some platforms decode the whole image whenever it is fetched.
+    /// See [`clipboard::Clipboard::set_paste_rich_data`].
+    pub fn set_paste_rich_data(&mut self, paste_rich_data: bool) {
+        self.clipboard.set_paste_rich_data(paste_rich_data);
+    }
+
     /// Places the text onto the clipboard.
     pub fn set_clipboard_text(&mut self, text: String) {
         self.clipboard.set_text(text);
@@ -869,16 +900,17 @@ impl State {
 
     fn on_touch(&mut self, window: &Window, touch: &winit::event::Touch) {
         let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+        let pos = egui::pos2(
+            touch.location.x as f32 / pixels_per_point,
+            touch.location.y as f32 / pixels_per_point,
+        );
 
         // Emit touch event
         self.egui_input.events.push(egui::Event::Touch {
             device_id: egui::TouchDeviceId(egui::epaint::util::hash(touch.device_id)),
             id: egui::TouchId::from(touch.id),
             phase: to_egui_touch_phase(touch.phase),
-            pos: egui::pos2(
-                touch.location.x as f32 / pixels_per_point,
-                touch.location.y as f32 / pixels_per_point,
-            ),
+            pos,
             force: match touch.force {
                 Some(winit::event::Force::Normalized(force)) => Some(force as f32),
                 Some(winit::event::Force::Calibrated {
@@ -889,6 +921,32 @@ impl State {
                 None => None,
             },
         });
+
+        // winit only tells us about a stylus (e.g. Apple Pencil) via the altitude angle:
+        if let Some(
+            force @ winit::event::Force::Calibrated {
+                altitude_angle: Some(altitude_angle),
+                ..
+            },
+        ) = touch.force
+        {
+            let is_touching = matches!(
+                touch.phase,
+                winit::event::TouchPhase::Started | winit::event::TouchPhase::Moved
+            );
+            self.egui_input.events.push(egui::Event::Pen {
+                pos,
+                info: egui::PenInfo {
+                    pressure: if is_touching {
+                        (force.normalized() as f32).clamp(0.0, 1.0)
+                    } else {
+                        0.0
+                    },
+                    altitude_angle: Some(altitude_angle as f32),
+                    ..Default::default()
+                },
+            });
+        }
         // If we're not yet translating a touch or we're translating this very
         // touch …
         if self.pointer_touch_id.is_none() || self.pointer_touch_id.unwrap_or_default() == touch.id
@@ -1017,12 +1075,7 @@ impl State {
                     self.egui_input.events.push(egui::Event::Copy);
                     return;
                 } else if is_paste_command(self.egui_input.modifiers, active_key) {
-                    if let Some(contents) = self.clipboard.get() {
-                        let contents = contents.replace("\r\n", "\n");
-                        if !contents.is_empty() {
-                            self.egui_input.events.push(egui::Event::Paste(contents));
-                        }
-                    }
+                    self.paste();
                     return;
                 }
             }
@@ -1078,6 +1131,7 @@ impl State {
         let egui::PlatformOutput {
             commands,
             cursor_icon,
+            cursor_image,
             events: _,                    // handled elsewhere
             mutable_text_under_cursor: _, // only used in eframe web
             ime,
@@ -1094,13 +1148,20 @@ impl State {
                 egui::OutputCommand::CopyImage(image) => {
                     self.clipboard.set_image(&image);
                 }
+                egui::OutputCommand::CopyRich { text, html } => {
+                    self.clipboard
+                        .set_data(egui::ClipboardData::from_text(text).with_html(html));
+                }
+                egui::OutputCommand::CopyData(data) => {
+                    self.clipboard.set_data(data);
+                }
                 egui::OutputCommand::OpenUrl(open_url) => {
                     open_url_in_browser(&open_url.url);
                 }
             }
         }
 
-        self.set_cursor_icon(window, cursor_icon);
+        self.set_cursor_icon(window, cursor_icon, cursor_image);
 
         let allow_ime = ime.is_some();
         if self.allow_ime != allow_ime {
@@ -1154,7 +1215,58 @@ impl State {
         let _ = accesskit_update;
     }
 
-    fn set_cursor_icon(&mut self, window: &Window, cursor_icon: egui::CursorIcon) {
+    /// Create the custom cursor requested by the last call to [`Self::handle_platform_output`], if any.
+    ///
+    /// Creating a cursor from a [`egui::CursorImage`] requires the event loop,
+    /// so until this is called, [`egui::CursorImage::fallback`] is shown instead.
+    pub fn create_custom_cursor(&mut self, event_loop: &ActiveEventLoop, window: &Window) {
+        let Some((id, image)) = self.pending_cursor_image.take() else {
+            return;
+        };
+
+        let custom_cursor =
+            custom_cursor_source(&image).map(|source| event_loop.create_custom_cursor(source));
+        self.custom_cursors
+            .insert(id, (std::sync::Arc::clone(&image), custom_cursor));
+
+        self.current_cursor_icon = None;
+        self.set_cursor_icon(window, egui::CursorIcon::Custom(id), Some(image));
+    }
+
+    fn set_cursor_icon(
+        &mut self,
+        window: &Window,
+        cursor_icon: egui::CursorIcon,
+        cursor_image: Option<std::sync::Arc<egui::CursorImage>>,
+    ) {
+        self.pending_cursor_image = None;
+
+        let mut custom_cursor = None;
+        if let egui::CursorIcon::Custom(id) = cursor_icon {
+            let Some(image) = cursor_image else {
+                // Not registered with `Context::custom_cursor`.
+                self.set_cursor_icon(window, egui::CursorIcon::Default, None);
+                return;
+            };
+
+            match self.custom_cursors.get(&id) {
+                Some((created_from, created))
+                    if std::sync::Arc::ptr_eq(created_from, &image) || *created_from == image =>
+                {
+                    let Some(created) = created else {
+                        self.set_cursor_icon(window, image.fallback, None);
+                        return;
+                    };
+                    custom_cursor = Some(created.clone());
+                }
+                _ => {
+                    self.set_cursor_icon(window, image.fallback, None);
+                    self.pending_cursor_image = Some((id, image));
+                    return;
+                }
+            }
+        }
+
         if self.current_cursor_icon == Some(cursor_icon) {
             // Prevent flickering near frame boundary when Windows OS tries to control cursor icon for window resizing.
             // On other platforms: just early-out to save CPU.
@@ -1165,7 +1277,10 @@ impl State {
         if is_pointer_in_window {
             self.current_cursor_icon = Some(cursor_icon);
 
-            if let Some(winit_cursor_icon) = translate_cursor(cursor_icon) {
+            if let Some(custom_cursor) = custom_cursor {
+                window.set_cursor_visible(true);
+                window.set_cursor(custom_cursor);
+            } else if let Some(winit_cursor_icon) = translate_cursor(cursor_icon) {
                 window.set_cursor_visible(true);
                 window.set_cursor(winit_cursor_icon);
             } else {
@@ -1178,6 +1293,30 @@ impl State {
     }
 }
 
+fn custom_cursor_source(cursor: &egui::CursorImage) -> Option<winit::window::CustomCursorSource> {
+    let [width, height] = cursor.image.size;
+    let [hotspot_x, hotspot_y] = cursor.hotspot;
+    let (Ok(width), Ok(height), Ok(hotspot_x), Ok(hotspot_y)) = (
+        u16::try_from(width),
+        u16::try_from(height),
+        u16::try_from(hotspot_x),
+        u16::try_from(hotspot_y),
+    ) else {
+        log::warn!("Custom cursor is too large: {width}x{height}");
+        return None;
+    };
+
+    let rgba: Vec<u8> = cursor
+        .image
+        .pixels
+        .iter()
+        .flat_map(|color| color.to_srgba_unmultiplied())
+        .collect();
+    winit::window::CustomCursor::from_rgba(rgba, width, height, hotspot_x, hotspot_y)
+        .inspect_err(|err| log::warn!("Failed to create custom cursor: {err}"))
+        .ok()
+}
+
 fn to_egui_touch_phase(phase: winit::event::TouchPhase) -> egui::TouchPhase {
     match phase {
         winit::event::TouchPhase::Started => egui::TouchPhase::Start,
@@ -1540,7 +1679,10 @@ fn translate_cursor(cursor_icon: egui::CursorIcon) -> Option<winit::window::Curs
         egui::CursorIcon::ContextMenu => Some(winit::window::CursorIcon::ContextMenu),
         egui::CursorIcon::Copy => Some(winit::window::CursorIcon::Copy),
         egui::CursorIcon::Crosshair => Some(winit::window::CursorIcon::Crosshair),
-        egui::CursorIcon::Default => Some(winit::window::CursorIcon::Default),
+        // `Custom` is handled by `State::set_cursor_icon`:
+        egui::CursorIcon::Default | egui::CursorIcon::Custom(_) => {
+            Some(winit::window::CursorIcon::Default)
+        }
         egui::CursorIcon::Grab => Some(winit::window::CursorIcon::Grab),
         egui::CursorIcon::Grabbing => Some(winit::window::CursorIcon::Grabbing),
         egui::CursorIcon::Help => Some(winit::window::CursorIcon::Help),
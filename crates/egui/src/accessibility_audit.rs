@@ -0,0 +1,408 @@
+//! Automated checks for common accessibility problems.
+//!
+//! [`audit`] walks the [`crate::WidgetRects`] and the AccessKit nodes of the last pass and reports:
+//! * interactive widgets without a label
+//! * interactive widgets that can't be reached with the keyboard
+//! * text with insufficient contrast against its background
+//! * hit targets that are too small
+//! * interactive widgets that partially overlap each other
+//!
+//! Use it from tests (e.g. `egui_kittest::Harness::accessibility_audit`),
+//! or add the [`AccessibilityAuditPlugin`] to show the problems as an overlay at runtime.
+
+use std::fmt;
+
+use ahash::HashMap;
+
+use crate::{
+    Color32, Context, FullOutput, Id, LayerId, Order, Plugin, Rect, Rgba, Ui, Vec2, Visuals,
+    WidgetRect,
+};
+
+/// Thresholds used by [`audit`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AuditOptions {
+    /// Minimum contrast ratio between text and its background.
+    ///
+    /// The default of 4.5 is the WCAG 2 AA requirement for normal text.
+    pub min_contrast: f32,
+
+    /// Minimum width and height of interactive widgets, in points.
+    ///
+    /// The default of 24 is the WCAG 2.2 AA requirement.
+    /// Note that the default egui style uses 18 points high widgets
+    /// (see [`crate::Spacing::interact_size`]).
+    pub min_target_size: f32,
+
+    /// Report interactive widgets that partially overlap each other.
+    ///
+    /// Widgets fully contained in another widget (e.g. a button in a draggable window) are fine.
+    pub check_overlap: bool,
+}
+
+impl Default for AuditOptions {
+    fn default() -> Self {
+        Self {
+            min_contrast: 4.5,
+            min_target_size: 24.0,
+            check_overlap: true,
+        }
+    }
+}
+
+/// What kind of problem an [`AuditIssue`] is.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AuditIssueKind {
+    /// An interactive widget has no label for screen readers.
+    MissingLabel,
+
+    /// An interactive widget can't be reached with the keyboard.
+    ///
+    /// Either it can't be focused, or Tab skips over it because of a negative tab index
+    /// (see [`crate::Response::with_tab_index`]) or a [skipping](crate::FocusScope::skip) focus scope.
+    /// The widgets of a [roving](crate::FocusScope::roving) focus scope can be reached with the arrow keys,
+    /// so they are fine.
+    NotFocusable,
+
+    /// The text color of the widget (from [`Visuals`]) has too low contrast against its background.
+    LowContrast {
+        text: Color32,
+        background: Color32,
+        contrast: f32,
+    },
+
+    /// The widget is smaller than [`AuditOptions::min_target_size`].
+    SmallTarget { size: Vec2 },
+
+    /// The widget partially overlaps another interactive widget.
+    Overlapping { other: Id },
+}
+
+impl fmt::Display for AuditIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingLabel => write!(f, "missing label"),
+            Self::NotFocusable => write!(f, "not focusable"),
+            Self::LowContrast { contrast, .. } => write!(f, "low contrast ({contrast:.1}:1)"),
+            Self::SmallTarget { size } => write!(f, "small target ({}x{})", size.x, size.y),
+            Self::Overlapping { other } => write!(f, "overlaps {other:?}"),
+        }
+    }
+}
+
+/// A single problem found by [`audit`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditIssue {
+    /// The widget with the problem.
+    pub id: Id,
+
+    /// The AccessKit role of the widget, if it has an AccessKit node.
+    pub role: Option<accesskit::Role>,
+
+    /// The label of the widget, if any.
+    pub label: Option<String>,
+
+    /// Where the widget is, in global coordinates.
+    pub rect: Rect,
+
+    pub kind: AuditIssueKind,
+}
+
+impl fmt::Display for AuditIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            id,
+            role,
+            label,
+            rect,
+            kind,
+        } = self;
+        write!(f, "{kind}: ")?;
+        if let Some(role) = role {
+            write!(f, "{role:?} ")?;
+        }
+        if let Some(label) = label {
+            write!(f, "{label:?} ")?;
+        }
+        write!(
+            f,
+            "at [{:.0} {:.0} - {:.0} {:.0}] ({id:?})",
+            rect.min.x, rect.min.y, rect.max.x, rect.max.y
+        )
+    }
+}
+
+/// The result of [`audit`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuditReport {
+    /// All problems found, in painting order.
+    pub issues: Vec<AuditIssue>,
+}
+
+impl AuditReport {
+    /// Were no problems found?
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// The problems found with a specific widget.
+    pub fn issues_for(&self, id: Id) -> impl Iterator<Item = &AuditIssue> + '_ {
+        self.issues.iter().filter(move |issue| issue.id == id)
+    }
+}
+
+impl fmt::Display for AuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.issues.is_empty() {
+            return write!(f, "No accessibility issues found");
+        }
+        writeln!(f, "{} accessibility issue(s) found:", self.issues.len())?;
+        for issue in &self.issues {
+            writeln!(f, "  {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Check the last pass of the current viewport for accessibility problems.
+///
+/// `nodes` are the AccessKit nodes of that pass,
+/// e.g. from [`crate::PlatformOutput::accesskit_update`].
+///
+/// Contrast is checked using the colors of the current [`Visuals`],
+/// so colors set directly on a widget (e.g. with [`crate::RichText::color`]) are not considered.
+pub fn audit(
+    ctx: &Context,
+    nodes: &[(accesskit::NodeId, accesskit::Node)],
+    options: &AuditOptions,
+) -> AuditReport {
+    profiling::function_scope!();
+
+    let nodes: HashMap<accesskit::NodeId, &accesskit::Node> =
+        nodes.iter().map(|(id, node)| (*id, node)).collect();
+    let widgets = ctx.viewport(|viewport| viewport.prev_pass.widgets.clone());
+    let visuals = ctx.global_style().visuals.clone();
+    let skipped_by_tab = ctx.memory(|mem| mem.skipped_by_tab());
+
+    let mut layers: Vec<LayerId> = widgets.layer_ids().collect();
+    ctx.memory(|mem| layers.sort_by(|&a, &b| mem.areas().compare_order(a, b)));
+
+    let mut issues = Vec::new();
+    for layer_id in layers {
+        let transform = ctx.layer_transform_to_global(layer_id).unwrap_or_default();
+        let mut controls: Vec<&WidgetRect> = Vec::new();
+
+        for widget in widgets.get_layer(layer_id) {
+            if !widget.enabled || !widget.interact_rect.is_positive() {
+                continue;
+            }
+
+            let node = nodes.get(&widget.id.accesskit_id()).copied();
+            let label = node.and_then(|node| accessible_name(node, &nodes));
+            let mut report = |kind| {
+                issues.push(AuditIssue {
+                    id: widget.id,
+                    role: node.map(|node| node.role()),
+                    label: label.clone(),
+                    rect: transform * widget.rect,
+                    kind,
+                });
+            };
+
+            if let Some(node) = node
+                && let Some((text, background)) = text_colors(&visuals, layer_id, node.role())
+            {
+                let contrast = contrast_ratio(text, background);
+                if contrast < options.min_contrast {
+                    report(AuditIssueKind::LowContrast {
+                        text,
+                        background,
+                        contrast,
+                    });
+                }
+            }
+
+            // Things like draggable window backgrounds are not controls on their own,
+            // labels only sense clicks for text selection,
+            // and widgets without a node (e.g. a window title bar) are hidden from assistive technology:
+            if !widget.sense.senses_click()
+                || node.is_none_or(|node| node.role() == accesskit::Role::Label)
+            {
+                continue;
+            }
+
+            if label.is_none() {
+                report(AuditIssueKind::MissingLabel);
+            }
+            if !widget.sense.is_focusable() || skipped_by_tab.contains(&widget.id) {
+                report(AuditIssueKind::NotFocusable);
+            }
+            let size = widget.rect.size();
+            if size.x < options.min_target_size || size.y < options.min_target_size {
+                report(AuditIssueKind::SmallTarget { size });
+            }
+            if options.check_overlap {
+                for other in &controls {
+                    if partially_overlaps(widget.interact_rect, other.interact_rect) {
+                        report(AuditIssueKind::Overlapping { other: other.id });
+                    }
+                }
+            }
+
+            controls.push(widget);
+        }
+    }
+
+    AuditReport { issues }
+}
+
+/// The label of the node, either set directly or via [`accesskit::Node::labelled_by`].
+fn accessible_name(
+    node: &accesskit::Node,
+    nodes: &HashMap<accesskit::NodeId, &accesskit::Node>,
+) -> Option<String> {
+    if let Some(label) = node.label()
+        && !label.trim().is_empty()
+    {
+        return Some(label.to_owned());
+    }
+
+    let label = node
+        .labelled_by()
+        .iter()
+        .filter_map(|id| nodes.get(id))
+        .filter_map(|label| label.label().or_else(|| label.value()))
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!label.trim().is_empty()).then_some(label)
+}
+
+/// The text and background color a widget with this role is painted with.
+fn text_colors(
+    visuals: &Visuals,
+    layer_id: LayerId,
+    role: accesskit::Role,
+) -> Option<(Color32, Color32)> {
+    use accesskit::Role;
+
+    let base = if layer_id.order == Order::Background {
+        visuals.panel_fill
+    } else {
+        visuals.window_fill
+    };
+    let widget = &visuals.widgets.inactive;
+
+    match role {
+        Role::Label => Some((visuals.text_color(), base)),
+        Role::Link => Some((visuals.hyperlink_color, base)),
+        Role::CheckBox | Role::RadioButton => Some((widget.text_color(), base)),
+        Role::Button | Role::ComboBox => {
+            Some((widget.text_color(), base.blend(widget.weak_bg_fill)))
+        }
+        Role::TextInput | Role::MultilineTextInput | Role::SearchInput => Some((
+            visuals.text_color(),
+            base.blend(visuals.text_edit_bg_color()),
+        )),
+        _ => None,
+    }
+}
+
+/// The WCAG 2 contrast ratio between two colors, from 1 to 21.
+///
+/// Transparency in `text` is taken into account by blending it on top of `background`.
+pub fn contrast_ratio(text: Color32, background: Color32) -> f32 {
+    let luminance = |color: Color32| -> f32 {
+        let linear = Rgba::from(color);
+        0.2126 * linear.r() + 0.7152 * linear.g() + 0.0722 * linear.b()
+    };
+    let background = background.to_opaque();
+    let a = luminance(background.blend(text)) + 0.05;
+    let b = luminance(background) + 0.05;
+    a.max(b) / a.min(b)
+}
+
+fn partially_overlaps(a: Rect, b: Rect) -> bool {
+    let intersection = a.intersect(b);
+    // Touching edges, or rounding errors, don't count:
+    intersection.width() > 0.5
+        && intersection.height() > 0.5
+        && !a.contains_rect(b)
+        && !b.contains_rect(a)
+}
+
+/// A [`Plugin`] that highlights the problems found by [`audit`].
+///
+/// The overlay is shown one frame late, since the audit needs the AccessKit tree of a finished pass.
+/// Enabling the plugin also enables AccessKit.
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// ctx.add_plugin(egui::accessibility_audit::AccessibilityAuditPlugin::default());
+/// ```
+#[derive(Debug)]
+pub struct AccessibilityAuditPlugin {
+    /// Show the overlay?
+    pub enabled: bool,
+
+    pub options: AuditOptions,
+
+    /// The color used to highlight problems.
+    pub color: Color32,
+
+    nodes: Vec<(accesskit::NodeId, accesskit::Node)>,
+    report: AuditReport,
+}
+
+impl Default for AccessibilityAuditPlugin {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            options: AuditOptions::default(),
+            color: Color32::RED,
+            nodes: Vec::new(),
+            report: AuditReport::default(),
+        }
+    }
+}
+
+impl AccessibilityAuditPlugin {
+    /// The result of the last audit.
+    pub fn report(&self) -> &AuditReport {
+        &self.report
+    }
+}
+
+impl Plugin for AccessibilityAuditPlugin {
+    fn debug_name(&self) -> &'static str {
+        "AccessibilityAuditPlugin"
+    }
+
+    fn on_begin_pass(&mut self, ui: &mut Ui) {
+        if !self.enabled {
+            self.report = AuditReport::default();
+            return;
+        }
+
+        ui.enable_accesskit();
+
+        // The widget rects of the previous pass match the nodes from `output_hook`:
+        self.report = audit(ui.ctx(), &self.nodes, &self.options);
+
+        let painter = ui.ctx().debug_painter();
+        for issues in self.report.issues.chunk_by(|a, b| a.id == b.id) {
+            let text = issues
+                .iter()
+                .map(|issue| issue.kind.to_string())
+                .collect::<Vec<_>>()
+                .join("\n");
+            painter.debug_rect(issues[0].rect, self.color, text);
+        }
+    }
+
+    fn output_hook(&mut self, output: &mut FullOutput) {
+        if let Some(update) = &output.platform_output.accesskit_update {
+            self.nodes.clone_from(&update.nodes);
+        }
+    }
+}
@@ -0,0 +1,560 @@
+//! See [`Flex`] for docs.
+
+use std::ops::Range;
+
+use emath::{Align, NumExt as _, Pos2, Rect, Vec2, vec2};
+
+use crate::{Id, InnerResponse, Layout, Response, Sense, Ui, UiBuilder, Widget};
+
+/// The direction a [`Flex`] container lays out its items in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FlexDirection {
+    /// Items are placed left to right, and lines wrap downwards.
+    #[default]
+    Horizontal,
+
+    /// Items are placed top to bottom, and lines wrap to the right.
+    Vertical,
+}
+
+impl FlexDirection {
+    fn main(self, v: Vec2) -> f32 {
+        match self {
+            Self::Horizontal => v.x,
+            Self::Vertical => v.y,
+        }
+    }
+
+    fn cross(self, v: Vec2) -> f32 {
+        match self {
+            Self::Horizontal => v.y,
+            Self::Vertical => v.x,
+        }
+    }
+
+    fn vec2(self, main: f32, cross: f32) -> Vec2 {
+        match self {
+            Self::Horizontal => vec2(main, cross),
+            Self::Vertical => vec2(cross, main),
+        }
+    }
+}
+
+/// How a [`Flex`] distributes the free space of a line between and around its items.
+///
+/// Only has an effect if no item on the line grows, see [`FlexItem::grow`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FlexJustify {
+    /// Pack items at the start of the line.
+    #[default]
+    Start,
+
+    /// Pack items at the end of the line.
+    End,
+
+    /// Pack items in the center of the line.
+    Center,
+
+    /// The first item is at the start, the last item at the end, and the rest evenly in between.
+    SpaceBetween,
+
+    /// Each item gets the same amount of space on both sides.
+    SpaceAround,
+
+    /// The space between any two items, and before the first and after the last, is the same.
+    SpaceEvenly,
+}
+
+/// How items are placed across the line they are on, see [`Flex::align_items`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FlexAlign {
+    /// At the top of the line (or left, for [`FlexDirection::Vertical`]).
+    Start,
+
+    /// At the bottom of the line (or right, for [`FlexDirection::Vertical`]).
+    End,
+
+    /// In the center of the line.
+    #[default]
+    Center,
+
+    /// Give the item the full size of the line.
+    Stretch,
+}
+
+/// How an item of a [`Flex`] is sized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FlexItem {
+    grow: f32,
+    shrink: f32,
+    basis: Option<f32>,
+    align_self: Option<FlexAlign>,
+}
+
+impl Default for FlexItem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FlexItem {
+    /// An item that doesn't grow, and shrinks if the line is too small.
+    pub fn new() -> Self {
+        Self {
+            grow: 0.0,
+            shrink: 1.0,
+            basis: None,
+            align_self: None,
+        }
+    }
+
+    /// How much of the free space of the line this item gets, relative to the other items.
+    ///
+    /// Default: `0.0`.
+    #[inline]
+    pub fn grow(mut self, grow: f32) -> Self {
+        self.grow = grow;
+        self
+    }
+
+    /// How much this item shrinks when the line is too small, relative to the other items.
+    ///
+    /// Items shrink in proportion to this times their size.
+    /// Content that can't shrink (e.g. text that doesn't wrap) will overflow its item.
+    ///
+    /// Default: `1.0`.
+    #[inline]
+    pub fn shrink(mut self, shrink: f32) -> Self {
+        self.shrink = shrink;
+        self
+    }
+
+    /// The size of the item along the main axis, before growing or shrinking.
+    ///
+    /// By default this is the measured size of the content. Set it for content that fills
+    /// all the space it is given, since its size says nothing about the size it needs.
+    #[inline]
+    pub fn basis(mut self, basis: f32) -> Self {
+        self.basis = Some(basis);
+        self
+    }
+
+    /// Override [`Flex::align_items`] for this item.
+    #[inline]
+    pub fn align_self(mut self, align: FlexAlign) -> Self {
+        self.align_self = Some(align);
+        self
+    }
+}
+
+/// The items of last pass: how they were configured, and the measured size of their content.
+#[derive(Clone, Debug, Default)]
+struct State {
+    items: Vec<(FlexItem, Vec2)>,
+}
+
+/// A flexbox-style container, for toolbars and forms that adapt to the available space.
+///
+/// Items are placed one after the other along the main axis, and wrap to a new line
+/// if [`Self::wrap`] is enabled. The free space of each line goes to the items that
+/// [`FlexItem::grow`], or else is distributed according to [`Self::justify`].
+/// If the line is too small, items [`FlexItem::shrink`].
+///
+/// The layout is based on the size of the items measured in previous passes.
+/// New items are measured in an invisible sizing pass, and whenever a size changes the
+/// pass is discarded (see [`crate::Context::request_discard`]), so the layout doesn't jitter.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut query = String::new();
+/// use egui::{Flex, FlexItem};
+/// Flex::horizontal().wrap(true).show(ui, |flex| {
+///     flex.add(FlexItem::new(), egui::Label::new("Search:"));
+///     flex.add(
+///         FlexItem::new().grow(1.0).basis(100.0),
+///         egui::TextEdit::singleline(&mut query),
+///     );
+///     if flex.add(FlexItem::new(), egui::Button::new("Go")).clicked() {
+///         // …
+///     }
+/// });
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use = "You should call .show()"]
+pub struct Flex {
+    id_salt: Option<Id>,
+    direction: FlexDirection,
+    wrap: bool,
+    gap: Option<Vec2>,
+    justify: FlexJustify,
+    align_items: FlexAlign,
+}
+
+impl Default for Flex {
+    fn default() -> Self {
+        Self::horizontal()
+    }
+}
+
+impl Flex {
+    /// Lay out items left to right.
+    pub fn horizontal() -> Self {
+        Self {
+            id_salt: None,
+            direction: FlexDirection::Horizontal,
+            wrap: false,
+            gap: None,
+            justify: FlexJustify::default(),
+            align_items: FlexAlign::default(),
+        }
+    }
+
+    /// Lay out items top to bottom.
+    pub fn vertical() -> Self {
+        Self {
+            direction: FlexDirection::Vertical,
+            ..Self::horizontal()
+        }
+    }
+
+    /// Needed if you have several [`Flex`] containers in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Start a new line when the next item doesn't fit.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// The horizontal and vertical space between items and between lines.
+    ///
+    /// Default: [`crate::style::Spacing::item_spacing`].
+    #[inline]
+    pub fn gap(mut self, gap: impl Into<Vec2>) -> Self {
+        self.gap = Some(gap.into());
+        self
+    }
+
+    /// How to distribute the free space of a line.
+    ///
+    /// Default: [`FlexJustify::Start`].
+    #[inline]
+    pub fn justify(mut self, justify: FlexJustify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// How to place items across their line.
+    ///
+    /// Default: [`FlexAlign::Center`].
+    #[inline]
+    pub fn align_items(mut self, align_items: FlexAlign) -> Self {
+        self.align_items = align_items;
+        self
+    }
+
+    /// Show the container. Add the items with [`FlexUi::add`] and [`FlexUi::add_ui`].
+    ///
+    /// The container uses the available width (or height, for [`FlexDirection::Vertical`]).
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut FlexUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let id = ui.make_persistent_id(self.id_salt.unwrap_or_else(|| Id::new("flex")));
+        let gap = self.gap.unwrap_or_else(|| ui.spacing().item_spacing);
+        let available = ui.available_rect_before_wrap();
+        let main_size = if ui.is_sizing_pass() {
+            // Show the items at their natural size, so the parent can size itself to them:
+            f32::INFINITY
+        } else {
+            self.direction.main(available.size())
+        };
+
+        let old_items = ui
+            .data_mut(|d| d.get_temp::<State>(id))
+            .unwrap_or_default()
+            .items;
+        let rects = self.layout(&old_items, available.min, main_size, gap);
+
+        let mut flex_ui = FlexUi {
+            ui,
+            flex: self,
+            available,
+            rects,
+            old_items,
+            items: Vec::new(),
+            content_rect: Rect::NOTHING,
+            changed: false,
+        };
+        let inner = add_contents(&mut flex_ui);
+        let FlexUi {
+            ui,
+            old_items,
+            items,
+            content_rect,
+            mut changed,
+            ..
+        } = flex_ui;
+
+        changed |= items.len() != old_items.len();
+        if changed {
+            // Lay out again with the new measurements:
+            ui.ctx().request_discard("Flex item sizes changed");
+        }
+        ui.data_mut(|d| d.insert_temp(id, State { items }));
+
+        let rect = if content_rect.is_positive() {
+            content_rect
+        } else {
+            Rect::from_min_size(available.min, Vec2::ZERO)
+        };
+        ui.advance_cursor_after_rect(rect);
+        let response = ui.interact(rect, id, Sense::hover());
+        InnerResponse::new(inner, response)
+    }
+
+    /// The rects of the items, given their configuration and measured sizes.
+    fn layout(
+        &self,
+        items: &[(FlexItem, Vec2)],
+        origin: Pos2,
+        main_size: f32,
+        gap: Vec2,
+    ) -> Vec<Rect> {
+        let dir = self.direction;
+        let main_gap = dir.main(gap);
+        let cross_gap = dir.cross(gap);
+        let basis = |(item, size): &(FlexItem, Vec2)| item.basis.unwrap_or_else(|| dir.main(*size));
+
+        let mut lines: Vec<Range<usize>> = Vec::new();
+        let mut line_start = 0;
+        let mut line_size = 0.0;
+        for (i, item) in items.iter().enumerate() {
+            let size = basis(item);
+            if i == line_start {
+                line_size = size;
+            } else if self.wrap && main_size < line_size + main_gap + size {
+                lines.push(line_start..i);
+                line_start = i;
+                line_size = size;
+            } else {
+                line_size += main_gap + size;
+            }
+        }
+        if line_start < items.len() {
+            lines.push(line_start..items.len());
+        }
+
+        let mut rects = vec![Rect::NOTHING; items.len()];
+        let mut cross_pos = 0.0;
+        for line in lines {
+            let line_items = &items[line.clone()];
+            let num_items = line_items.len() as f32;
+            let mut sizes: Vec<f32> = line_items.iter().map(basis).collect();
+            let mut free =
+                main_size - sizes.iter().sum::<f32>() - main_gap * (num_items - 1.0).at_least(0.0);
+
+            if !free.is_finite() {
+                free = 0.0;
+            } else if 0.0 < free {
+                let total_grow: f32 = line_items.iter().map(|(item, _)| item.grow).sum();
+                if 0.0 < total_grow {
+                    for (size, (item, _)) in sizes.iter_mut().zip(line_items) {
+                        *size += free * item.grow / total_grow;
+                    }
+                    free = 0.0;
+                }
+            } else {
+                let total_shrink: f32 = line_items
+                    .iter()
+                    .zip(&sizes)
+                    .map(|((item, _), size)| item.shrink * size)
+                    .sum();
+                if 0.0 < total_shrink {
+                    for (size, (item, _)) in sizes.iter_mut().zip(line_items) {
+                        *size = (*size + free * item.shrink * *size / total_shrink).at_least(0.0);
+                    }
+                }
+                free = 0.0;
+            }
+
+            let (mut main_pos, space_between) = match self.justify {
+                FlexJustify::Start => (0.0, 0.0),
+                FlexJustify::End => (free, 0.0),
+                FlexJustify::Center => (free / 2.0, 0.0),
+                FlexJustify::SpaceBetween => (0.0, free / (num_items - 1.0).at_least(1.0)),
+                FlexJustify::SpaceAround => (free / num_items / 2.0, free / num_items),
+                FlexJustify::SpaceEvenly => (free / (num_items + 1.0), free / (num_items + 1.0)),
+            };
+
+            let line_cross = line_items
+                .iter()
+                .map(|(_, size)| dir.cross(*size))
+                .fold(0.0, f32::max);
+            for ((i, (item, size)), main) in line.zip(line_items).zip(sizes) {
+                let cross = dir.cross(*size);
+                let (offset, cross) = match item.align_self.unwrap_or(self.align_items) {
+                    FlexAlign::Start => (0.0, cross),
+                    FlexAlign::End => (line_cross - cross, cross),
+                    FlexAlign::Center => ((line_cross - cross) / 2.0, cross),
+                    FlexAlign::Stretch => (0.0, line_cross),
+                };
+                rects[i] = Rect::from_min_size(
+                    origin + dir.vec2(main_pos, cross_pos + offset),
+                    dir.vec2(main, cross),
+                );
+                main_pos += main + main_gap + space_between;
+            }
+            cross_pos += line_cross + cross_gap;
+        }
+        rects
+    }
+}
+
+/// Add items to a [`Flex`] container.
+pub struct FlexUi<'a> {
+    ui: &'a mut Ui,
+    flex: Flex,
+    available: Rect,
+
+    /// Where to put the items, based on [`Self::old_items`].
+    rects: Vec<Rect>,
+    old_items: Vec<(FlexItem, Vec2)>,
+    items: Vec<(FlexItem, Vec2)>,
+    content_rect: Rect,
+
+    /// Did an item change, so the layout should be done again?
+    changed: bool,
+}
+
+impl FlexUi<'_> {
+    /// The parent [`Ui`].
+    pub fn ui(&self) -> &Ui {
+        self.ui
+    }
+
+    /// Add a widget as an item.
+    ///
+    /// The widget fills the item if it grows or is stretched, like a [`Ui::add_sized`].
+    /// The item is then measured by [`Response::intrinsic_size`], if the widget has one.
+    pub fn add(&mut self, item: FlexItem, widget: impl Widget) -> Response {
+        let fill = 0.0 < item.grow
+            || item.align_self.unwrap_or(self.flex.align_items) == FlexAlign::Stretch;
+        self.add_item(item, |ui| {
+            if fill && !ui.is_sizing_pass() {
+                let response = ui.add_sized(ui.available_size(), widget);
+                // The widget fills the item, so its rect doesn't tell us the size it needs:
+                let natural_size = response.intrinsic_size();
+                (response, natural_size)
+            } else {
+                (ui.add(widget), None)
+            }
+        })
+        .inner
+    }
+
+    /// Add an item with any contents.
+    ///
+    /// The contents are placed in a [`Ui`] with the size of the item, but are free
+    /// to use less. Contents that fill all of the space should have a [`FlexItem::basis`].
+    pub fn add_ui<R>(
+        &mut self,
+        item: FlexItem,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.add_item(item, |ui| (add_contents(ui), None))
+    }
+
+    /// Add an item, whose contents may know their natural size.
+    ///
+    /// Without a natural size, the item is measured by the size the contents used.
+    fn add_item<R>(
+        &mut self,
+        item: FlexItem,
+        add_contents: impl FnOnce(&mut Ui) -> (R, Option<Vec2>),
+    ) -> InnerResponse<R> {
+        let index = self.items.len();
+        let dir = self.flex.direction;
+        let layout = match dir {
+            FlexDirection::Horizontal => Layout::left_to_right(Align::Center),
+            FlexDirection::Vertical => Layout::top_down(Align::Min),
+        };
+
+        let old = self
+            .old_items
+            .get(index)
+            .filter(|(old_item, _)| *old_item == item)
+            .map(|(_, size)| (*size, self.rects[index]));
+
+        let mut builder = UiBuilder::new().id_salt(index).layout(layout);
+        builder = if let Some((_, rect)) = old {
+            builder.max_rect(rect)
+        } else {
+            // Measure the natural size of the contents without showing them:
+            self.changed = true;
+            builder.max_rect(self.available).sizing_pass().invisible()
+        };
+        let mut child_ui = self.ui.new_child(builder);
+        let (inner, natural_size) = add_contents(&mut child_ui);
+        let measured = child_ui.min_rect().size();
+
+        let size = if let Some(natural_size) = natural_size {
+            if let Some((old_size, rect)) = old {
+                self.changed |= 0.5 < (natural_size - old_size).abs().max_elem();
+                self.content_rect |= rect | child_ui.min_rect();
+            }
+            natural_size
+        } else if let Some((old_size, rect)) = old {
+            let size = dir.vec2(
+                remeasure(
+                    dir.main(old_size),
+                    dir.main(rect.size()),
+                    dir.main(measured),
+                ),
+                remeasure(
+                    dir.cross(old_size),
+                    dir.cross(rect.size()),
+                    dir.cross(measured),
+                ),
+            );
+            self.changed |= 0.5 < (size - old_size).abs().max_elem();
+            self.content_rect |= rect | child_ui.min_rect();
+            size
+        } else {
+            measured
+        };
+        self.items.push((item, size));
+
+        let response = child_ui.response();
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// The new measured size of an item along one axis.
+///
+/// Contents may fill the space they were given, so if the item was given more space than it
+/// measured, we only trust a new measurement that is outside of the old and new size.
+/// Likewise if the item was given less space, contents may wrap, so we only trust a larger size.
+fn remeasure(old: f32, given: f32, measured: f32) -> f32 {
+    let changed = if old + 0.5 < given {
+        measured < old - 0.5 || given + 0.5 < measured
+    } else if given < old - 0.5 {
+        old + 0.5 < measured
+    } else {
+        true
+    };
+    if changed { measured } else { old }
+}
@@ -6,17 +6,20 @@ pub(crate) mod area;
 mod close_tag;
 pub mod collapsing_header;
 mod combo_box;
+mod flex;
 pub mod frame;
 pub mod menu;
 pub mod modal;
 pub mod old_popup;
 pub mod panel;
 mod popup;
+mod reorderable_list;
 pub(crate) mod resize;
 mod scene;
 pub mod scroll_area;
 mod sides;
 mod tooltip;
+mod virtual_list;
 pub(crate) mod window;
 
 pub use {
@@ -24,15 +27,18 @@ pub use {
     close_tag::ClosableTag,
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
+    flex::{Flex, FlexAlign, FlexDirection, FlexItem, FlexJustify, FlexUi},
     frame::Frame,
     modal::{Modal, ModalResponse},
     old_popup::*,
     panel::*,
     popup::*,
+    reorderable_list::{ReorderMove, ReorderableList, ReorderableListResponse},
     resize::Resize,
     scene::{DragPanButtons, Scene},
     scroll_area::ScrollArea,
     sides::Sides,
     tooltip::*,
+    virtual_list::VirtualList,
     window::Window,
 };
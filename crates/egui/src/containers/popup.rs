@@ -588,6 +588,10 @@ impl<'a> Popup<'a> {
             area = area.default_width(width);
         }
 
+        if kind != PopupKind::Tooltip {
+            ctx.memory_mut(|mem| mem.restore_focus_when_closed(area.layer()));
+        }
+
         let mut response = area.show(&ctx, |ui| {
             style.apply(ui.style_mut());
             let frame = frame.unwrap_or_else(|| Frame::popup(ui.style()));
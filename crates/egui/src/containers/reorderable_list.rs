@@ -0,0 +1,483 @@
+//! See [`ReorderableList`] for docs.
+
+use std::hash::Hash;
+
+use crate::{
+    Align, Context, CursorIcon, DragAndDrop, Id, IdMap, Key, LayerId, Layout, Modifiers,
+    NumExt as _, Order, Rect, Response, Sense, StrokeKind, Ui, UiBuilder, Vec2, pos2,
+    style::ScrollAnimation, vec2,
+};
+
+/// How fast we scroll when dragging an item to the edge of a [`crate::ScrollArea`], in points per second.
+const AUTO_SCROLL_SPEED: f32 = 800.0;
+
+/// The [`DragAndDrop`] payload of an item being dragged.
+#[derive(Clone, Copy, Debug)]
+struct DragPayload {
+    group: Id,
+    list: Id,
+    index: usize,
+    item: Id,
+
+    /// Height of the item plus item spacing.
+    gap: f32,
+
+    /// Where the item was grabbed, relative to its top left corner.
+    grab_offset: Vec2,
+}
+
+/// Where an item was let go of, so it can animate from there to its new place.
+#[derive(Clone, Copy, Debug)]
+struct Dropped {
+    item: Id,
+    top: f32,
+    frame_nr: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ItemState {
+    height: f32,
+
+    /// Top of the item relative to the top of the list, without displacement.
+    top: f32,
+
+    /// How far the item was displaced, e.g. to make room for a dragged item.
+    offset: f32,
+}
+
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// The rect of the list last frame. This is where items can be dropped.
+    rect: Option<Rect>,
+
+    items: IdMap<ItemState>,
+}
+
+impl State {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_temp(id))
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+}
+
+/// A vertical list of items that can be sorted by dragging them.
+///
+/// While an item is dragged, the other items move out of the way to show where it will land,
+/// and the list scrolls when the item is dragged to the edge of a [`crate::ScrollArea`].
+/// A focused item can also be moved with `Alt+↑` and `Alt+↓`.
+///
+/// Items can be dragged between lists that share the same [`Self::group`], e.g. the columns of a
+/// Kanban board. Moves within a list are applied by [`Self::show`], but moves between lists are
+/// returned in [`ReorderableListResponse::moved`] for you to apply, since only you have access to
+/// both lists.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut todo = vec!["Buy milk".to_owned()];
+/// # let mut done = vec!["Write docs".to_owned()];
+/// let todo_list = egui::ReorderableList::new("todo").group("board");
+/// let done_list = egui::ReorderableList::new("done").group("board");
+///
+/// let moved = ui.columns(2, |columns| {
+///     let a = todo_list.show(&mut columns[0], &mut todo, |item| item.clone(), |ui, item| {
+///         ui.label(item.as_str());
+///     });
+///     let b = done_list.show(&mut columns[1], &mut done, |item| item.clone(), |ui, item| {
+///         ui.label(item.as_str());
+///     });
+///     a.moved.or(b.moved)
+/// });
+///
+/// if let Some(moved) = moved
+///     && !moved.is_within_list()
+/// {
+///     if moved.from_list == egui::Id::new("todo") {
+///         moved.apply_between(&mut todo, &mut done);
+///     } else {
+///         moved.apply_between(&mut done, &mut todo);
+///     }
+/// }
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use = "You should call .show()"]
+pub struct ReorderableList {
+    id: Id,
+    group: Option<Id>,
+}
+
+impl ReorderableList {
+    /// The id of the list is `Id::new(id_salt)`, so that you can tell lists apart in
+    /// [`ReorderMove`]. It must be unique.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            group: None,
+        }
+    }
+
+    /// Items can be dragged between lists in the same group.
+    ///
+    /// By default each list is its own group.
+    #[inline]
+    pub fn group(mut self, group: impl Hash) -> Self {
+        self.group = Some(Id::new(group));
+        self
+    }
+
+    /// The id of the list, as used in [`ReorderMove`].
+    #[inline]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Show the items, and let the user reorder them.
+    ///
+    /// `item_key` should return a key that identifies the item, even when it moves.
+    /// It must be unique within the group. It is also used as the id salt of the item.
+    pub fn show<T, K: Hash>(
+        self,
+        ui: &mut Ui,
+        items: &mut Vec<T>,
+        item_key: impl Fn(&T) -> K,
+        mut add_item: impl FnMut(&mut Ui, &mut T),
+    ) -> ReorderableListResponse {
+        let Self { id, group } = self;
+        let group = group.unwrap_or(id);
+        let ctx = ui.ctx().clone();
+        let mut state = State::load(&ctx, id).unwrap_or_default();
+
+        let keys: Vec<Id> = items
+            .iter()
+            .map(|item| group.with(item_key(item)))
+            .collect();
+        let spacing = ui.spacing().item_spacing.y;
+        let animation_time = ui.style().animation_time;
+        let origin = ui.cursor().min;
+        let width = ui.available_width();
+        let (pointer, released, press_origin) = ui.input(|i| {
+            (
+                i.pointer.interact_pos(),
+                i.pointer.any_released(),
+                i.pointer.press_origin(),
+            )
+        });
+
+        for (index, &key) in keys.iter().enumerate() {
+            if ctx.is_being_dragged(key) {
+                let previous = state.items.get(&key).copied();
+                let grab_offset = DragAndDrop::payload::<DragPayload>(&ctx)
+                    .filter(|payload| payload.item == key)
+                    .map(|payload| payload.grab_offset)
+                    .or_else(|| {
+                        let previous = previous?;
+                        Some(press_origin? - origin - vec2(0.0, previous.top + previous.offset))
+                    })
+                    .unwrap_or_default();
+                DragAndDrop::set_payload(
+                    &ctx,
+                    DragPayload {
+                        group,
+                        list: id,
+                        index,
+                        item: key,
+                        gap: previous.map_or(0.0, |previous| previous.height) + spacing,
+                        grab_offset,
+                    },
+                );
+            }
+        }
+
+        let payload = DragAndDrop::payload::<DragPayload>(&ctx)
+            .filter(|payload| payload.group == group)
+            .map(|payload| *payload);
+        let gap = payload.map_or(0.0, |payload| payload.gap);
+        let dragged_index = payload
+            .filter(|payload| payload.list == id)
+            .and_then(|payload| keys.iter().position(|key| *key == payload.item));
+        let hovered = payload.is_some()
+            && state
+                .rect
+                .is_some_and(|rect| ui.rect_contains_pointer(rect));
+
+        // Where the dragged item would be inserted, not counting the dragged item itself,
+        // and the top of the gap we leave for it:
+        let insertion = if hovered && let Some(pointer) = pointer {
+            let mut y = origin.y;
+            let mut insert_index = 0;
+            for (index, key) in keys.iter().enumerate() {
+                if Some(index) == dragged_index {
+                    continue;
+                }
+                let height = state.items.get(key).map_or(0.0, |item| item.height);
+                if pointer.y < y + height / 2.0 {
+                    break;
+                }
+                y += height + spacing;
+                insert_index += 1;
+            }
+            Some((insert_index, y))
+        } else {
+            None
+        };
+
+        let dropped_id = group.with("reorderable_list_dropped");
+        let frame_nr = ctx.cumulative_frame_nr();
+        let dropped = ctx
+            .data(|d| d.get_temp::<Dropped>(dropped_id))
+            .filter(|dropped| dropped.frame_nr + 1 == frame_nr);
+        if released
+            && let Some(payload) = payload
+            && let Some(pointer) = pointer
+        {
+            ctx.data_mut(|d| {
+                d.insert_temp(
+                    dropped_id,
+                    Dropped {
+                        item: payload.item,
+                        top: pointer.y - payload.grab_offset.y,
+                        frame_nr,
+                    },
+                );
+            });
+        }
+
+        let layout = Layout::top_down(Align::Min);
+        let mut new_items = IdMap::default();
+        let mut keyboard_move = None;
+        let mut y = origin.y;
+        let mut rank = 0; // Index of the item, not counting the dragged item.
+        let num_items = items.len();
+        for (index, item) in items.iter_mut().enumerate() {
+            let key = keys[index];
+            let top = y - origin.y;
+            let previous = state.items.get(&key).copied();
+            let is_dragged = Some(index) == dragged_index;
+
+            let target = if is_dragged {
+                0.0
+            } else {
+                let after_gap = insertion.is_some_and(|(insert_index, _)| insert_index <= rank);
+                let after_dragged = dragged_index.is_some_and(|dragged| dragged < index);
+                rank += 1;
+                gap * (f32::from(u8::from(after_gap)) - f32::from(u8::from(after_dragged)))
+            };
+
+            // Items that changed place start where they were last shown, and animate from there:
+            let offset_id = key.with("reorderable_list_offset");
+            if let Some(dropped) = dropped
+                && dropped.item == key
+            {
+                ctx.animate_value_with_time(offset_id, dropped.top - y, 0.0);
+            } else if let Some(previous) = previous {
+                if previous.top != top {
+                    let offset = previous.offset + previous.top - top;
+                    ctx.animate_value_with_time(offset_id, offset, 0.0);
+                }
+            } else {
+                ctx.animate_value_with_time(offset_id, target, 0.0);
+            }
+            let offset = ctx.animate_value_with_time(offset_id, target, animation_time);
+
+            let height = previous.map_or(0.0, |previous| previous.height);
+            let row_rect = Rect::from_min_size(pos2(origin.x, y + offset), vec2(width, height));
+
+            let min = if is_dragged {
+                if let Some(pointer) = pointer {
+                    pointer - payload.map_or(Vec2::ZERO, |payload| payload.grab_offset)
+                } else {
+                    row_rect.min
+                }
+            } else {
+                row_rect.min
+            };
+            let mut builder = UiBuilder::new()
+                .max_rect(Rect::from_min_size(min, vec2(width, f32::INFINITY)))
+                .layout(layout)
+                .id_salt(key);
+            if is_dragged {
+                builder = builder.layer_id(LayerId::new(Order::Tooltip, key));
+            } else if previous.is_some() {
+                let response = ui
+                    .interact(row_rect, key, Sense::click_and_drag())
+                    .on_hover_cursor(CursorIcon::Grab);
+                if response.clicked() {
+                    response.request_focus();
+                }
+                if response.has_focus() {
+                    ui.painter().rect_stroke(
+                        row_rect,
+                        ui.visuals().widgets.noninteractive.corner_radius,
+                        ui.visuals().selection.stroke,
+                        StrokeKind::Outside,
+                    );
+                    let (up, down) = ui.input_mut(|i| {
+                        (
+                            i.consume_key(Modifiers::ALT, Key::ArrowUp),
+                            i.consume_key(Modifiers::ALT, Key::ArrowDown),
+                        )
+                    });
+                    if up && 0 < index {
+                        keyboard_move = Some((index, index - 1));
+                    } else if down && index + 1 < num_items {
+                        keyboard_move = Some((index, index + 1));
+                    }
+                }
+            }
+
+            let mut child = ui.new_child(builder);
+            // Dragging the text should drag the item:
+            child.style_mut().interaction.selectable_labels = false;
+            add_item(&mut child, item);
+            let height = child.min_rect().height();
+
+            new_items.insert(
+                key,
+                ItemState {
+                    height,
+                    top,
+                    offset,
+                },
+            );
+            y += height + spacing;
+        }
+
+        // Make room for an item dragged in from another list:
+        let extra_height = if insertion.is_some() && dragged_index.is_none() {
+            gap
+        } else {
+            0.0
+        };
+        let extra_height = ctx.animate_value_with_time(
+            id.with("reorderable_list_extra"),
+            extra_height,
+            animation_time,
+        );
+        let height = (y - origin.y - spacing + extra_height).at_least(ui.spacing().interact_size.y);
+        let rect = Rect::from_min_size(origin, vec2(width, height));
+        let response = ui.allocate_rect(rect, Sense::hover());
+
+        if let Some((_, gap_top)) = insertion {
+            let y = (gap_top - spacing / 2.0).at_least(origin.y);
+            ui.painter()
+                .hline(rect.x_range(), y, ui.visuals().selection.stroke);
+        }
+
+        if payload.is_some()
+            && (hovered || dragged_index.is_some())
+            && let Some(pointer) = pointer
+        {
+            auto_scroll(ui, pointer.y);
+        }
+
+        let moved = if let Some((from_index, to_index)) = keyboard_move {
+            Some(ReorderMove {
+                from_list: id,
+                from_index,
+                to_list: id,
+                to_index,
+            })
+        } else if released
+            && let Some(payload) = payload
+            && let Some((to_index, _)) = insertion
+        {
+            Some(ReorderMove {
+                from_list: payload.list,
+                from_index: dragged_index.unwrap_or(payload.index),
+                to_list: id,
+                to_index,
+            })
+        } else {
+            None
+        };
+        if let Some(moved) = moved
+            && moved.is_within_list()
+        {
+            moved.apply(items);
+        }
+
+        state.rect = Some(rect);
+        state.items = new_items;
+        state.store(&ctx, id);
+
+        ReorderableListResponse { response, moved }
+    }
+}
+
+/// Scroll the enclosing [`crate::ScrollArea`] when the pointer is close to its edge.
+fn auto_scroll(ui: &Ui, pointer_y: f32) {
+    let clip_rect = ui.clip_rect();
+    let edge = (2.0 * ui.spacing().interact_size.y).at_most(clip_rect.height() / 4.0);
+    if edge <= 0.0 {
+        return;
+    }
+
+    let speed = if pointer_y < clip_rect.top() + edge {
+        (clip_rect.top() + edge - pointer_y) / edge
+    } else if clip_rect.bottom() - edge < pointer_y {
+        -(pointer_y - (clip_rect.bottom() - edge)) / edge
+    } else {
+        return;
+    };
+    let dt = ui.input(|i| i.stable_dt).at_most(0.1);
+    let delta = speed.clamp(-1.0, 1.0) * AUTO_SCROLL_SPEED * dt;
+    ui.scroll_with_delta_animation(vec2(0.0, delta), ScrollAnimation::none());
+    ui.ctx().request_repaint();
+}
+
+/// Returned by [`ReorderableList::show`].
+#[derive(Debug)]
+pub struct ReorderableListResponse {
+    /// The response of the whole list, which is where items can be dropped.
+    pub response: Response,
+
+    /// An item that was moved into this list this frame.
+    ///
+    /// Moves within the list have already been applied.
+    /// Moves from another list in the same [`ReorderableList::group`] are left for you to apply,
+    /// e.g. with [`ReorderMove::apply_between`].
+    pub moved: Option<ReorderMove>,
+}
+
+/// An item that was moved by the user, see [`ReorderableList`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReorderMove {
+    /// The [`ReorderableList::id`] of the list the item came from.
+    pub from_list: Id,
+
+    /// The index of the item in the list it came from.
+    pub from_index: usize,
+
+    /// The [`ReorderableList::id`] of the list the item was moved to.
+    pub to_list: Id,
+
+    /// The index of the item in the list it was moved to, after it was removed from its old place.
+    pub to_index: usize,
+}
+
+impl ReorderMove {
+    /// Was the item moved within one list?
+    #[inline]
+    pub fn is_within_list(&self) -> bool {
+        self.from_list == self.to_list
+    }
+
+    /// Move the item within `items`.
+    pub fn apply<T>(&self, items: &mut Vec<T>) {
+        if self.from_index < items.len() {
+            let item = items.remove(self.from_index);
+            items.insert(self.to_index.min(items.len()), item);
+        }
+    }
+
+    /// Move the item from one list to another.
+    pub fn apply_between<T>(&self, from: &mut Vec<T>, to: &mut Vec<T>) {
+        if self.from_index < from.len() {
+            let item = from.remove(self.from_index);
+            to.insert(self.to_index.min(to.len()), item);
+        }
+    }
+}
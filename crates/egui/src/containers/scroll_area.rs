@@ -20,6 +20,15 @@ struct ScrollingToTarget {
     target_offset: f32,
 }
 
+/// The widget or item that [`ScrollArea::scroll_anchoring`] keeps in place.
+#[derive(Clone, Copy, Debug)]
+struct ScrollAnchor {
+    id: Id,
+
+    /// Top of the anchor, relative to the top of the content.
+    content_y: f32,
+}
+
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
@@ -53,6 +62,10 @@ pub struct State {
 
     /// Area that can be dragged. This is the size of the content from the last frame.
     interact_rect: Option<Rect>,
+
+    /// See [`ScrollArea::scroll_anchoring`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    anchor: Option<ScrollAnchor>,
 }
 
 impl Default for State {
@@ -67,6 +80,7 @@ impl Default for State {
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
             interact_rect: None,
+            anchor: None,
         }
     }
 }
@@ -84,6 +98,19 @@ impl State {
     pub fn velocity(&self) -> Vec2 {
         self.vel
     }
+
+    /// The [`Id`] of the widget or item that [`ScrollArea::scroll_anchoring`] keeps in place.
+    ///
+    /// Content that only shows some of its items can use this to register the anchor
+    /// with [`Ui::scroll_anchor`], even if it isn't shown.
+    pub fn scroll_anchor(&self) -> Option<Id> {
+        self.anchor.map(|anchor| anchor.id)
+    }
+
+    /// Are we animating towards a scroll target, e.g. after [`Ui::scroll_to_rect`]?
+    pub(crate) fn is_animating(&self) -> bool {
+        self.offset_target.iter().any(Option::is_some)
+    }
 }
 
 pub struct ScrollAreaOutput<R> {
@@ -290,9 +317,9 @@ pub struct ScrollArea {
     min_scrolled_size: Vec2,
     scroll_bar_visibility: ScrollBarVisibility,
     scroll_bar_rect: Option<Rect>,
-    id_salt: Option<Id>,
+    pub(crate) id_salt: Option<Id>,
     offset_x: Option<f32>,
-    offset_y: Option<f32>,
+    pub(crate) offset_y: Option<f32>,
     on_hover_cursor: Option<CursorIcon>,
     on_drag_cursor: Option<CursorIcon>,
     scroll_source: ScrollSource,
@@ -307,6 +334,8 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    pub(crate) scroll_anchoring: bool,
 }
 
 impl ScrollArea {
@@ -355,6 +384,7 @@ impl ScrollArea {
             content_margin: None,
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            scroll_anchoring: false,
         }
     }
 
@@ -635,6 +665,23 @@ impl ScrollArea {
         self.stick_to_end[1] = stick;
         self
     }
+
+    /// Keep the content the user is looking at in place when content above it changes size,
+    /// e.g. when older messages are inserted at the top of a chat log.
+    ///
+    /// The [`ScrollArea`] remembers the first item that starts in view (the anchor),
+    /// and adjusts the vertical offset so that it stays where it was.
+    /// The anchor is found again by its [`Id`], so items should have ids that don't depend
+    /// on their position: put each item in [`Ui::push_id`] with a unique key,
+    /// or register it with [`Ui::scroll_anchor`].
+    /// If there are no such items, any widget can be the anchor.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn scroll_anchoring(mut self, scroll_anchoring: bool) -> Self {
+        self.scroll_anchoring = scroll_anchoring;
+        self
+    }
 }
 
 struct Prepared {
@@ -680,6 +727,12 @@ struct Prepared {
     /// not for us to handle so we save it and restore it after this [`ScrollArea`] is done.
     saved_scroll_target: [Option<pass_state::ScrollTarget>; 2],
 
+    /// Like [`Self::saved_scroll_target`], but for [`Ui::scroll_anchor`].
+    saved_scroll_anchors: Option<Vec<(Id, Rect)>>,
+
+    /// Widgets of the content layer from this index on were added by our content.
+    first_content_widget: usize,
+
     /// The response from dragging the background (if enabled)
     background_drag_response: Option<Response>,
 
@@ -705,6 +758,7 @@ impl ScrollArea {
             content_margin: _, // Used elsewhere
             stick_to_end,
             animated,
+            scroll_anchoring,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -781,6 +835,7 @@ impl ScrollArea {
                 .ui_stack_info(UiStackInfo::new(UiKind::ScrollArea))
                 .max_rect(content_max_rect),
         );
+        content_ui.scroll_anchoring = scroll_anchoring;
 
         {
             // Clip the content, but only when we really need to:
@@ -826,18 +881,18 @@ impl ScrollArea {
                         }
                     }
                 } else {
-                    // Apply the cursor velocity to the scroll area when the user releases the drag.
-                    if content_response_option
-                        .as_ref()
-                        .is_some_and(|response| response.drag_stopped())
+                    // Apply the fling velocity to the scroll area when the user releases the drag.
+                    if let Some(response) = &content_response_option
+                        && response.drag_stopped()
                     {
                         state.vel = direction_enabled.to_vec2()
-                            * ui.input(|input| input.pointer.velocity());
+                            * response.fling_velocity().unwrap_or_default();
                     }
+                    let interaction = &ui.style().interaction;
+                    let stop_speed = interaction.fling_min_speed;
+                    let friction_coeff = interaction.fling_friction;
                     for d in 0..2 {
                         // Kinetic scrolling
-                        let stop_speed = 20.0; // Pixels per second.
-                        let friction_coeff = 1000.0; // Pixels per second squared.
 
                         let friction = friction_coeff * dt;
                         if friction > state.vel[d].abs() || state.vel[d].abs() < stop_speed {
@@ -904,6 +959,16 @@ impl ScrollArea {
         let saved_scroll_target = content_ui
             .ctx()
             .pass_state_mut(|state| std::mem::take(&mut state.scroll_target));
+        let saved_scroll_anchors = content_ui.ctx().pass_state_mut(|state| {
+            std::mem::replace(&mut state.scroll_anchors, scroll_anchoring.then(Vec::new))
+        });
+        let first_content_widget = ctx.viewport(|viewport| {
+            viewport
+                .this_pass
+                .widgets
+                .get_layer(content_ui.layer_id())
+                .count()
+        });
 
         Prepared {
             id,
@@ -921,6 +986,8 @@ impl ScrollArea {
             wheel_scroll_multiplier,
             stick_to_end,
             saved_scroll_target,
+            saved_scroll_anchors,
+            first_content_widget,
             background_drag_response,
             animated,
         }
@@ -1052,12 +1119,52 @@ impl Prepared {
             wheel_scroll_multiplier,
             stick_to_end,
             saved_scroll_target,
+            saved_scroll_anchors,
+            first_content_widget,
             background_drag_response,
             animated,
         } = self;
 
         let content_size = content_ui.min_size();
 
+        // Restore the anchors of ScrollAreas up the stack, and take ours:
+        let scroll_anchors = ui.ctx().pass_state_mut(|state| {
+            std::mem::replace(&mut state.scroll_anchors, saved_scroll_anchors)
+        });
+        let anchor_candidates = match scroll_anchors {
+            Some(scroll_anchors) if !scroll_anchors.is_empty() => scroll_anchors,
+            Some(_) => ui.ctx().viewport(|viewport| {
+                viewport
+                    .this_pass
+                    .widgets
+                    .get_layer(content_ui.layer_id())
+                    .skip(first_content_widget)
+                    .map(|widget| (widget.id, widget.rect))
+                    .collect()
+            }),
+            None => vec![],
+        };
+        let content_top = content_ui.max_rect().top();
+
+        if let Some(anchor) = state.anchor
+            && direction_enabled[1]
+            && !(stick_to_end[1] && state.scroll_stuck_to_end[1])
+            && ui
+                .ctx()
+                .pass_state(|state| state.scroll_target[1].is_none())
+            && let Some((_, rect)) = anchor_candidates.iter().find(|(id, _)| *id == anchor.id)
+        {
+            // Content above the anchor changed size, so move with it:
+            let delta = (rect.top() - content_top) - anchor.content_y;
+            if delta.abs() > 0.5 {
+                state.offset.y += delta;
+                if let Some(target) = &mut state.offset_target[1] {
+                    target.target_offset += delta;
+                }
+                ui.ctx().request_discard("ScrollArea anchoring");
+            }
+        }
+
         let scroll_delta = content_ui
             .ctx()
             .pass_state_mut(|state| std::mem::take(&mut state.scroll_delta));
@@ -1502,6 +1609,11 @@ impl Prepared {
         state.show_scroll = show_scroll_this_frame;
         state.content_is_too_large = content_is_too_large;
         state.interact_rect = Some(inner_rect);
+        state.anchor = pick_scroll_anchor(
+            &anchor_candidates,
+            content_top,
+            Rangef::new(state.offset.y, state.offset.y + inner_rect.height()),
+        );
 
         state.store(ui.ctx(), id);
 
@@ -1509,6 +1621,22 @@ impl Prepared {
     }
 }
 
+/// The first candidate that starts in view, preferring later (more deeply nested) ones on ties.
+///
+/// Candidates taller than the view are probably containers of the items, so they are skipped.
+fn pick_scroll_anchor(
+    candidates: &[(Id, Rect)],
+    content_top: f32,
+    view: Rangef,
+) -> Option<ScrollAnchor> {
+    candidates
+        .iter()
+        .map(|(id, rect)| (*id, rect.top() - content_top, rect.height()))
+        .filter(|(_, top, height)| view.contains(*top) && *height <= view.span())
+        .min_by(|(_, a, _), (_, b, _)| a.total_cmp(b).then(std::cmp::Ordering::Greater))
+        .map(|(id, content_y, _)| ScrollAnchor { id, content_y })
+}
+
 /// Paint fade-out gradients at the top and/or bottom of a scroll area to
 /// indicate that more content is available beyond the visible region.
 fn paint_fade_areas<R>(ui: &Ui, scroll_output: &ScrollAreaOutput<R>) {
@@ -0,0 +1,362 @@
+//! See [`VirtualList`] for docs.
+
+use std::hash::Hash;
+use std::ops::Range;
+
+use crate::{
+    Align, Context, Id, IdMap, IdSet, NumExt as _, Rect, Ui, UiBuilder,
+    scroll_area::{self, ScrollArea, ScrollAreaOutput},
+};
+
+/// The row the user is looking at, so we can keep it in place when heights change.
+#[derive(Clone, Copy, Debug)]
+struct Anchor {
+    /// Key of the row at the top of the viewport.
+    key: Id,
+
+    /// How far down into that row the viewport starts (negative if the row starts below the top).
+    offset_into_row: f32,
+
+    /// The scroll offset we left the [`ScrollArea`] at.
+    scroll_offset: f32,
+}
+
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// Measured heights of rows, by key.
+    heights: IdMap<f32>,
+
+    /// Sum of [`Self::heights`], for the estimated height of rows that haven't been measured.
+    height_sum: f32,
+
+    /// The key of each row last frame.
+    keys: Vec<Id>,
+
+    /// `tops[i]` is the top of row `i`, and the last one is the total height plus one spacing.
+    ///
+    /// Only the tops up to the first row that changed are kept between frames.
+    tops: Vec<f32>,
+
+    /// The height of rows that haven't been measured, that [`Self::tops`] were computed with.
+    estimate: f32,
+
+    /// The spacing between rows that [`Self::tops`] were computed with.
+    spacing: f32,
+
+    /// The width the heights were measured at.
+    width: f32,
+
+    /// Height of the viewport last frame.
+    viewport_height: f32,
+
+    anchor: Option<Anchor>,
+
+    /// The row we are scrolling to, see [`VirtualList::scroll_to_item`].
+    scroll_to: Option<(Id, Option<Align>)>,
+}
+
+impl State {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.remove_temp(id))
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+}
+
+/// Efficiently show a large number of rows of different heights in a [`ScrollArea`].
+///
+/// Unlike [`ScrollArea::show_rows`], the rows don't need to have the same height.
+/// Rows are measured the first time they are shown, and the heights are cached by the key of
+/// each row, so rows can be inserted and removed. Rows that haven't been shown yet are
+/// assumed to have the average height of the measured rows.
+///
+/// When measuring corrects the estimated height of rows above the viewport,
+/// the scroll offset is adjusted so that the visible rows stay where they are.
+///
+/// `item_key` is called for every row each frame, but the layout is only computed again
+/// from the first row that changed, and only visible rows are shown.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let messages = vec!["Hi".to_owned(); 10_000];
+/// egui::VirtualList::new().show(
+///     ui,
+///     messages.len(),
+///     |index| index,
+///     |ui, index| {
+///         ui.label(&messages[index]);
+///     },
+/// );
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "You should call .show()"]
+pub struct VirtualList {
+    scroll_area: ScrollArea,
+    estimated_row_height: Option<f32>,
+    scroll_to_item: Option<(usize, Option<Align>)>,
+}
+
+impl Default for VirtualList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VirtualList {
+    /// A virtual list in a [`ScrollArea::vertical`].
+    pub fn new() -> Self {
+        Self {
+            scroll_area: ScrollArea::vertical(),
+            estimated_row_height: None,
+            scroll_to_item: None,
+        }
+    }
+
+    /// Use this [`ScrollArea`], e.g. to set an id salt or stick to the bottom.
+    ///
+    /// Only vertical virtualization is supported.
+    /// The list always keeps the visible rows in place,
+    /// so [`ScrollArea::scroll_anchoring`] is turned off.
+    #[inline]
+    pub fn scroll_area(mut self, scroll_area: ScrollArea) -> Self {
+        self.scroll_area = scroll_area;
+        self
+    }
+
+    /// The height to assume for rows before any row has been measured.
+    ///
+    /// Default is [`crate::style::Spacing::interact_size`].
+    #[inline]
+    pub fn estimated_row_height(mut self, estimated_row_height: f32) -> Self {
+        self.estimated_row_height = Some(estimated_row_height);
+        self
+    }
+
+    /// Scroll to the row with the given index.
+    ///
+    /// Call this once. The list keeps scrolling to the row over the next frames,
+    /// until the heights of the rows around it have been measured.
+    ///
+    /// See [`Ui::scroll_to_rect`] for the meaning of `align`.
+    #[inline]
+    pub fn scroll_to_item(mut self, index: usize, align: Option<Align>) -> Self {
+        self.scroll_to_item = Some((index, align));
+        self
+    }
+
+    /// Show the rows that are visible.
+    ///
+    /// `item_key` should return a key that identifies the row, even if rows are inserted or
+    /// removed. It is used to cache the heights, and as the id salt of the row.
+    ///
+    /// The inner output is the range of rows that were shown.
+    pub fn show<K: Hash>(
+        self,
+        ui: &mut Ui,
+        total_rows: usize,
+        item_key: impl Fn(usize) -> K,
+        mut add_row: impl FnMut(&mut Ui, usize),
+    ) -> ScrollAreaOutput<Range<usize>> {
+        let Self {
+            mut scroll_area,
+            estimated_row_height,
+            scroll_to_item,
+        } = self;
+
+        // We keep the rows in place ourselves, and must not correct the offset twice:
+        scroll_area.scroll_anchoring = false;
+
+        let id_salt = scroll_area
+            .id_salt
+            .unwrap_or_else(|| Id::new("scroll_area"));
+        let scroll_id = ui.make_persistent_id(id_salt);
+        let id = scroll_id.with("virtual_list");
+        let mut state = State::load(ui.ctx(), id).unwrap_or_default();
+
+        let width = ui.available_width();
+        if state.width != width {
+            // Wrapped rows change their height:
+            state.heights.clear();
+            state.height_sum = 0.0;
+            state.tops.clear();
+            state.width = width;
+        }
+
+        // Update the keys in place, and forget the tops from the first row that changed:
+        let mut first_changed = total_rows.min(state.keys.len());
+        state.keys.truncate(total_rows);
+        for index in 0..total_rows {
+            let key = Id::new(item_key(index));
+            match state.keys.get_mut(index) {
+                Some(old_key) if *old_key == key => {}
+                Some(old_key) => {
+                    *old_key = key;
+                    first_changed = first_changed.min(index);
+                }
+                None => state.keys.push(key),
+            }
+        }
+        if state.keys.len() < state.heights.len() {
+            let keys: IdSet = state.keys.iter().copied().collect();
+            state.heights.retain(|key, _| keys.contains(key));
+            state.height_sum = state.heights.values().sum();
+        }
+
+        let spacing = ui.spacing().item_spacing.y;
+        let estimate = if state.heights.is_empty() {
+            estimated_row_height.unwrap_or_else(|| ui.spacing().interact_size.y)
+        } else {
+            state.height_sum / state.heights.len() as f32
+        };
+        if state.spacing != spacing {
+            state.tops.clear();
+            state.spacing = spacing;
+        }
+        if state.estimate != estimate {
+            // The rows that haven't been measured moved:
+            first_changed = first_changed.min(
+                state
+                    .keys
+                    .iter()
+                    .position(|key| !state.heights.contains_key(key))
+                    .unwrap_or(total_rows),
+            );
+            state.estimate = estimate;
+        }
+        state.tops.truncate(first_changed + 1);
+
+        let keys = std::mem::take(&mut state.keys);
+        let mut tops = std::mem::take(&mut state.tops);
+        if tops.is_empty() {
+            tops.push(0.0);
+        }
+        let mut y = tops[tops.len() - 1];
+        for key in &keys[tops.len() - 1..] {
+            y += state.heights.get(key).copied().unwrap_or(estimate) + spacing;
+            tops.push(y);
+        }
+        let total_height = (y - spacing).at_least(0.0);
+        let row_at = |y: f32| {
+            tops.partition_point(|&top| top <= y)
+                .saturating_sub(1)
+                .min(total_rows.saturating_sub(1))
+        };
+
+        let scroll_offset = scroll_area::State::load(ui.ctx(), scroll_id).map(|s| s.offset.y);
+        if let Some((index, align)) = scroll_to_item
+            && let Some(key) = keys.get(index)
+        {
+            state.scroll_to = Some((*key, align));
+        }
+        let scroll_to = state
+            .scroll_to
+            .and_then(|(key, align)| Some((keys.iter().position(|k| *k == key)?, align)));
+
+        if let Some((index, align)) = scroll_to {
+            let top = tops[index];
+            let height = tops[index + 1] - spacing - top;
+            let viewport_height = state.viewport_height;
+            let current = scroll_offset.unwrap_or(0.0);
+            if top + height < current || current + viewport_height < top {
+                // Jump close to the row, so it is shown and measured.
+                // We scroll to its actual rect when we show it below.
+                let align = align.unwrap_or(if top < current {
+                    Align::Min
+                } else {
+                    Align::Max
+                });
+                let target = top - (viewport_height - height) * align.to_factor();
+                scroll_area = scroll_area.vertical_scroll_offset(target.at_least(0.0));
+            }
+        } else if scroll_area.offset_y.is_none()
+            && let Some(anchor) = state.anchor
+            && let Some(scroll_offset) = scroll_offset
+            && let Some(index) = keys.iter().position(|key| *key == anchor.key)
+        {
+            // Keep the anchor row in place, even if the rows above it changed height:
+            let user_scroll = scroll_offset - anchor.scroll_offset;
+            let offset = tops[index] + anchor.offset_into_row + user_scroll;
+            if offset != scroll_offset {
+                scroll_area = scroll_area.vertical_scroll_offset(offset.at_least(0.0));
+            }
+        }
+
+        let mut first_new_height = None;
+        let mut scrolled_to_row = false;
+        let output = scroll_area.show_viewport(ui, |ui, viewport| {
+            ui.set_min_height(total_height);
+            if total_rows == 0 {
+                return 0..0;
+            }
+
+            let first_row = row_at(viewport.min.y);
+            let origin = ui.max_rect().top();
+            let rect = Rect::from_x_y_ranges(
+                ui.max_rect().x_range(),
+                origin + tops[first_row]..=f32::INFINITY,
+            );
+
+            ui.scope_builder(UiBuilder::new().max_rect(rect), |ui| {
+                let mut row = first_row;
+                while row < total_rows && ui.cursor().top() <= origin + viewport.max.y {
+                    let key = keys[row];
+                    let response = ui
+                        .scope_builder(UiBuilder::new().id_salt(key), |ui| add_row(ui, row))
+                        .response;
+
+                    let height = response.rect.height();
+                    let old_height = state.heights.insert(key, height);
+                    if old_height != Some(height) {
+                        state.height_sum += height - old_height.unwrap_or(0.0);
+                        first_new_height.get_or_insert(row);
+                    }
+                    if let Some((index, align)) = scroll_to
+                        && index == row
+                    {
+                        ui.scroll_to_rect(response.rect, align);
+                        scrolled_to_row = true;
+                    }
+                    row += 1;
+                }
+                first_row..row
+            })
+            .inner
+        });
+
+        // Keep scrolling to the row until the heights around it are known,
+        // and we arrived, since the rect of the row moves while we measure:
+        let measured_new_height = first_new_height.is_some();
+        let arrived = scrolled_to_row && !measured_new_height && !output.state.is_animating();
+        if scroll_to.is_none() || arrived {
+            state.scroll_to = None;
+        }
+
+        // Anchor to the first row that starts in view,
+        // so that rows that are partially scrolled out of view can grow upwards:
+        let scroll_offset = output.state.offset.y;
+        let row = tops
+            .partition_point(|&top| top < scroll_offset)
+            .min(total_rows.saturating_sub(1));
+        state.anchor = (total_rows > 0).then(|| Anchor {
+            key: keys[row],
+            offset_into_row: scroll_offset - tops[row],
+            scroll_offset,
+        });
+        state.viewport_height = output.inner_rect.height();
+        if let Some(row) = first_new_height {
+            tops.truncate(row + 1);
+        }
+        state.keys = keys;
+        state.tops = tops;
+        if measured_new_height || state.scroll_to.is_some() {
+            // Lay out again with the measured heights:
+            ui.ctx().request_repaint();
+        }
+        state.store(ui.ctx(), id);
+
+        output
+    }
+}
@@ -1362,8 +1362,10 @@ impl TitleBar {
 fn close_button(ui: &mut Ui, rect: Rect) -> Response {
     let close_id = ui.auto_id_with("window_close_button");
     let response = ui.interact(rect, close_id, Sense::click());
+    // Not inside `widget_info`, which holds the context lock:
+    let label = ui.ctx().tr("egui-window-close");
     response
-        .widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), "Close window"));
+        .widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), label.clone()));
 
     ui.expand_to_include_rect(response.rect);
 
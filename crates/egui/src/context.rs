@@ -405,6 +405,11 @@ struct ContextImpl {
     is_accesskit_enabled: bool,
 
     loaders: Arc<Loaders>,
+
+    /// Registered with [`Context::custom_cursor`].
+    custom_cursors: IdMap<Arc<crate::CursorImage>>,
+
+    localization: crate::i18n::Localization,
 }
 
 impl ContextImpl {
@@ -459,7 +464,7 @@ impl ContextImpl {
             pixels_per_point,
             self.memory.options.input_options,
         );
-        let repaint_after = viewport.input.wants_repaint_after();
+        let mut repaint_after = viewport.input.wants_repaint_after();
 
         let content_rect = viewport.input.content_rect();
 
@@ -483,13 +488,26 @@ impl ContextImpl {
                 WidgetHits::default()
             };
 
+            let interaction_style = self.memory.options.style().interaction.clone();
             viewport.interact_widgets = crate::interaction::interact(
                 &viewport.interact_widgets,
                 &viewport.prev_pass.widgets,
                 &viewport.hits,
                 &viewport.input,
                 self.memory.interaction_mut(),
+                &interaction_style,
             );
+
+            // Wake up to check for a long-press:
+            if let Some(remaining) = crate::interaction::long_press_remaining(
+                &viewport.input,
+                self.memory.interaction(),
+                &interaction_style,
+            ) && 0.0 < remaining
+            {
+                let delay = Duration::from_secs_f32(remaining);
+                repaint_after = Some(repaint_after.map_or(delay, |after| after.min(delay)));
+            }
         }
 
         // Ensure we register the background area so panels and background ui can catch clicks:
@@ -725,6 +743,17 @@ impl std::cmp::PartialEq for Context {
     }
 }
 
+/// A reference to a [`Context`] that doesn't keep it alive.
+#[derive(Clone)]
+pub(crate) struct WeakContext(std::sync::Weak<RwLock<ContextImpl>>);
+
+impl WeakContext {
+    /// `None` if the [`Context`] has been dropped.
+    pub(crate) fn upgrade(&self) -> Option<Context> {
+        self.0.upgrade().map(Context)
+    }
+}
+
 impl Default for Context {
     fn default() -> Self {
         let ctx_impl = ContextImpl {
@@ -740,6 +769,7 @@ impl Default for Context {
         ctx.add_plugin(crate::debug_text::DebugTextPlugin::default());
         ctx.add_plugin(crate::text_selection::LabelSelectionState::default());
         ctx.add_plugin(crate::DragAndDrop::default());
+        ctx.add_plugin(crate::promise::Promises::default());
 
         ctx
     }
@@ -756,6 +786,10 @@ impl Context {
         writer(&mut self.0.write())
     }
 
+    pub(crate) fn downgrade(&self) -> WeakContext {
+        WeakContext(Arc::downgrade(&self.0))
+    }
+
     /// Run the ui code for one frame.
     ///
     /// At most [`Options::max_passes`] calls will be issued to `run_ui`,
@@ -1443,6 +1477,14 @@ impl Context {
                     Flags::DRAG_STOPPED,
                     Some(id) == viewport.interact_widgets.drag_stopped,
                 );
+                res.flags.set(
+                    Flags::LONG_PRESSED,
+                    Some(id) == viewport.interact_widgets.long_pressed,
+                );
+                res.flags.set(
+                    Flags::DOUBLE_TAP_DRAGGED,
+                    Some(id) == viewport.interact_widgets.double_tap_dragged,
+                );
             }
 
             let clicked = Some(id) == viewport.interact_widgets.clicked;
@@ -1580,6 +1622,30 @@ impl Context {
         self.output_mut(|o| o.cursor_icon = cursor_icon);
     }
 
+    /// Register an image to use as the mouse cursor, and get the [`CursorIcon`] that shows it.
+    ///
+    /// You can call this every frame: the integration only uploads the image again when it changes.
+    /// The image stays registered under `id` until replaced.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let image = egui::ColorImage::filled([16, 16], egui::Color32::RED);
+    /// let cursor = ui.ctx().custom_cursor(
+    ///     egui::Id::new("red square"),
+    ///     egui::CursorImage::new(image, [8, 8]).with_fallback(egui::CursorIcon::Crosshair),
+    /// );
+    /// ui.label("Hover me").on_hover_cursor(cursor);
+    /// # });
+    /// ```
+    pub fn custom_cursor(&self, id: Id, image: crate::CursorImage) -> CursorIcon {
+        self.write(|ctx| {
+            if ctx.custom_cursors.get(&id).is_none_or(|old| **old != image) {
+                ctx.custom_cursors.insert(id, Arc::new(image));
+            }
+        });
+        CursorIcon::Custom(id)
+    }
+
     /// Add a command to [`PlatformOutput::commands`],
     /// for the integration to execute at the end of the frame.
     pub fn send_cmd(&self, cmd: crate::OutputCommand) {
@@ -1616,6 +1682,29 @@ impl Context {
         self.send_cmd(crate::OutputCommand::CopyImage(image));
     }
 
+    /// Copy the given text to the system clipboard, together with an HTML version of it.
+    ///
+    /// Apps that understand HTML (e.g. word processors) will paste the styled text,
+    /// others the plain text. Integrations that don't support HTML only copy the plain text.
+    ///
+    /// Note that in web applications, the clipboard is only accessible in secure contexts (e.g.,
+    /// HTTPS or localhost). If this method is used outside of a secure context, it will log an
+    /// error and do nothing. See <https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts>.
+    pub fn copy_rich(&self, text: String, html: String) {
+        self.send_cmd(crate::OutputCommand::CopyRich { text, html });
+    }
+
+    /// Copy the given data to the system clipboard, e.g. text together with an image of it.
+    ///
+    /// The integration puts as many of the representations on the clipboard as it supports.
+    ///
+    /// Note that in web applications, the clipboard is only accessible in secure contexts (e.g.,
+    /// HTTPS or localhost). If this method is used outside of a secure context, it will log an
+    /// error and do nothing. See <https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts>.
+    pub fn copy_data(&self, data: crate::ClipboardData) {
+        self.send_cmd(crate::OutputCommand::CopyData(data));
+    }
+
     fn can_show_modifier_symbols(&self) -> bool {
         let ModifierNames {
             alt,
@@ -2476,9 +2565,13 @@ impl Context {
                 let InteractionSnapshot {
                     clicked,
                     long_touched: _,
+                    long_pressed: _,
                     drag_started: _,
                     dragged,
                     drag_stopped: _,
+                    double_tap_dragged: _,
+                    swiped: _,
+                    flung: _,
                     contains_pointer,
                     hovered,
                 } = interact_widgets;
@@ -2610,6 +2703,10 @@ impl ContextImpl {
         let textures_delta = self.tex_manager.0.write().take_delta();
 
         let mut platform_output: PlatformOutput = std::mem::take(&mut viewport.output);
+        platform_output.cursor_image = match platform_output.cursor_icon {
+            CursorIcon::Custom(id) => self.custom_cursors.get(&id).cloned(),
+            _ => None,
+        };
 
         {
             profiling::scope!("accesskit");
@@ -2640,7 +2737,11 @@ impl ContextImpl {
             .graphics
             .drain(self.memory.areas().order(), &self.memory.to_global);
 
-        let mut repaint_needed = false;
+        // e.g. the user pressed Tab, so we need another pass to show the new focus:
+        let mut repaint_needed = self
+            .memory
+            .focus()
+            .is_some_and(|focus| focus.has_pending_change());
 
         if self.memory.options.repaint_on_widget_change {
             profiling::scope!("compare-widget-rects");
@@ -3941,6 +4042,151 @@ impl Context {
     }
 }
 
+/// ## Localization
+impl Context {
+    /// Read the translations and the current language.
+    ///
+    /// See [`crate::i18n`].
+    pub fn localization<R>(&self, reader: impl FnOnce(&crate::i18n::Localization) -> R) -> R {
+        self.read(move |ctx| reader(&ctx.localization))
+    }
+
+    /// The current language, e.g. `"en"` (the default) or `"pt-BR"`.
+    pub fn language(&self) -> String {
+        self.localization(|l| l.language().to_owned())
+    }
+
+    /// Switch to another language, e.g. `"sv"` or `"pt-BR"`.
+    ///
+    /// Numbers in e.g. [`crate::DragValue`] and [`crate::Slider`] are formatted for the language,
+    /// by replacing [`crate::Style::number_formatter`] with [`crate::style::NumberFormatter::for_language`]
+    /// in all styles. A custom formatter installed by the app is left as is.
+    ///
+    /// The current pass is discarded (see [`Self::request_discard`]),
+    /// so that no text laid out in the old language lingers, and all viewports are repainted.
+    pub fn set_language(&self, language: impl Into<String>) {
+        let language = language.into();
+        if self.language() == language {
+            return;
+        }
+
+        let formatter = crate::style::NumberFormatter::for_language(&language);
+        self.write(|ctx| ctx.localization.set_language(language));
+        self.all_styles_mut(|style| {
+            if style.number_formatter.follows_language() {
+                style.number_formatter = formatter.clone();
+            }
+        });
+        self.request_discard("Language changed");
+
+        let cause = RepaintCause::new();
+        self.write(|ctx| {
+            #[expect(clippy::iter_over_hash_type)]
+            for viewport_id in ctx.all_viewport_ids() {
+                ctx.request_repaint(viewport_id, cause.clone());
+            }
+        });
+    }
+
+    /// Add translations, replacing any messages with the same ids in the same language.
+    ///
+    /// Add the English messages of your app too, so that other languages can fall back to them.
+    pub fn add_messages(&self, bundle: crate::i18n::MessageBundle) {
+        self.write(|ctx| ctx.localization.add_bundle(bundle));
+        self.request_repaint();
+    }
+
+    /// The message with this id in the current language.
+    ///
+    /// Returns the id itself if there is no such message.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// assert_eq!(ctx.tr("egui-window-close"), "Close window");
+    /// ```
+    pub fn tr(&self, id: &str) -> String {
+        self.tr_args(id, &crate::i18n::MessageArgs::new())
+    }
+
+    /// The message with this id in the current language, with the given values for its variables.
+    ///
+    /// See [`crate::i18n`] for an example.
+    pub fn tr_args(&self, id: &str, args: &crate::i18n::MessageArgs) -> String {
+        self.localization(|l| l.format(id, args))
+    }
+}
+
+/// ## Promises
+impl Context {
+    /// Run a future, and get its output once it is ready.
+    ///
+    /// Call this every frame you want the output: `start` is only called the first time,
+    /// and `None` is returned until the future has finished.
+    /// The future is polled by egui, and a repaint is requested whenever it is woken.
+    ///
+    /// egui does not come with an executor, so this works with any future that makes progress
+    /// when woken, e.g. the `JoinHandle` of a task spawned on your async runtime,
+    /// or the receiving end of a channel. For blocking work, use [`Self::promise_blocking`].
+    ///
+    /// The promise is cancelled (the future is dropped) if it is not asked for during a pass,
+    /// e.g. because the widget showing it was hidden, or if the same `id` is used for a promise
+    /// with another output type.
+    /// Asking for it again starts it over. Use [`Self::forget_promise`] to restart it directly.
+    ///
+    /// The output is cloned each frame, so wrap large values in an [`Arc`].
+    /// See also [`Ui::async_value`].
+    ///
+    /// The future must be [`Send`]. Use [`Self::promise_local`] for futures that aren't,
+    /// like most futures on the web.
+    pub fn promise<T, F>(&self, id: Id, start: impl FnOnce() -> F) -> Option<T>
+    where
+        T: Clone + Send + Sync + 'static,
+        F: std::future::Future<Output = T> + Send + 'static,
+    {
+        crate::promise::poll(self, id, || Box::pin(start()))
+    }
+
+    /// Like [`Self::promise`], but for futures that aren't [`Send`].
+    ///
+    /// This is what you want on the web, where e.g. `JsFuture` and `fetch` futures aren't [`Send`].
+    /// The future is kept on the current thread, so the promise must always be asked for
+    /// from the same thread (usually the ui thread). Its output must still be [`Send`].
+    pub fn promise_local<T, F>(&self, id: Id, start: impl FnOnce() -> F) -> Option<T>
+    where
+        T: Clone + Send + Sync + 'static,
+        F: std::future::Future<Output = T> + 'static,
+    {
+        crate::promise::poll(self, id, || crate::promise::local(start()))
+    }
+
+    /// Run a blocking closure on a new thread, and get its output once it is ready.
+    ///
+    /// Useful for e.g. loading files. Otherwise like [`Self::promise`],
+    /// except that cancelling can't stop the thread: its output is just ignored.
+    ///
+    /// Returns `Some(Err)` if the thread couldn't be spawned, or if `compute` panicked.
+    ///
+    /// Not available on the web, since it has no threads.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn promise_blocking<T>(
+        &self,
+        id: Id,
+        compute: impl FnOnce() -> T + Send + 'static,
+    ) -> Option<Result<T, String>>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        crate::promise::poll(self, id, || crate::promise::spawn_thread(compute))
+    }
+
+    /// Cancel the promise with this id, so that the next call to [`Self::promise`] starts it over.
+    ///
+    /// Use this to e.g. reload some data.
+    pub fn forget_promise(&self, id: Id) {
+        crate::promise::forget(self, id);
+    }
+}
+
 /// ## Viewports
 impl Context {
     /// Return the `ViewportId` of the current viewport.
@@ -437,6 +437,25 @@ pub enum Event {
     /// The integration detected a "paste" event (e.g. Cmd+V).
     Paste(String),
 
+    /// The integration detected a "paste" event, and there was an image on the clipboard.
+    ///
+    /// This can come together with [`Self::Paste`] and [`Self::PasteData`],
+    /// if the clipboard has several representations of the same content.
+    PasteImage(std::sync::Arc<crate::ColorImage>),
+
+    /// The integration detected a "paste" event, and the clipboard had data of this mime type,
+    /// e.g. `text/html`.
+    ///
+    /// This can come together with [`Self::Paste`] and [`Self::PasteImage`],
+    /// if the clipboard has several representations of the same content.
+    ///
+    /// Custom formats put on the clipboard with [`crate::ClipboardData::data`] are usually
+    /// only pasted within the same app.
+    PasteData {
+        mime: String,
+        bytes: std::sync::Arc<[u8]>,
+    },
+
     /// Text input, e.g. via keyboard.
     ///
     /// When the user presses enter/return, do not send a [`Text`](Event::Text) (just [`Key::Enter`]).
@@ -554,6 +573,19 @@ pub enum Event {
         force: Option<f32>,
     },
 
+    /// On pen tablets and stylus screens, report this *in addition to*
+    /// and right before [`Self::PointerMoved`] and [`Self::PointerButton`].
+    ///
+    /// The pen should also move the pointer as usual,
+    /// with the tip acting as [`PointerButton::Primary`].
+    Pen {
+        /// Position of the pen tip.
+        pos: Pos2,
+
+        /// Pressure, tilt, buttons etc.
+        info: PenInfo,
+    },
+
     /// A raw mouse wheel event as sent by the backend.
     ///
     /// Used for scrolling.
@@ -1228,6 +1260,75 @@ impl RawInput {
     }
 }
 
+/// What kind of device is controlling the pointer.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PointerKind {
+    /// A mouse, trackpad, or anything else that isn't reported as touch or pen.
+    #[default]
+    Mouse,
+
+    /// A finger on a touch screen, reported with [`Event::Touch`].
+    Touch,
+
+    /// A pen (stylus), reported with [`Event::Pen`].
+    Pen,
+}
+
+/// The state of a pen (stylus), sent with [`Event::Pen`].
+///
+/// Fields that the platform doesn't report are left at their defaults.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PenInfo {
+    /// How hard the pen is pressed, from 0.0 (hovering) to 1.0 (maximum pressure).
+    pub pressure: f32,
+
+    /// The angle between the pen and the surface, in radians.
+    ///
+    /// This is `π/2` when the pen is perpendicular to the surface,
+    /// and approaches zero as the pen is tilted towards the surface.
+    pub altitude_angle: Option<f32>,
+
+    /// The direction the pen is tilted towards, in radians.
+    ///
+    /// Zero is towards positive x (right), and `π/2` is towards positive y (down).
+    pub azimuth_angle: Option<f32>,
+
+    /// Is the eraser end of the pen being used?
+    pub eraser: bool,
+
+    /// Is the button on the side of the pen held down?
+    pub barrel_button: bool,
+
+    /// How far the pen is hovering above the surface,
+    /// from 0.0 (touching) to 1.0 (at the edge of detection).
+    ///
+    /// `None` if unknown.
+    pub hover_distance: Option<f32>,
+}
+
+impl PenInfo {
+    /// Is the pen tip pressed against the surface?
+    #[inline]
+    pub fn is_touching(&self) -> bool {
+        0.0 < self.pressure
+    }
+
+    /// The tilt of the pen as a vector in the direction the pen is tilted towards,
+    /// i.e. the pen projected onto the surface.
+    ///
+    /// The length is zero when the pen is perpendicular to the surface,
+    /// and approaches one as the pen is tilted towards the surface.
+    ///
+    /// `None` if the platform doesn't report the tilt.
+    pub fn tilt(&self) -> Option<Vec2> {
+        let altitude_angle = self.altitude_angle?;
+        let azimuth_angle = self.azimuth_angle.unwrap_or(0.0);
+        Some(altitude_angle.cos() * Vec2::angled(azimuth_angle))
+    }
+}
+
 /// this is a `u64` as values of this kind can always be obtained by hashing
 #[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
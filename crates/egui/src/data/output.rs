@@ -1,5 +1,7 @@
 //! All the data egui returns to the backend at the end of each frame.
 
+use std::sync::Arc;
+
 use crate::{OrderedViewportIdMap, RepaintCause, ViewportOutput, WidgetType};
 
 /// What egui emits each frame from [`crate::Context::run`].
@@ -95,10 +97,95 @@ pub enum OutputCommand {
     /// Put this image to the system clipboard.
     CopyImage(crate::ColorImage),
 
+    /// Put this text to the system clipboard, together with an HTML version of it.
+    ///
+    /// Integrations that can't put HTML on the clipboard should treat this as [`Self::CopyText`].
+    ///
+    /// See [`crate::Context::copy_rich`].
+    CopyRich {
+        /// Plain text.
+        text: String,
+
+        /// The same text as HTML, e.g. with its colors and fonts.
+        html: String,
+    },
+
+    /// Put this data to the system clipboard, in as many of its representations as the
+    /// platform supports.
+    CopyData(ClipboardData),
+
     /// Open this url in a browser.
     OpenUrl(OpenUrl),
 }
 
+/// Something to put on the clipboard, in one or more representations.
+///
+/// The app that pastes it picks the representation it understands best,
+/// so include plain [`Self::text`] whenever you can.
+///
+/// See [`OutputCommand::CopyData`] and [`crate::Context::copy_data`].
+///
+/// ```
+/// let data = egui::ClipboardData::from_text("Hello")
+///     .with_html("<b>Hello</b>")
+///     .with_data("application/x-my-app", vec![1, 2, 3]);
+/// assert_eq!(data.text.as_deref(), Some("Hello"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ClipboardData {
+    /// Plain text.
+    pub text: Option<String>,
+
+    /// The same content as HTML, e.g. styled text.
+    pub html: Option<String>,
+
+    /// The same content as an image.
+    pub image: Option<crate::ColorImage>,
+
+    /// Other representations, as mime types and bytes.
+    ///
+    /// Most platforms don't support custom formats, so these may only be pasted within the
+    /// same app, see [`crate::Event::PasteData`].
+    pub data: Vec<(String, Arc<[u8]>)>,
+}
+
+impl ClipboardData {
+    /// Plain text, which you can add other representations to.
+    pub fn from_text(text: impl Into<String>) -> Self {
+        Self {
+            text: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Add an HTML representation.
+    #[inline]
+    pub fn with_html(mut self, html: impl Into<String>) -> Self {
+        self.html = Some(html.into());
+        self
+    }
+
+    /// Add an image representation.
+    #[inline]
+    pub fn with_image(mut self, image: crate::ColorImage) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Add a representation with the given mime type.
+    #[inline]
+    pub fn with_data(mut self, mime: impl Into<String>, bytes: impl Into<Arc<[u8]>>) -> Self {
+        self.data.push((mime.into(), bytes.into()));
+        self
+    }
+
+    /// Is there nothing to copy?
+    pub fn is_empty(&self) -> bool {
+        self.text.is_none() && self.html.is_none() && self.image.is_none() && self.data.is_empty()
+    }
+}
+
 /// The non-rendering part of what egui emits each frame.
 ///
 /// You can access (and modify) this with [`crate::Context::output`].
@@ -113,6 +200,9 @@ pub struct PlatformOutput {
     /// Set the cursor to this icon.
     pub cursor_icon: CursorIcon,
 
+    /// The image to use if [`Self::cursor_icon`] is [`CursorIcon::Custom`].
+    pub cursor_image: Option<Arc<CursorImage>>,
+
     /// Events that may be useful to e.g. a screen reader.
     pub events: Vec<OutputEvent>,
 
@@ -149,7 +239,14 @@ pub struct PlatformOutput {
 
 impl PlatformOutput {
     /// This can be used by a text-to-speech system to describe the events (if any).
-    pub fn events_description(&self) -> String {
+    ///
+    /// This is in the language of the given [`crate::Context`].
+    pub fn events_description(&self, ctx: &crate::Context) -> String {
+        ctx.localization(|localization| self.localized_events_description(localization))
+    }
+
+    /// Like [`Self::events_description`], in the language of the given [`crate::i18n::Localization`].
+    pub fn localized_events_description(&self, localization: &crate::i18n::Localization) -> String {
         // only describe last event:
         if let Some(event) = self.events.iter().next_back() {
             match event {
@@ -159,7 +256,7 @@ impl PlatformOutput {
                 | OutputEvent::FocusGained(widget_info)
                 | OutputEvent::TextSelectionChanged(widget_info)
                 | OutputEvent::ValueChanged(widget_info) => {
-                    return widget_info.description();
+                    return widget_info.localized_description(localization);
                 }
             }
         }
@@ -171,6 +268,7 @@ impl PlatformOutput {
         let Self {
             mut commands,
             cursor_icon,
+            cursor_image,
             mut events,
             mutable_text_under_cursor,
             ime,
@@ -181,6 +279,7 @@ impl PlatformOutput {
 
         self.commands.append(&mut commands);
         self.cursor_icon = cursor_icon;
+        self.cursor_image = cursor_image;
         self.events.append(&mut events);
         self.mutable_text_under_cursor = mutable_text_under_cursor;
         self.ime = ime.or(self.ime);
@@ -192,10 +291,11 @@ impl PlatformOutput {
         self.accesskit_update = accesskit_update;
     }
 
-    /// Take everything ephemeral (everything except `cursor_icon` currently)
+    /// Take everything ephemeral (everything except the cursor currently)
     pub fn take(&mut self) -> Self {
         let taken = std::mem::take(self);
         self.cursor_icon = taken.cursor_icon; // everything else is ephemeral
+        self.cursor_image.clone_from(&taken.cursor_image);
         taken
     }
 
@@ -382,9 +482,16 @@ pub enum CursorIcon {
 
     /// Let's get a better overview
     ZoomOut,
+
+    // ------------------------------------
+    /// A custom image, registered with [`crate::Context::custom_cursor`].
+    ///
+    /// Integrations that can't show custom cursors show [`CursorImage::fallback`] instead.
+    Custom(crate::Id),
 }
 
 impl CursorIcon {
+    /// All the system cursors, i.e. everything except [`Self::Custom`].
     pub const ALL: [Self; 35] = [
         Self::Default,
         Self::None,
@@ -424,6 +531,52 @@ impl CursorIcon {
     ];
 }
 
+/// An image to use as the mouse cursor, see [`crate::Context::custom_cursor`].
+///
+/// ```
+/// # let image = egui::ColorImage::filled([16, 16], egui::Color32::RED);
+/// let cursor = egui::CursorImage::new(image, [8, 8]).with_fallback(egui::CursorIcon::Crosshair);
+/// assert_eq!(cursor.hotspot, [8, 8]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CursorImage {
+    /// The cursor, in physical pixels.
+    ///
+    /// Keep it small: many platforms don't support cursors larger than 32x32 or 128x128 pixels.
+    pub image: Arc<crate::ColorImage>,
+
+    /// The pixel of [`Self::image`] that is the tip of the cursor, i.e. the pointer position.
+    pub hotspot: [usize; 2],
+
+    /// Shown on platforms that don't support custom cursors, or while the custom cursor is loading.
+    pub fallback: CursorIcon,
+}
+
+impl CursorImage {
+    /// The fallback is [`CursorIcon::Default`].
+    pub fn new(image: impl Into<Arc<crate::ColorImage>>, hotspot: [usize; 2]) -> Self {
+        Self {
+            image: image.into(),
+            hotspot,
+            fallback: CursorIcon::Default,
+        }
+    }
+
+    /// Shown on platforms that don't support custom cursors.
+    ///
+    /// Must be a system cursor, i.e. not [`CursorIcon::Custom`].
+    #[inline]
+    pub fn with_fallback(mut self, fallback: CursorIcon) -> Self {
+        debug_assert!(
+            !matches!(fallback, CursorIcon::Custom(_)),
+            "The fallback must be a system cursor"
+        );
+        self.fallback = fallback;
+        self
+    }
+}
+
 /// Things that happened during this frame that the integration may be interested in.
 ///
 /// In particular, these events may be useful for accessibility, i.e. for screen readers.
@@ -648,7 +801,16 @@ impl WidgetInfo {
     }
 
     /// This can be used by a text-to-speech system to describe the widget.
+    ///
+    /// This is in English, see [`Self::localized_description`].
     pub fn description(&self) -> String {
+        self.localized_description(crate::i18n::Localization::english())
+    }
+
+    /// Describe the widget in the language of the given [`crate::i18n::Localization`].
+    pub fn localized_description(&self, localization: &crate::i18n::Localization) -> String {
+        let tr = |id: &str| localization.format(id, &Default::default());
+
         let Self {
             typ,
             enabled,
@@ -661,37 +823,44 @@ impl WidgetInfo {
             hint_text: _,
         } = self;
 
-        // TODO(emilk): localization
         let widget_type = match typ {
-            WidgetType::Link => "link",
-            WidgetType::TextEdit => "text edit",
-            WidgetType::Button => "button",
-            WidgetType::Checkbox => "checkbox",
-            WidgetType::RadioButton => "radio",
-            WidgetType::RadioGroup => "radio group",
-            WidgetType::SelectableLabel => "selectable",
-            WidgetType::ComboBox => "combo",
-            WidgetType::Slider => "slider",
-            WidgetType::DragValue => "drag value",
-            WidgetType::ColorButton => "color button",
-            WidgetType::Image => "image",
-            WidgetType::CollapsingHeader => "collapsing header",
-            WidgetType::Panel => "panel",
-            WidgetType::ProgressIndicator => "progress indicator",
-            WidgetType::Window => "window",
-            WidgetType::ScrollBar => "scroll bar",
-            WidgetType::ResizeHandle => "resize handle",
+            WidgetType::Link => "egui-widget-link",
+            WidgetType::TextEdit => "egui-widget-text-edit",
+            WidgetType::Button => "egui-widget-button",
+            WidgetType::Checkbox => "egui-widget-checkbox",
+            WidgetType::RadioButton => "egui-widget-radio-button",
+            WidgetType::RadioGroup => "egui-widget-radio-group",
+            WidgetType::SelectableLabel => "egui-widget-selectable-label",
+            WidgetType::ComboBox => "egui-widget-combo-box",
+            WidgetType::Slider => "egui-widget-slider",
+            WidgetType::DragValue => "egui-widget-drag-value",
+            WidgetType::ColorButton => "egui-widget-color-button",
+            WidgetType::Image => "egui-widget-image",
+            WidgetType::CollapsingHeader => "egui-widget-collapsing-header",
+            WidgetType::Panel => "egui-widget-panel",
+            WidgetType::ProgressIndicator => "egui-widget-progress-indicator",
+            WidgetType::Window => "egui-widget-window",
+            WidgetType::ScrollBar => "egui-widget-scroll-bar",
+            WidgetType::ResizeHandle => "egui-widget-resize-handle",
             WidgetType::Label | WidgetType::Other => "",
         };
 
-        let mut description = widget_type.to_owned();
+        let mut description = if widget_type.is_empty() {
+            String::new()
+        } else {
+            tr(widget_type)
+        };
 
         if let Some(selected) = selected {
             if *typ == WidgetType::Checkbox {
-                let state = if *selected { "checked" } else { "unchecked" };
+                let state = tr(if *selected {
+                    "egui-widget-checked"
+                } else {
+                    "egui-widget-unchecked"
+                });
                 description = format!("{state} {description}");
-            } else {
-                description += if *selected { "selected" } else { "" };
+            } else if *selected {
+                description += &tr("egui-widget-selected");
             }
         }
 
@@ -702,12 +871,12 @@ impl WidgetInfo {
         if typ == &WidgetType::TextEdit {
             let text = if let Some(text_value) = text_value {
                 if text_value.is_empty() {
-                    "blank".into()
+                    tr("egui-widget-blank")
                 } else {
                     text_value.clone()
                 }
             } else {
-                "blank".into()
+                tr("egui-widget-blank")
             };
             description = format!("{text}: {description}");
         }
@@ -718,7 +887,8 @@ impl WidgetInfo {
         }
 
         if !enabled {
-            description += ": disabled";
+            description += ": ";
+            description += &tr("egui-widget-disabled");
         }
         description.trim().to_owned()
     }
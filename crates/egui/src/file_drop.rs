@@ -0,0 +1,207 @@
+use std::path::Path;
+
+use crate::{DroppedFile, HoveredFile};
+
+/// Which files a file drop target accepts.
+///
+/// Files are matched by extension or by mime type.
+/// A filter without any extensions or mime types accepts all files.
+///
+/// See [`crate::Response::file_drop_target`] and [`crate::Ui::file_drop_zone`].
+///
+/// ```
+/// let filter = egui::FileFilter::any().extension("png").mime_type("image/*");
+/// let file = egui::DroppedFile {
+///     name: "cat.PNG".to_owned(),
+///     ..Default::default()
+/// };
+/// assert!(filter.accepts_dropped(&file));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileFilter {
+    extensions: Vec<String>,
+    mime_types: Vec<String>,
+}
+
+impl FileFilter {
+    /// Accept all files, until you add an extension or mime type.
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// Accept files with this extension, e.g. `"png"`.
+    ///
+    /// The extension is matched case-insensitively, with or without a leading dot.
+    #[inline]
+    pub fn extension(mut self, extension: impl Into<String>) -> Self {
+        let extension = extension.into();
+        self.extensions
+            .push(extension.trim_start_matches('.').to_lowercase());
+        self
+    }
+
+    /// Accept files with any of these extensions, see [`Self::extension`].
+    #[inline]
+    pub fn extensions(mut self, extensions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        for extension in extensions {
+            self = self.extension(extension);
+        }
+        self
+    }
+
+    /// Accept files with this mime type, e.g. `"image/png"`, or `"image/*"` for all images.
+    #[inline]
+    pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_types.push(mime_type.into().to_lowercase());
+        self
+    }
+
+    /// Does this filter accept all files?
+    pub fn is_any(&self) -> bool {
+        self.extensions.is_empty() && self.mime_types.is_empty()
+    }
+
+    /// Is this file being dragged over us acceptable?
+    ///
+    /// Some integrations don't tell us anything about hovered files.
+    /// Such files are accepted, and then filtered again with [`Self::accepts_dropped`] when dropped.
+    pub fn accepts_hovered(&self, file: &HoveredFile) -> bool {
+        self.accepts(file.path.as_deref(), &file.mime)
+            .unwrap_or(true)
+    }
+
+    /// Is this dropped file acceptable?
+    pub fn accepts_dropped(&self, file: &DroppedFile) -> bool {
+        let name = if file.name.is_empty() {
+            file.path.as_deref()
+        } else {
+            Some(Path::new(&file.name))
+        };
+        self.accepts(name, &file.mime).unwrap_or(false)
+    }
+
+    /// `None` if we can't tell.
+    fn accepts(&self, name: Option<&Path>, mime: &str) -> Option<bool> {
+        if self.is_any() {
+            return Some(true);
+        }
+
+        let extension = name
+            .and_then(|name| name.extension())
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        let mime = (!mime.is_empty()).then(|| mime.to_lowercase());
+        if extension.is_none() && mime.is_none() {
+            return None;
+        }
+
+        let extension_matches =
+            extension.is_some_and(|extension| self.extensions.contains(&extension));
+        let mime_matches = mime.is_some_and(|mime| {
+            self.mime_types
+                .iter()
+                .any(|pattern| mime_matches(pattern, &mime))
+        });
+        Some(extension_matches || mime_matches)
+    }
+}
+
+/// Does `mime` match `pattern`, which may end with a `*` wildcard, like `"image/*"`?
+fn mime_matches(pattern: &str, mime: &str) -> bool {
+    if let Some(prefix) = pattern.strip_suffix('*') {
+        mime.starts_with(prefix)
+    } else {
+        pattern == mime
+    }
+}
+
+/// Files that are being dragged over, or were dropped on, a widget.
+///
+/// Returned by [`crate::Response::file_drop_target`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileDrop {
+    /// The acceptable files being dragged over the widget.
+    pub hovered: Vec<HoveredFile>,
+
+    /// The acceptable files that were dropped on the widget this frame.
+    pub dropped: Vec<DroppedFile>,
+
+    /// How many of the hovered or dropped files are not acceptable.
+    pub rejected: usize,
+}
+
+impl FileDrop {
+    /// Would dropping the hovered files drop anything?
+    ///
+    /// Use this to show that the widget is ready to receive the files.
+    #[inline]
+    pub fn can_accept(&self) -> bool {
+        !self.hovered.is_empty()
+    }
+
+    /// Are files being dragged over the widget, none of which it accepts?
+    ///
+    /// Use this to show that the files can't be dropped here.
+    #[inline]
+    pub fn is_rejected(&self) -> bool {
+        self.hovered.is_empty() && self.dropped.is_empty() && 0 < self.rejected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dropped(name: &str, mime: &str) -> DroppedFile {
+        DroppedFile {
+            name: name.to_owned(),
+            mime: mime.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn filter() {
+        let any = FileFilter::any();
+        assert!(
+            any.accepts_dropped(&dropped("notes", "")),
+            "Any accepts all"
+        );
+
+        let images = FileFilter::any()
+            .extensions([".PNG", "jpg"])
+            .mime_type("image/*");
+        assert!(
+            images.accepts_dropped(&dropped("cat.png", "")),
+            "By extension"
+        );
+        assert!(
+            images.accepts_dropped(&dropped("dog.JPG", "")),
+            "Case insensitive"
+        );
+        assert!(
+            images.accepts_dropped(&dropped("blob", "image/webp")),
+            "By mime"
+        );
+        assert!(
+            !images.accepts_dropped(&dropped("notes.txt", "text/plain")),
+            "Wrong type"
+        );
+        assert!(
+            !images.accepts_dropped(&dropped("notes", "")),
+            "Unknown type"
+        );
+
+        let path = HoveredFile {
+            path: Some("dir.d/notes.txt".into()),
+            mime: String::new(),
+        };
+        assert!(
+            !images.accepts_hovered(&path),
+            "Extension of the file, not the dir"
+        );
+        assert!(
+            images.accepts_hovered(&HoveredFile::default()),
+            "Unknown hovered files are accepted until dropped"
+        );
+    }
+}
@@ -1,10 +1,10 @@
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
 
 use emath::GuiRounding as _;
 
 use crate::{
-    Align2, Color32, Context, Id, InnerResponse, NumExt as _, Painter, Rect, Region, Style, Ui,
-    UiBuilder, Vec2, vec2,
+    Align2, Color32, Context, Id, InnerResponse, NumExt as _, Painter, Rangef, Rect, Region, Style,
+    Ui, UiBuilder, Vec2, vec2,
 };
 
 #[cfg(debug_assertions)]
@@ -54,6 +54,19 @@ impl State {
     }
 }
 
+/// Grow the sizes in `span` evenly, so that together (with spacing) they are at least `size`.
+fn set_min_span_size(sizes: &mut Vec<f32>, span: Range<usize>, size: f32, spacing: f32) {
+    sizes.resize(sizes.len().max(span.end), 0.0);
+    let sizes = &mut sizes[span];
+    let current = sizes.iter().sum::<f32>() + (sizes.len() - 1) as f32 * spacing;
+    if current < size {
+        let extra = (size - current) / sizes.len() as f32;
+        for s in sizes {
+            *s += extra;
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 // type alias for boxed function to determine row color during grid generation
@@ -85,6 +98,18 @@ pub(crate) struct GridLayout {
     // Cursor:
     col: usize,
     row: usize,
+
+    /// Number of columns and rows the next cell spans, see [`Ui::grid_span`].
+    next_span: (usize, usize),
+
+    /// For each column: the first row that isn't covered by a cell spanning several rows.
+    row_span_ends: Vec<usize>,
+
+    /// Cells spanning several columns: the columns, and the width of the cell.
+    col_span_widths: Vec<(Range<usize>, f32)>,
+
+    /// Cells spanning several rows: the rows, and the height of the cell.
+    row_span_heights: Vec<(Range<usize>, f32)>,
 }
 
 impl GridLayout {
@@ -119,6 +144,11 @@ impl GridLayout {
 
             col: 0,
             row: 0,
+
+            next_span: (1, 1),
+            row_span_ends: Vec::new(),
+            col_span_widths: Vec::new(),
+            row_span_heights: Vec::new(),
         }
     }
 }
@@ -136,38 +166,75 @@ impl GridLayout {
             .unwrap_or(self.min_cell_size.y)
     }
 
+    /// The width of the next cell, given the width of each column.
+    fn span_width(&self, col_width: impl Fn(usize) -> f32) -> f32 {
+        let col_span = self.next_span.0;
+        (self.col..self.col + col_span).map(col_width).sum::<f32>()
+            + (col_span - 1) as f32 * self.spacing.x
+    }
+
+    /// The height of the next cell, given the height of each row.
+    fn span_height(&self, row_height: impl Fn(usize) -> f32) -> f32 {
+        let row_span = self.next_span.1;
+        (self.row..self.row + row_span).map(row_height).sum::<f32>()
+            + (row_span - 1) as f32 * self.spacing.y
+    }
+
+    /// Is this column of the current row covered by a cell of a previous row?
+    fn is_covered(&self, col: usize) -> bool {
+        self.row_span_ends
+            .get(col)
+            .is_some_and(|&end| self.row < end)
+    }
+
+    /// Move the cursor past columns that are covered by cells of previous rows.
+    fn skip_covered_cells(&mut self, cursor: &mut Rect) {
+        while self.is_covered(self.col) {
+            cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
+            self.col += 1;
+        }
+    }
+
+    pub(crate) fn set_next_span(&mut self, col_span: usize, row_span: usize) {
+        self.next_span = (col_span.at_least(1), row_span.at_least(1));
+    }
+
     pub(crate) fn wrap_text(&self) -> bool {
         self.max_cell_size.x.is_finite()
     }
 
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
-        let is_last_column = Some(self.col + 1) == self.num_columns;
+        let is_last_column = Some(self.col + self.next_span.0) == self.num_columns;
 
         let width = if is_last_column {
             // The first frame we don't really know the widths of the previous columns,
             // so returning a big available width here can cause trouble.
             if self.is_first_frame {
-                self.curr_state
-                    .col_width(self.col)
-                    .unwrap_or(self.min_cell_size.x)
+                self.span_width(|col| {
+                    self.curr_state
+                        .col_width(col)
+                        .unwrap_or(self.min_cell_size.x)
+                })
             } else {
                 (self.initial_available.right() - region.cursor.left())
                     .at_most(self.max_cell_size.x)
             }
         } else if self.max_cell_size.x.is_finite() {
             // TODO(emilk): should probably heed `prev_state` here too
-            self.max_cell_size.x
+            self.span_width(|_| self.max_cell_size.x)
         } else {
             // If we want to allow width-filling widgets like [`Separator`] in one of the first cells
             // then we need to make sure they don't spill out of the first cell:
-            self.prev_state
-                .col_width(self.col)
-                .or_else(|| self.curr_state.col_width(self.col))
-                .unwrap_or(self.min_cell_size.x)
+            self.span_width(|col| {
+                self.prev_state
+                    .col_width(col)
+                    .or_else(|| self.curr_state.col_width(col))
+                    .unwrap_or(self.min_cell_size.x)
+            })
         };
 
         // If something above was wider, we can be wider:
-        let width = width.max(self.curr_state.col_width(self.col).unwrap_or(0.0));
+        let width = width.max(self.span_width(|col| self.curr_state.col_width(col).unwrap_or(0.0)));
 
         let available = region.max_rect.intersect(region.cursor);
 
@@ -180,8 +247,8 @@ impl GridLayout {
     }
 
     pub(crate) fn next_cell(&self, cursor: Rect, child_size: Vec2) -> Rect {
-        let width = self.prev_state.col_width(self.col).unwrap_or(0.0);
-        let height = self.prev_row_height(self.row);
+        let width = self.span_width(|col| self.prev_state.col_width(col).unwrap_or(0.0));
+        let height = self.span_height(|row| self.prev_row_height(row));
         let size = child_size.max(vec2(width, height));
         Rect::from_min_size(cursor.min, size).round_ui()
     }
@@ -199,14 +266,16 @@ impl GridLayout {
     }
 
     pub(crate) fn advance(&mut self, cursor: &mut Rect, _frame_rect: Rect, widget_rect: Rect) {
+        let prev_width = self.span_width(|col| self.prev_col_width(col));
+
         #[cfg(debug_assertions)]
         {
             let debug_expand_width = self.style.debug.show_expand_width;
             let debug_expand_height = self.style.debug.show_expand_height;
             if debug_expand_width || debug_expand_height {
                 let rect = widget_rect;
-                let too_wide = rect.width() > self.prev_col_width(self.col);
-                let too_high = rect.height() > self.prev_row_height(self.row);
+                let too_wide = rect.width() > prev_width;
+                let too_high = rect.height() > self.span_height(|row| self.prev_row_height(row));
 
                 if (debug_expand_width && too_wide) || (debug_expand_height && too_high) {
                     let painter = self.ctx.debug_painter();
@@ -229,13 +298,40 @@ impl GridLayout {
             }
         }
 
-        self.curr_state
-            .set_min_col_width(self.col, widget_rect.width().max(self.min_cell_size.x));
-        self.curr_state
-            .set_min_row_height(self.row, widget_rect.height().max(self.min_cell_size.y));
+        let (col_span, row_span) = std::mem::replace(&mut self.next_span, (1, 1));
+        let cols = self.col..self.col + col_span;
+        let rows = self.row..self.row + row_span;
+
+        if col_span == 1 {
+            self.curr_state
+                .set_min_col_width(self.col, widget_rect.width().max(self.min_cell_size.x));
+        } else {
+            // Distributed over the columns once we know the widths of all single cells:
+            for col in cols.clone() {
+                self.curr_state.set_min_col_width(col, self.min_cell_size.x);
+            }
+            self.col_span_widths
+                .push((cols.clone(), widget_rect.width()));
+        }
 
-        cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
-        self.col += 1;
+        if row_span == 1 {
+            self.curr_state
+                .set_min_row_height(self.row, widget_rect.height().max(self.min_cell_size.y));
+        } else {
+            self.curr_state
+                .set_min_row_height(self.row, self.min_cell_size.y);
+            self.row_span_heights
+                .push((rows.clone(), widget_rect.height()));
+            self.row_span_ends
+                .resize(self.row_span_ends.len().max(cols.end), 0);
+            for end in &mut self.row_span_ends[cols.clone()] {
+                *end = rows.end;
+            }
+        }
+
+        cursor.min.x += prev_width + self.spacing.x;
+        self.col = cols.end;
+        self.skip_covered_cells(cursor);
     }
 
     fn paint_row(&self, cursor: &Rect, painter: &Painter) {
@@ -249,34 +345,74 @@ impl GridLayout {
         let Some(height) = self.prev_state.row_height(self.row) else {
             return;
         };
+
+        // Don't paint over cells of previous rows that span this row:
+        let mut segments = Vec::new();
+        let mut start = cursor.min.x;
+        let mut x = cursor.min.x;
+        for (col, width) in self.prev_state.col_widths.iter().enumerate() {
+            if self.is_covered(col) {
+                if start < x {
+                    segments.push(Rangef::new(start, x - self.spacing.x));
+                }
+                start = x + width + self.spacing.x;
+            }
+            x += width + self.spacing.x;
+        }
+        let end = cursor.min.x + self.prev_state.full_width(self.spacing.x);
+        if start <= end {
+            segments.push(Rangef::new(start, end));
+        }
+
         // Paint background for coming row:
-        let size = Vec2::new(self.prev_state.full_width(self.spacing.x), height);
-        let rect = Rect::from_min_size(cursor.min, size);
-        let rect = rect.expand2(0.5 * self.spacing.y * Vec2::Y);
-        let rect = rect.expand2(2.0 * Vec2::X); // HACK: just looks better with some spacing on the sides
+        for x_range in segments {
+            let y_range = Rangef::new(cursor.min.y, cursor.min.y + height);
+            let rect = Rect::from_x_y_ranges(x_range, y_range);
+            let rect = rect.expand2(0.5 * self.spacing.y * Vec2::Y);
+            let rect = rect.expand2(2.0 * Vec2::X); // HACK: just looks better with some spacing on the sides
 
-        painter.rect_filled(rect, 2.0, row_color);
+            painter.rect_filled(rect, 2.0, row_color);
+        }
     }
 
     pub(crate) fn end_row(&mut self, cursor: &mut Rect, painter: &Painter) {
         cursor.min.x = self.initial_available.min.x;
         cursor.min.y += self.spacing.y;
-        cursor.min.y += self
+        let mut height = self
             .curr_state
             .row_height(self.row)
             .unwrap_or(self.min_cell_size.y);
+        if self.row_span_ends.iter().any(|&end| self.row < end) {
+            // Cells spanning several rows may need more height than the single cells:
+            height = height.max(self.prev_state.row_height(self.row).unwrap_or(0.0));
+        }
+        cursor.min.y += height;
 
         self.col = 0;
         self.row += 1;
 
         self.paint_row(cursor, painter);
+        self.skip_covered_cells(cursor);
     }
 
     pub(crate) fn save(&self) {
+        let mut state = self.curr_state.clone();
+        for (cols, width) in &self.col_span_widths {
+            set_min_span_size(&mut state.col_widths, cols.clone(), *width, self.spacing.x);
+        }
+        for (rows, height) in &self.row_span_heights {
+            set_min_span_size(
+                &mut state.row_heights,
+                rows.clone(),
+                *height,
+                self.spacing.y,
+            );
+        }
+
         // We need to always save state on the first frame, otherwise request_discard
         // would be called repeatedly (see #5132)
-        if self.curr_state != self.prev_state || self.is_first_frame {
-            self.curr_state.clone().store(&self.ctx, self.id);
+        if state != self.prev_state || self.is_first_frame {
+            state.store(&self.ctx, self.id);
             self.ctx.request_repaint();
         }
     }
@@ -292,6 +428,8 @@ impl GridLayout {
 /// If you want to add multiple widgets to a cell you need to group them with
 /// [`Ui::horizontal`], [`Ui::vertical`] etc.
 ///
+/// A cell can span several columns and rows, see [`Ui::grid_span`].
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// egui::Grid::new("some_unique_id").show(ui, |ui| {
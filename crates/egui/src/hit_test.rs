@@ -2,7 +2,7 @@ use ahash::HashMap;
 
 use emath::TSTransform;
 
-use crate::{LayerId, Pos2, Sense, WidgetRect, WidgetRects, ahash, emath, id::IdSet};
+use crate::{IdMap, LayerId, Pos2, Sense, WidgetRect, WidgetRects, ahash, emath, id::IdSet};
 
 /// Result of a hit-test against [`WidgetRects`].
 ///
@@ -71,7 +71,7 @@ pub fn hit_test(
 
             let pos_in_layer = pos_in_layers.get(&w.layer_id).copied().unwrap_or(pos);
             // TODO(emilk): we should probably do the distance testing in global space instead
-            let dist_sq = w.interact_rect.distance_sq_to_pos(pos_in_layer);
+            let dist_sq = widgets.distance_sq_to_pos(w, pos_in_layer);
 
             // In tie, pick last = topmost.
             if dist_sq <= closest_dist_sq {
@@ -93,6 +93,26 @@ pub fn hit_test(
 
     close.retain(|rect| !rect.interact_rect.any_nan()); // Protect against bad input and transforms
 
+    // Widgets with a custom `HitShape` only count as hit when the pointer is close to the shape.
+    // The shape is in layer coordinates, so scale the distance to global coordinates:
+    let shape_dists: IdMap<f32> = close
+        .iter()
+        .filter_map(|w| {
+            let shape = widgets.hit_shape(w.id)?;
+            let pos_in_layer = pos_in_layers.get(&w.layer_id).copied().unwrap_or(pos);
+            let scaling = layer_to_global
+                .get(&w.layer_id)
+                .map_or(1.0, |to_global| to_global.scaling);
+            Some((w.id, scaling * shape.distance(pos_in_layer)))
+        })
+        .collect();
+    let dist_sq = |w: &WidgetRect| {
+        let rect_dist_sq = w.interact_rect.distance_sq_to_pos(pos);
+        shape_dists.get(&w.id).map_or(rect_dist_sq, |shape_dist| {
+            rect_dist_sq.max(shape_dist * shape_dist)
+        })
+    };
+
     // When using layer transforms it is common to stack layers close to each other.
     // For instance, you may have a resize-separator on a panel, with two
     // transform-layers on either side.
@@ -117,7 +137,8 @@ pub fn hit_test(
     let mut included_layers: ahash::HashSet<LayerId> = Default::default();
     for hit in close.iter().rev() {
         included_layers.insert(hit.layer_id);
-        let hit_covers_search_area = contains_circle(hit.interact_rect, pos, search_radius);
+        let hit_covers_search_area = !shape_dists.contains_key(&hit.id)
+            && contains_circle(hit.interact_rect, pos, search_radius);
         if hit_covers_search_area {
             break; // nothing behind this layer could ever be interacted with
         }
@@ -144,6 +165,7 @@ pub fn hit_test(
         for next in &close[i + 1..] {
             if next.interact_rect.contains_rect(current.interact_rect)
                 && current.layer_id != next.layer_id
+                && !shape_dists.contains_key(&next.id)
             {
                 hidden.insert(current.id);
             }
@@ -152,11 +174,11 @@ pub fn hit_test(
 
     close.retain(|c| !hidden.contains(&c.id));
 
-    let mut hits = hit_test_on_close(&close, pos);
+    let mut hits = hit_test_on_close_with(&close, &dist_sq);
 
     hits.contains_pointer = close
         .iter()
-        .filter(|widget| widget.interact_rect.contains(pos))
+        .filter(|widget| widget.interact_rect.contains(pos) && dist_sq(widget) <= 0.0)
         .copied()
         .collect();
 
@@ -200,18 +222,27 @@ fn contains_circle(interact_rect: emath::Rect, pos: Pos2, radius: f32) -> bool {
     interact_rect.shrink(radius).contains(pos)
 }
 
+#[cfg(test)]
 fn hit_test_on_close(close: &[WidgetRect], pos: Pos2) -> WidgetHits {
+    hit_test_on_close_with(close, &|w| w.interact_rect.distance_sq_to_pos(pos))
+}
+
+/// `dist_sq` is the squared distance from the pointer to a widget.
+fn hit_test_on_close_with(
+    close: &[WidgetRect],
+    dist_sq: &dyn Fn(&WidgetRect) -> f32,
+) -> WidgetHits {
     #![expect(clippy::collapsible_else_if)]
 
     // First find the best direct hits:
     let hit_click = find_closest_within(
         close.iter().copied().filter(|w| w.sense.senses_click()),
-        pos,
+        dist_sq,
         0.0,
     );
     let hit_drag = find_closest_within(
         close.iter().copied().filter(|w| w.sense.senses_drag()),
-        pos,
+        dist_sq,
         0.0,
     );
 
@@ -224,7 +255,7 @@ fn hit_test_on_close(close: &[WidgetRect], pos: Pos2) -> WidgetHits {
                     .iter()
                     .copied()
                     .filter(|w| w.sense.senses_click() || w.sense.senses_drag()),
-                pos,
+                dist_sq,
             );
 
             if let Some(closest) = closest {
@@ -253,7 +284,7 @@ fn hit_test_on_close(close: &[WidgetRect], pos: Pos2) -> WidgetHits {
 
             let closest_click = find_closest(
                 close.iter().copied().filter(|w| w.sense.senses_click()),
-                pos,
+                dist_sq,
             );
             if let Some(closest_click) = closest_click {
                 if closest_click.sense.senses_drag() {
@@ -314,7 +345,7 @@ fn hit_test_on_close(close: &[WidgetRect], pos: Pos2) -> WidgetHits {
                         .iter()
                         .copied()
                         .filter(|w| w.sense.senses_drag() && w.id != hit_drag.id),
-                    pos,
+                    dist_sq,
                 );
 
                 if let Some(closest_drag) = closest_drag
@@ -409,13 +440,16 @@ fn hit_test_on_close(close: &[WidgetRect], pos: Pos2) -> WidgetHits {
     }
 }
 
-fn find_closest(widgets: impl Iterator<Item = WidgetRect>, pos: Pos2) -> Option<WidgetRect> {
-    find_closest_within(widgets, pos, f32::INFINITY)
+fn find_closest(
+    widgets: impl Iterator<Item = WidgetRect>,
+    dist_sq: &dyn Fn(&WidgetRect) -> f32,
+) -> Option<WidgetRect> {
+    find_closest_within(widgets, dist_sq, f32::INFINITY)
 }
 
 fn find_closest_within(
     widgets: impl Iterator<Item = WidgetRect>,
-    pos: Pos2,
+    dist_sq: &dyn Fn(&WidgetRect) -> f32,
     max_dist: f32,
 ) -> Option<WidgetRect> {
     let mut closest: Option<WidgetRect> = None;
@@ -425,7 +459,7 @@ fn find_closest_within(
             continue;
         }
 
-        let dist_sq = widget.interact_rect.distance_sq_to_pos(pos);
+        let dist_sq = dist_sq(&widget);
 
         // In case of a tie, take the last one = the one on top.
         if dist_sq <= closest_dist_sq {
@@ -549,4 +583,53 @@ mod tests {
         assert_eq!(hits.click.unwrap().id, Id::new("fg-right-label"));
         assert_eq!(hits.drag.unwrap().id, Id::new("fg-right-label"));
     }
+
+    #[test]
+    fn hit_shape_does_not_steal_from_neighbors() {
+        let button = wr(
+            Id::new("button"),
+            Sense::click(),
+            Rect::from_min_size(pos2(0.0, 0.0), vec2(40.0, 20.0)),
+        );
+        // A diagonal wire on top of the button, whose bounding box covers the button:
+        let wire = wr(
+            Id::new("wire"),
+            Sense::click_and_drag(),
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0)),
+        );
+
+        let mut widgets = WidgetRects::default();
+        for w in [button, wire] {
+            widgets.insert(w.layer_id, w, Default::default());
+        }
+        widgets.set_hit_shape(
+            wire.id,
+            crate::HitShape::capsule(pos2(0.0, 100.0), pos2(100.0, 0.0), 2.0),
+        );
+
+        let layers = [LayerId::background()];
+        let hit = |pos| hit_test(&widgets, &layers, &Default::default(), pos, 4.0);
+
+        // On the button, far from the wire:
+        let hits = hit(pos2(10.0, 10.0));
+        assert_eq!(hits.click.unwrap().id, button.id);
+        assert_eq!(
+            hits.contains_pointer.len(),
+            1,
+            "Only the button contains the pointer"
+        );
+
+        // On the wire:
+        let hits = hit(pos2(50.0, 50.0));
+        assert_eq!(hits.click.unwrap().id, wire.id);
+        assert_eq!(hits.drag.unwrap().id, wire.id);
+
+        // Inside the bounding box of the wire, but far from it:
+        let hits = hit(pos2(80.0, 80.0));
+        assert_eq!(hits.click, None);
+        assert!(
+            hits.contains_pointer.is_empty(),
+            "Nothing contains the pointer"
+        );
+    }
 }
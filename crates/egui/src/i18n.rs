@@ -0,0 +1,1013 @@
+//! Runtime localization of text.
+//!
+//! Messages are looked up by id with [`crate::Context::tr`] and [`crate::Context::tr_args`],
+//! in the language set with [`crate::Context::set_language`].
+//! Messages missing from that language fall back to English.
+//!
+//! Translations are written in a subset of [Fluent](https://projectfluent.org/):
+//!
+//! ```
+//! use egui::i18n::{MessageArgs, MessageBundle};
+//!
+//! let ctx = egui::Context::default();
+//! let bundle = MessageBundle::parse(
+//!     "sv",
+//!     r"
+//! hello = Hej { $name }!
+//! files = { $count ->
+//!     [0] Inga filer
+//!     [one] En fil
+//!    *[other] { $count } filer
+//! }
+//! ",
+//! )
+//! .unwrap();
+//! ctx.add_messages(bundle);
+//! ctx.set_language("sv");
+//!
+//! assert_eq!(ctx.tr_args("hello", &MessageArgs::new().with("name", "Ada")), "Hej Ada!");
+//! assert_eq!(ctx.tr_args("files", &MessageArgs::new().with("count", 1)), "En fil");
+//! assert_eq!(ctx.tr_args("files", &MessageArgs::new().with("count", 1500)), "1\u{a0}500 filer");
+//! ```
+//!
+//! Supported syntax:
+//! * `id = pattern`, where the pattern continues on the following indented lines
+//! * comments: lines starting with `#`
+//! * variables: `{ $name }`
+//! * references to other messages: `{ other-id }`
+//! * string and number literals: `{ "{" }`
+//! * selectors on a variable, with exactly one default variant marked by `*`.
+//!   Numbers select a variant by exact value (`[0]`) or by [`PluralCategory`] (`[one]`),
+//!   strings by name.
+//!
+//! egui's own strings (e.g. in the color picker, and the descriptions of widgets for screen readers)
+//! have ids starting with `egui-`. See [`BUILTIN_MESSAGES`] for the English originals to translate.
+
+use std::{collections::BTreeMap, ops::RangeInclusive, sync::LazyLock};
+
+/// The English originals of all the strings egui shows, as a Fluent message bundle.
+///
+/// Translate these and add them with [`crate::Context::add_messages`]
+/// to localize egui's built-in widgets.
+pub const BUILTIN_MESSAGES: &str = r"
+# Describing widgets to screen readers:
+egui-widget-link = link
+egui-widget-text-edit = text edit
+egui-widget-button = button
+egui-widget-checkbox = checkbox
+egui-widget-radio-button = radio
+egui-widget-radio-group = radio group
+egui-widget-selectable-label = selectable
+egui-widget-combo-box = combo
+egui-widget-slider = slider
+egui-widget-drag-value = drag value
+egui-widget-color-button = color button
+egui-widget-image = image
+egui-widget-collapsing-header = collapsing header
+egui-widget-panel = panel
+egui-widget-progress-indicator = progress indicator
+egui-widget-window = window
+egui-widget-scroll-bar = scroll bar
+egui-widget-resize-handle = resize handle
+egui-widget-checked = checked
+egui-widget-unchecked = unchecked
+egui-widget-selected = selected
+egui-widget-disabled = disabled
+egui-widget-blank = blank
+
+egui-window-close = Close window
+
+egui-drag-angle-tau = 1τ = one turn, 0.5τ = half a turn, etc. 0.25τ = 90°
+
+egui-color-selected = Selected color
+egui-color-blending = Blending:
+egui-color-blending-normal = Normal
+egui-color-blending-additive = Additive
+egui-color-hue = Hue
+egui-color-saturation = Saturation
+egui-color-value = Value
+egui-color-alpha = Alpha
+egui-color-copy = Click to copy color values
+egui-color-edit = Click to edit color
+
+egui-theme-system = 💻 System
+egui-theme-dark = 🌙 Dark
+egui-theme-light = ☀ Light
+egui-theme-switch-to-light = Switch to light mode
+egui-theme-switch-to-dark = Switch to dark mode
+egui-theme-follow-system = Follow the system theme preference.
+egui-theme-current-system = The current system theme is: { $theme ->
+    [dark] dark
+   *[light] light
+}
+egui-theme-system-unknown = The system theme is unknown.
+egui-theme-use-dark = Use the dark mode theme
+egui-theme-use-light = Use the light mode theme
+";
+
+/// The language that missing messages fall back to.
+const FALLBACK_LANGUAGE: &str = "en";
+
+static BUILTIN_BUNDLE: LazyLock<MessageBundle> = LazyLock::new(|| {
+    MessageBundle::parse(FALLBACK_LANGUAGE, BUILTIN_MESSAGES)
+        .expect("The built-in messages should parse")
+});
+
+/// How deeply messages may reference other messages, to guard against cycles.
+const MAX_REFERENCE_DEPTH: usize = 8;
+
+// ----------------------------------------------------------------------------
+
+/// A value for a variable in a message, see [`MessageArgs`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum MessageArg {
+    /// Formatted for the language, and selects variants by [`PluralCategory`].
+    Number(f64),
+
+    /// Inserted as is, and selects variants by name.
+    Text(String),
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for MessageArg {
+                #[inline]
+                fn from(value: $t) -> Self {
+                    Self::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(f32, i8, i16, i32, i64, u8, u16, u32, u64, usize, isize);
+
+impl From<f64> for MessageArg {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<&str> for MessageArg {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_owned())
+    }
+}
+
+impl From<String> for MessageArg {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+/// The values of the variables in a message, e.g. `{ $count }`.
+///
+/// ```
+/// let args = egui::i18n::MessageArgs::new().with("name", "Ada").with("count", 3);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MessageArgs(Vec<(String, MessageArg)>);
+
+impl MessageArgs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value of the variable `name` (without the `$`).
+    #[inline]
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<MessageArg>) -> Self {
+        self.0.push((name.into(), value.into()));
+        self
+    }
+
+    fn get(&self, name: &str) -> Option<&MessageArg> {
+        self.0
+            .iter()
+            .rev()
+            .find_map(|(arg_name, value)| (arg_name == name).then_some(value))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The plural form a language uses for a number, as defined by
+/// [CLDR](https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html).
+///
+/// Select on it in messages with e.g. `[one]` and `[other]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// The plural category of `n` in the given language, e.g. `"en"` or `"pt-BR"`.
+    ///
+    /// Covers the most common languages. Others are treated like English.
+    pub fn of(language: &str, n: f64) -> Self {
+        let is_integer = n.fract() == 0.0;
+        let i = n.abs().trunc() as u64;
+        let (i10, i100) = (i % 10, i % 100);
+
+        match primary_language(language).as_str() {
+            "fr" | "pt" | "hy" | "ff" | "kab" => {
+                if i <= 1 {
+                    Self::One
+                } else {
+                    Self::Other
+                }
+            }
+
+            "ru" | "uk" | "be" | "sr" | "hr" | "bs" if is_integer => {
+                if i10 == 1 && i100 != 11 {
+                    Self::One
+                } else if (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+                    Self::Few
+                } else {
+                    Self::Many
+                }
+            }
+
+            "pl" if is_integer => {
+                if i == 1 {
+                    Self::One
+                } else if (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+                    Self::Few
+                } else {
+                    Self::Many
+                }
+            }
+
+            "cs" | "sk" => {
+                if !is_integer {
+                    Self::Many
+                } else if i == 1 {
+                    Self::One
+                } else if (2..=4).contains(&i) {
+                    Self::Few
+                } else {
+                    Self::Other
+                }
+            }
+
+            "ar" if is_integer => match (i, i100) {
+                (0, _) => Self::Zero,
+                (1, _) => Self::One,
+                (2, _) => Self::Two,
+                (_, 3..=10) => Self::Few,
+                (_, 11..=99) => Self::Many,
+                _ => Self::Other,
+            },
+
+            // No plural forms, or fractions in the languages above:
+            "ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" | "lo" | "my" | "km" | "ru" | "uk"
+            | "be" | "sr" | "hr" | "bs" | "pl" | "ar" => Self::Other,
+
+            _ => {
+                if is_integer && i == 1 {
+                    Self::One
+                } else {
+                    Self::Other
+                }
+            }
+        }
+    }
+
+    /// The name used in messages, e.g. `"few"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// `"pt"` for `"pt-BR"`.
+fn primary_language(language: &str) -> String {
+    language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+// ----------------------------------------------------------------------------
+
+/// How a language writes numbers.
+///
+/// See also [`crate::style::NumberFormatter::localized`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumberSymbols {
+    /// Separates the integer part from the fraction, e.g. `'.'` or `','`.
+    pub decimal_separator: char,
+
+    /// Separates groups of three digits, e.g. the `','` in `1,000`.
+    pub grouping_separator: Option<char>,
+}
+
+impl Default for NumberSymbols {
+    fn default() -> Self {
+        Self::PLAIN
+    }
+}
+
+impl NumberSymbols {
+    /// How Rust formats numbers: `1234.5`.
+    pub const PLAIN: Self = Self {
+        decimal_separator: '.',
+        grouping_separator: None,
+    };
+
+    /// The symbols of a language, e.g. `"de"` or `"de-CH"`.
+    ///
+    /// Covers the most common languages. Others use [`Self::PLAIN`].
+    pub fn for_language(language: &str) -> Self {
+        let (decimal_separator, grouping_separator) = match language.to_lowercase().as_str() {
+            "de-ch" | "de-li" => ('.', '’'),
+            _ => match primary_language(language).as_str() {
+                "en" | "ja" | "zh" | "ko" | "th" | "he" | "hi" | "ms" => ('.', ','),
+                "de" | "nl" | "it" | "es" | "pt" | "id" | "da" | "tr" | "el" | "ro" | "hr"
+                | "sr" | "sl" => (',', '.'),
+                "fr" | "ru" | "uk" | "pl" | "cs" | "sk" | "sv" | "nb" | "nn" | "no" | "fi"
+                | "hu" | "bg" | "be" | "lt" | "lv" | "et" => (',', '\u{a0}'),
+                _ => return Self::PLAIN,
+            },
+        };
+        Self {
+            decimal_separator,
+            grouping_separator: Some(grouping_separator),
+        }
+    }
+
+    /// Format a number with the given range of decimals,
+    /// see [`crate::style::NumberFormatter::format`].
+    pub fn format(&self, value: f64, decimals: RangeInclusive<usize>) -> String {
+        let plain = emath::format_with_decimals_in_range(value, decimals);
+        if *self == Self::PLAIN {
+            return plain;
+        }
+
+        let (sign, unsigned) = match plain.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", plain.as_str()),
+        };
+        let (integer, fraction) = unsigned
+            .split_once('.')
+            .map_or((unsigned, None), |(integer, fraction)| {
+                (integer, Some(fraction))
+            });
+
+        let mut formatted = sign.to_owned();
+        let is_digits = integer.bytes().all(|b| b.is_ascii_digit());
+        match self.grouping_separator {
+            Some(separator) if is_digits => {
+                for (i, digit) in integer.chars().enumerate() {
+                    if 0 < i && (integer.len() - i) % 3 == 0 {
+                        formatted.push(separator);
+                    }
+                    formatted.push(digit);
+                }
+            }
+            _ => formatted.push_str(integer), // e.g. `inf`
+        }
+        if let Some(fraction) = fraction {
+            formatted.push(self.decimal_separator);
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
+    /// Parse a number written with these symbols.
+    ///
+    /// Whitespace is ignored, and the special minus character (U+2212) is treated as a normal minus.
+    ///
+    /// Grouping separators must separate groups of three digits,
+    /// so that e.g. `1.5` is rejected in German rather than read as `15`.
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        let text: String = text
+            .chars()
+            // Ignore whitespace (trailing, leading, and thousands separators):
+            .filter(|c| !c.is_whitespace())
+            // Replace special minus character with normal minus (hyphen):
+            .map(|c| if c == '−' { '-' } else { c })
+            .collect();
+
+        let (integer, fraction) = match text.split_once(self.decimal_separator) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (text.as_str(), None),
+        };
+
+        let mut plain = String::with_capacity(text.len());
+        match self.grouping_separator {
+            Some(separator) if integer.contains(separator) => {
+                let mut groups = integer.split(separator);
+                let first = groups.next().unwrap_or_default();
+                let first_digits = first.trim_start_matches(['-', '+']).len();
+                if !(1..=3).contains(&first_digits) || !groups.clone().all(|g| g.len() == 3) {
+                    return None;
+                }
+                plain.push_str(first);
+                plain.extend(groups);
+            }
+            _ => plain.push_str(integer),
+        }
+        if let Some(fraction) = fraction {
+            if self
+                .grouping_separator
+                .is_some_and(|separator| fraction.contains(separator))
+            {
+                return None;
+            }
+            plain.push('.');
+            plain.push_str(fraction);
+        }
+
+        plain.parse().ok()
+    }
+
+    /// Format a number in a message, with as many decimals as needed.
+    fn format_arg(&self, value: f64) -> String {
+        self.format(value, 0..=6)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A malformed message, see [`MessageBundle::parse`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number of the message.
+    pub line: usize,
+
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Element {
+    Text(String),
+    Variable(String),
+    Message(String),
+    Select {
+        variable: String,
+        variants: Vec<(VariantKey, Pattern)>,
+        default: usize,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum VariantKey {
+    Number(f64),
+    Name(String),
+}
+
+type Pattern = Vec<Element>;
+
+/// The translated messages of one language.
+///
+/// See [the module docs](self) for the syntax.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MessageBundle {
+    language: String,
+    messages: BTreeMap<String, Pattern>,
+}
+
+impl MessageBundle {
+    /// An empty bundle for a language, e.g. `"sv"` or `"pt-BR"`.
+    pub fn new(language: impl Into<String>) -> Self {
+        Self {
+            language: language.into(),
+            messages: Default::default(),
+        }
+    }
+
+    /// Parse the messages of a language.
+    ///
+    /// # Errors
+    /// If a message is malformed.
+    pub fn parse(language: impl Into<String>, source: &str) -> Result<Self, ParseError> {
+        let mut bundle = Self::new(language);
+        bundle.add_messages(source)?;
+        Ok(bundle)
+    }
+
+    /// Parse more messages, replacing any with the same ids.
+    ///
+    /// # Errors
+    /// If a message is malformed. The messages before it are still added.
+    pub fn add_messages(&mut self, source: &str) -> Result<(), ParseError> {
+        let mut current: Option<(usize, &str, Vec<&str>)> = None;
+        for (index, line) in source.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with(char::is_whitespace) || line.starts_with('}') {
+                let Some((_, _, lines)) = &mut current else {
+                    return Err(ParseError {
+                        line: index + 1,
+                        message: "Indented line outside of a message".to_owned(),
+                    });
+                };
+                lines.push(line.trim());
+                continue;
+            }
+
+            if let Some(message) = current.take() {
+                self.add_message(message)?;
+            }
+            let Some((id, first_line)) = line.split_once('=') else {
+                return Err(ParseError {
+                    line: index + 1,
+                    message: format!("Expected `id = message`, got {line:?}"),
+                });
+            };
+            let first_line = first_line.trim();
+            let lines = if first_line.is_empty() {
+                vec![]
+            } else {
+                vec![first_line]
+            };
+            current = Some((index + 1, id.trim(), lines));
+        }
+        if let Some(message) = current {
+            self.add_message(message)?;
+        }
+        Ok(())
+    }
+
+    fn add_message(
+        &mut self,
+        (line, id, lines): (usize, &str, Vec<&str>),
+    ) -> Result<(), ParseError> {
+        let error = |message: String| ParseError { line, message };
+        if !is_identifier(id) {
+            return Err(error(format!("Invalid message id: {id:?}")));
+        }
+        let source = lines.join("\n");
+        let mut parser = PatternParser {
+            text: &source,
+            pos: 0,
+        };
+        let pattern = parser
+            .pattern(false)
+            .map_err(|err| error(format!("{id}: {err}")))?;
+        self.messages.insert(id.to_owned(), pattern);
+        Ok(())
+    }
+
+    /// The language of the messages, e.g. `"sv"`.
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Does this bundle have a message with this id?
+    pub fn has_message(&self, id: &str) -> bool {
+        self.messages.contains_key(id)
+    }
+
+    /// The ids of all the messages.
+    pub fn message_ids(&self) -> impl Iterator<Item = &str> {
+        self.messages.keys().map(String::as_str)
+    }
+
+    /// Add the messages of `other`, replacing any with the same ids.
+    fn merge(&mut self, other: Self) {
+        self.messages.extend(other.messages);
+    }
+}
+
+fn is_identifier(id: &str) -> bool {
+    let mut chars = id.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+struct PatternParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl PatternParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, expected: &str) -> bool {
+        let found = self.text[self.pos..].starts_with(expected);
+        if found {
+            self.pos += expected.len();
+        }
+        found
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), String> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(format!("Expected `{expected}`"))
+        }
+    }
+
+    /// Skips newlines too.
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace() && c != '\n') {
+            self.bump();
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &str {
+        let start = self.pos;
+        while self.peek().is_some_and(&predicate) {
+            self.bump();
+        }
+        &self.text[start..self.pos]
+    }
+
+    fn identifier(&mut self) -> Result<String, String> {
+        let id = self.take_while(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if is_identifier(id) {
+            Ok(id.to_owned())
+        } else {
+            Err("Expected an identifier".to_owned())
+        }
+    }
+
+    /// A variant ends at the end of its line.
+    fn pattern(&mut self, is_variant: bool) -> Result<Pattern, String> {
+        let mut pattern = Pattern::new();
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '\n' if is_variant => break,
+                '{' => {
+                    self.bump();
+                    if !text.is_empty() {
+                        pattern.push(Element::Text(std::mem::take(&mut text)));
+                    }
+                    pattern.push(self.placeable()?);
+                }
+                '}' => return Err("Unbalanced `}`".to_owned()),
+                c => {
+                    self.bump();
+                    text.push(c);
+                }
+            }
+        }
+        if is_variant {
+            text.truncate(text.trim_end().len());
+        }
+        if !text.is_empty() {
+            pattern.push(Element::Text(text));
+        }
+        Ok(pattern)
+    }
+
+    /// After the opening `{`.
+    fn placeable(&mut self) -> Result<Element, String> {
+        self.skip_whitespace();
+        let element = match self.peek() {
+            Some('$') => {
+                self.bump();
+                let variable = self.identifier()?;
+                self.skip_whitespace();
+                if self.eat("->") {
+                    return self.select(variable);
+                }
+                Element::Variable(variable)
+            }
+            Some('"') => {
+                self.bump();
+                let mut literal = String::new();
+                loop {
+                    match self.bump() {
+                        Some('"') => break,
+                        Some('\\') => literal.extend(self.bump()),
+                        Some(c) => literal.push(c),
+                        None => return Err("Unterminated string literal".to_owned()),
+                    }
+                }
+                Element::Text(literal)
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' => {
+                let number = self.take_while(|c| c.is_ascii_digit() || c == '-' || c == '.');
+                Element::Text(number.to_owned())
+            }
+            Some(c) if c.is_ascii_alphabetic() => Element::Message(self.identifier()?),
+            _ => return Err("Expected a variable, message or literal after `{`".to_owned()),
+        };
+        self.skip_whitespace();
+        self.expect("}")?;
+        Ok(element)
+    }
+
+    /// After the `->`.
+    fn select(&mut self, variable: String) -> Result<Element, String> {
+        let mut variants = vec![];
+        let mut default = None;
+        loop {
+            self.skip_whitespace();
+            if self.eat("}") {
+                break;
+            }
+            if self.eat("*") {
+                if default.is_some() {
+                    return Err("Only one variant can be the default".to_owned());
+                }
+                default = Some(variants.len());
+            }
+            self.expect("[")?;
+            let key = self.take_while(|c| c != ']' && c != '\n').trim().to_owned();
+            self.expect("]")?;
+            let key = if let Ok(number) = key.parse() {
+                VariantKey::Number(number)
+            } else if is_identifier(&key) {
+                VariantKey::Name(key)
+            } else {
+                return Err(format!("Invalid variant key: {key:?}"));
+            };
+            self.skip_spaces();
+            variants.push((key, self.pattern(true)?));
+        }
+        let default = default.ok_or("A default variant must be marked with `*`")?;
+        Ok(Element::Select {
+            variable,
+            variants,
+            default,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Message bundles for all languages, and the current language.
+///
+/// Get it with [`crate::Context::localization`].
+#[derive(Clone, Debug)]
+pub struct Localization {
+    language: String,
+    bundles: Vec<MessageBundle>,
+}
+
+impl Default for Localization {
+    /// English, with the messages of egui's built-in widgets.
+    fn default() -> Self {
+        Self {
+            language: FALLBACK_LANGUAGE.to_owned(),
+            bundles: vec![BUILTIN_BUNDLE.clone()],
+        }
+    }
+}
+
+impl Localization {
+    /// The [`Default`] localization, without building it again.
+    pub(crate) fn english() -> &'static Self {
+        static ENGLISH: LazyLock<Localization> = LazyLock::new(Localization::default);
+        &ENGLISH
+    }
+
+    /// The current language, e.g. `"sv"`.
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Prefer [`crate::Context::set_language`], which also updates the ui.
+    pub fn set_language(&mut self, language: impl Into<String>) {
+        self.language = language.into();
+    }
+
+    /// Add translations, replacing any messages with the same ids in the same language.
+    pub fn add_bundle(&mut self, bundle: MessageBundle) {
+        if let Some(existing) = self
+            .bundles
+            .iter_mut()
+            .find(|existing| existing.language.eq_ignore_ascii_case(&bundle.language))
+        {
+            existing.merge(bundle);
+        } else {
+            self.bundles.push(bundle);
+        }
+    }
+
+    /// Is there a message with this id in the given language?
+    ///
+    /// Libraries can use this to only add their English messages once.
+    pub fn has_message(&self, language: &str, id: &str) -> bool {
+        self.bundle(language)
+            .is_some_and(|bundle| bundle.has_message(id))
+    }
+
+    /// How numbers are written in the current language.
+    pub fn number_symbols(&self) -> NumberSymbols {
+        NumberSymbols::for_language(&self.language)
+    }
+
+    /// The message with this id in the current language.
+    ///
+    /// Falls back to the base language (`"pt"` for `"pt-BR"`), then to English,
+    /// and finally to the id itself.
+    pub fn format(&self, id: &str, args: &MessageArgs) -> String {
+        let mut formatted = String::new();
+        self.write_message(&mut formatted, id, args, 0);
+        formatted
+    }
+
+    fn bundle(&self, language: &str) -> Option<&MessageBundle> {
+        self.bundles
+            .iter()
+            .find(|bundle| bundle.language.eq_ignore_ascii_case(language))
+    }
+
+    fn write_message(&self, out: &mut String, id: &str, args: &MessageArgs, depth: usize) {
+        let primary = primary_language(&self.language);
+        let found = [self.language.as_str(), &primary, FALLBACK_LANGUAGE]
+            .into_iter()
+            .filter_map(|language| self.bundle(language))
+            .find_map(|bundle| Some((bundle.language.as_str(), bundle.messages.get(id)?)));
+
+        if let Some((language, pattern)) = found
+            && depth < MAX_REFERENCE_DEPTH
+        {
+            self.write_pattern(out, language, pattern, args, depth);
+        } else {
+            log::debug!("Missing message {id:?} for language {:?}", self.language);
+            out.push_str(id);
+        }
+    }
+
+    fn write_pattern(
+        &self,
+        out: &mut String,
+        language: &str,
+        pattern: &[Element],
+        args: &MessageArgs,
+        depth: usize,
+    ) {
+        for element in pattern {
+            match element {
+                Element::Text(text) => out.push_str(text),
+                Element::Variable(name) => match args.get(name) {
+                    Some(MessageArg::Number(number)) => {
+                        out.push_str(&NumberSymbols::for_language(language).format_arg(*number));
+                    }
+                    Some(MessageArg::Text(text)) => out.push_str(text),
+                    None => {
+                        out.push_str("{$");
+                        out.push_str(name);
+                        out.push('}');
+                    }
+                },
+                Element::Message(id) => self.write_message(out, id, args, depth + 1),
+                Element::Select {
+                    variable,
+                    variants,
+                    default,
+                } => {
+                    let selected = args.get(variable).and_then(|arg| {
+                        variants.iter().position(|(key, _)| match (arg, key) {
+                            (MessageArg::Number(number), VariantKey::Number(key)) => number == key,
+                            (MessageArg::Number(number), VariantKey::Name(key)) => {
+                                PluralCategory::of(language, *number).name() == key
+                            }
+                            (MessageArg::Text(text), VariantKey::Name(key)) => text == key,
+                            (MessageArg::Text(_), VariantKey::Number(_)) => false,
+                        })
+                    });
+                    // Exact numbers win over plural categories:
+                    let exact = args.get(variable).and_then(|arg| match arg {
+                        MessageArg::Number(number) => variants.iter().position(
+                            |(key, _)| matches!(key, VariantKey::Number(key) if key == number),
+                        ),
+                        MessageArg::Text(_) => None,
+                    });
+                    let (_, variant) = &variants[exact.or(selected).unwrap_or(*default)];
+                    self.write_pattern(out, language, variant, args, depth);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn localization(language: &str, source: &str) -> Localization {
+        let mut localization = Localization::default();
+        localization.add_bundle(MessageBundle::parse(language, source).expect("Should parse"));
+        localization.set_language(language);
+        localization
+    }
+
+    #[test]
+    fn messages() {
+        let l = localization(
+            "pl",
+            r#"
+hello = Cześć { $name }!
+brace = { "{" }braces{ "}" }
+files = { $count ->
+    [0] Brak plików
+    [one] { $count } plik
+    [few] { $count } pliki
+   *[many] { $count } plików
+}
+summary =
+    { hello }
+    { files }
+"#,
+        );
+        let args = |count: i32| MessageArgs::new().with("count", count).with("name", "Ada");
+
+        assert_eq!(l.format("hello", &args(0)), "Cześć Ada!");
+        assert_eq!(l.format("brace", &args(0)), "{braces}");
+        assert_eq!(l.format("files", &args(0)), "Brak plików", "Exact match");
+        assert_eq!(l.format("files", &args(1)), "1 plik");
+        assert_eq!(l.format("files", &args(3)), "3 pliki");
+        assert_eq!(l.format("files", &args(5)), "5 plików");
+        assert_eq!(l.format("files", &args(22)), "22 pliki");
+        assert_eq!(l.format("files", &args(1235)), "1\u{a0}235 plików");
+        assert_eq!(
+            l.format("summary", &args(1)),
+            "Cześć Ada!\n1 plik",
+            "Multi-line messages with references"
+        );
+        assert_eq!(
+            l.format("hello", &MessageArgs::new()),
+            "Cześć {$name}!",
+            "Missing argument"
+        );
+        assert_eq!(
+            l.format("egui-color-hue", &args(0)),
+            "Hue",
+            "Falls back to English"
+        );
+        assert_eq!(l.format("missing", &args(0)), "missing");
+    }
+
+    #[test]
+    fn parse_errors() {
+        for source in [
+            "no equals sign",
+            "bad id! = x",
+            "x = { $count ->\n  [one] one\n}",
+            "x = unbalanced }",
+            "x = { $unterminated",
+        ] {
+            assert!(
+                MessageBundle::parse("en", source).is_err(),
+                "Should fail to parse {source:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn number_symbols() {
+        let de = NumberSymbols::for_language("de");
+        assert_eq!(de.format(-1234567.5, 1..=1), "-1.234.567,5");
+        assert_eq!(de.parse("-1.234.567,5"), Some(-1_234_567.5));
+        assert_eq!(de.parse("1234,5"), Some(1234.5));
+        assert_eq!(de.parse("1.5"), None, "Not a group of three digits");
+        assert_eq!(de.parse("12.34.567"), None, "Not a group of three digits");
+        assert_eq!(de.parse("1,5.0"), None, "Grouping in the fraction");
+        let en = NumberSymbols::for_language("en");
+        assert_eq!(en.parse("1,234.5"), Some(1234.5));
+        assert_eq!(en.parse("1,5"), None, "Not a group of three digits");
+        assert_eq!(
+            NumberSymbols::for_language("de-CH").format(1234.0, 0..=0),
+            "1’234"
+        );
+        assert_eq!(NumberSymbols::PLAIN.format(1234.5, 1..=1), "1234.5");
+        assert_eq!(NumberSymbols::PLAIN.parse(" −1 234.5"), Some(-1234.5));
+    }
+}
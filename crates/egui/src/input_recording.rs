@@ -0,0 +1,318 @@
+//! Record every [`RawInput`] of a session and play it back later.
+//!
+//! egui is deterministic given its input, so a recording of a user session can be used to
+//! reproduce bugs, e.g. in `egui_kittest` with `Harness::replay`.
+//!
+//! Add the [`InputRecorder`] plugin to record, and the [`InputReplayer`] plugin to play back:
+//! ```
+//! # let ctx = egui::Context::default();
+//! use egui::input_recording::InputRecorder;
+//!
+//! ctx.add_plugin(InputRecorder::default());
+//!
+//! // … later:
+//! let recording = ctx.plugin::<InputRecorder>().lock().take_recording();
+//! ```
+//!
+//! With the `persistence` feature, recordings can be saved to and loaded from (compact) RON files.
+
+use crate::{Event, Plugin, RawInput, Ui, UserData, ViewportCommand, ViewportIdMap, ViewportInfo};
+
+/// A single recorded frame.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RecordedFrame {
+    /// The input of the frame.
+    ///
+    /// To keep recordings small, [`RawInput::viewports`] is empty if it is the same as in the
+    /// previous frame of the same viewport. Use [`InputRecording::inputs`] to get the full input.
+    pub input: RawInput,
+
+    /// Markers added with [`InputRecorder::mark`] during this frame.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub markers: Vec<String>,
+}
+
+/// A recording of [`RawInput`]s, made by [`InputRecorder`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InputRecording {
+    /// All recorded frames, of all viewports, in order.
+    pub frames: Vec<RecordedFrame>,
+}
+
+impl InputRecording {
+    /// Number of recorded frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The recorded inputs, with their markers.
+    pub fn inputs(&self) -> impl Iterator<Item = (RawInput, &[String])> + '_ {
+        let mut last_viewports = ViewportIdMap::default();
+        self.frames.iter().map(move |frame| {
+            let mut input = frame.input.clone();
+            if input.viewports.is_empty() {
+                input.viewports = last_viewports
+                    .get(&input.viewport_id)
+                    .cloned()
+                    .unwrap_or_default();
+            } else {
+                last_viewports.insert(input.viewport_id, input.viewports.clone());
+            }
+            (input, frame.markers.as_slice())
+        })
+    }
+}
+
+#[cfg(feature = "persistence")]
+impl InputRecording {
+    /// Serialize to compact RON.
+    ///
+    /// # Errors
+    /// If serialization fails.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::to_string(self)
+    }
+
+    /// Deserialize from RON.
+    ///
+    /// # Errors
+    /// If the RON is not a valid recording.
+    pub fn from_ron(ron: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(ron)
+    }
+
+    /// Save the recording as compact RON.
+    ///
+    /// # Errors
+    /// If serialization or writing the file fails.
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let ron = self.to_ron().map_err(std::io::Error::other)?;
+        std::fs::write(path, ron)
+    }
+
+    /// Load a recording saved with [`Self::save`].
+    ///
+    /// # Errors
+    /// If reading the file fails, or it is not a valid recording.
+    pub fn load(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let ron = std::fs::read_to_string(path)?;
+        Self::from_ron(&ron).map_err(std::io::Error::other)
+    }
+}
+
+/// A [`Plugin`] that records every [`RawInput`] into an [`InputRecording`].
+///
+/// Get the recording with [`crate::Context::plugin`]:
+/// ```
+/// # let ctx = egui::Context::default();
+/// # ctx.add_plugin(egui::input_recording::InputRecorder::default());
+/// let recording = ctx
+///     .plugin::<egui::input_recording::InputRecorder>()
+///     .lock()
+///     .take_recording();
+/// ```
+#[derive(Debug)]
+pub struct InputRecorder {
+    /// Are we currently recording? Default is `true`.
+    pub recording: bool,
+
+    inputs: InputRecording,
+
+    /// The last recorded [`RawInput::viewports`] of each viewport.
+    last_viewports: ViewportIdMap<ViewportIdMap<ViewportInfo>>,
+}
+
+impl Default for InputRecorder {
+    fn default() -> Self {
+        Self {
+            recording: true,
+            inputs: InputRecording::default(),
+            last_viewports: Default::default(),
+        }
+    }
+}
+
+impl InputRecorder {
+    /// The recording so far.
+    pub fn recording(&self) -> &InputRecording {
+        &self.inputs
+    }
+
+    /// Take the recording so far, and start a new one.
+    pub fn take_recording(&mut self) -> InputRecording {
+        self.last_viewports.clear();
+        std::mem::take(&mut self.inputs)
+    }
+
+    /// Mark the current frame, e.g. to take a snapshot there when replaying.
+    ///
+    /// Call this during the frame, e.g. from your ui code:
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// use egui::input_recording::InputRecorder;
+    ///
+    /// ctx.with_plugin(|recorder: &mut InputRecorder| recorder.mark("saved"));
+    /// ```
+    pub fn mark(&mut self, name: impl Into<String>) {
+        if self.recording
+            && let Some(frame) = self.inputs.frames.last_mut()
+        {
+            frame.markers.push(name.into());
+        }
+    }
+}
+
+impl Plugin for InputRecorder {
+    fn debug_name(&self) -> &'static str {
+        "InputRecorder"
+    }
+
+    fn input_hook(&mut self, input: &mut RawInput) {
+        if !self.recording {
+            return;
+        }
+
+        let mut input = input.clone();
+        // Screenshots are big, and are the result of earlier input anyway:
+        input
+            .events
+            .retain(|event| !matches!(event, Event::Screenshot { .. }));
+        if self.last_viewports.get(&input.viewport_id) == Some(&input.viewports) {
+            input.viewports.clear();
+        } else {
+            self.last_viewports
+                .insert(input.viewport_id, input.viewports.clone());
+        }
+
+        self.inputs.frames.push(RecordedFrame {
+            input,
+            markers: Vec::new(),
+        });
+    }
+}
+
+/// A [`Plugin`] that replaces the real input with the frames of an [`InputRecording`].
+///
+/// Frames are played back one per pass, in order, as long as their viewport matches.
+/// The real input is ignored until the recording is done,
+/// except for [`Event::Screenshot`]s.
+///
+/// For tests, `egui_kittest::Harness::replay` is usually more convenient.
+#[derive(Debug)]
+pub struct InputReplayer {
+    inputs: Vec<(RawInput, Vec<String>)>,
+    next_frame: usize,
+
+    /// Request a screenshot with [`ViewportCommand::Screenshot`] at every marker.
+    ///
+    /// The [`UserData`] of the screenshot is the name of the marker, as a [`String`].
+    pub screenshot_markers: bool,
+
+    reached_markers: Vec<String>,
+}
+
+impl InputReplayer {
+    pub fn new(recording: &InputRecording) -> Self {
+        Self {
+            inputs: recording
+                .inputs()
+                .map(|(input, markers)| (input, markers.to_vec()))
+                .collect(),
+            next_frame: 0,
+            screenshot_markers: false,
+            reached_markers: Vec::new(),
+        }
+    }
+
+    /// Request a screenshot at every marker. See [`Self::screenshot_markers`].
+    #[inline]
+    pub fn with_screenshot_markers(mut self, screenshot_markers: bool) -> Self {
+        self.screenshot_markers = screenshot_markers;
+        self
+    }
+
+    /// Has the whole recording been played back?
+    pub fn is_done(&self) -> bool {
+        self.inputs.len() <= self.next_frame
+    }
+
+    /// Number of frames played back so far.
+    pub fn frames_played(&self) -> usize {
+        self.next_frame
+    }
+}
+
+impl Plugin for InputReplayer {
+    fn debug_name(&self) -> &'static str {
+        "InputReplayer"
+    }
+
+    fn input_hook(&mut self, input: &mut RawInput) {
+        let Some((recorded, markers)) = self.inputs.get(self.next_frame) else {
+            return;
+        };
+        if recorded.viewport_id != input.viewport_id {
+            return;
+        }
+
+        let screenshots = input
+            .events
+            .drain(..)
+            .filter(|event| matches!(event, Event::Screenshot { .. }));
+        let screenshots: Vec<Event> = screenshots.collect();
+        *input = recorded.clone();
+        input.events.extend(screenshots);
+
+        self.reached_markers.extend(markers.iter().cloned());
+        self.next_frame += 1;
+    }
+
+    fn on_begin_pass(&mut self, ui: &mut Ui) {
+        for marker in self.reached_markers.drain(..) {
+            if self.screenshot_markers {
+                ui.ctx()
+                    .send_viewport_cmd(ViewportCommand::Screenshot(UserData::new(marker)));
+            }
+        }
+
+        if !self.is_done() {
+            // Keep going until the recording is done, regardless of what the ui requests:
+            ui.ctx().request_repaint();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "persistence"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ron_round_trip() {
+        let mut recorder = InputRecorder::default();
+        for i in 0..3 {
+            let mut input = RawInput {
+                time: Some(i as f64),
+                ..Default::default()
+            };
+            input.events.push(Event::Text(format!("{i}")));
+            recorder.input_hook(&mut input);
+        }
+        recorder.mark("done");
+
+        let recording = recorder.take_recording();
+        assert!(recording.frames[1].input.viewports.is_empty());
+        assert_eq!(recording.frames[2].markers, vec!["done".to_owned()]);
+
+        let ron = recording.to_ron().unwrap();
+        assert_eq!(InputRecording::from_ron(&ron).unwrap(), recording);
+    }
+}
@@ -8,8 +8,8 @@ use crate::{
 };
 use crate::{
     data::input::{
-        Event, EventFilter, KeyboardShortcut, Modifiers, NUM_POINTER_BUTTONS, PointerButton,
-        RawInput, TouchDeviceId, ViewportInfo,
+        Event, EventFilter, KeyboardShortcut, Modifiers, NUM_POINTER_BUTTONS, PenInfo,
+        PointerButton, PointerKind, RawInput, TouchDeviceId, ViewportInfo,
     },
     input_state::wheel_state::WheelState,
 };
@@ -1008,6 +1008,9 @@ pub struct PointerState {
     /// When tapping a touch screen, this will be the location of the touch.
     interact_pos: Option<Pos2>,
 
+    /// Latest reported pointer position, ignoring all [`Event::PointerGone`].
+    last_known_pos: Option<Pos2>,
+
     /// How much the pointer moved compared to last frame, in points.
     delta: Vec2,
 
@@ -1022,6 +1025,12 @@ pub struct PointerState {
     /// Current direction of pointer.
     direction: Vec2,
 
+    /// What kind of device last moved or pressed the pointer.
+    kind: PointerKind,
+
+    /// Latest state of the pen, if the pointer is controlled by a pen.
+    pen: Option<PenInfo>,
+
     /// Recent movement of the pointer.
     /// Used for calculating velocity of pointer.
     pos_history: History<Pos2>,
@@ -1077,10 +1086,13 @@ impl Default for PointerState {
             time: -f64::INFINITY,
             latest_pos: None,
             interact_pos: None,
+            last_known_pos: None,
             delta: Vec2::ZERO,
             motion: None,
             velocity: Vec2::ZERO,
             direction: Vec2::ZERO,
+            kind: PointerKind::default(),
+            pen: None,
             pos_history: History::new(2..1000, 0.1),
             down: Default::default(),
             press_origin: None,
@@ -1114,10 +1126,29 @@ impl PointerState {
         }
 
         let mut clear_history_after_velocity_calculation = false;
+
+        // Pen and touch events come right before the pointer events they cause,
+        // at the same position. Other pointer events come from the mouse.
+        let mut pen_or_touch: Option<(PointerKind, Pos2)> = None;
+        let kind_at = |pen_or_touch: Option<(PointerKind, Pos2)>, pos: Pos2| match pen_or_touch {
+            Some((kind, at)) if at == pos => kind,
+            _ => PointerKind::Mouse,
+        };
+
         for event in &new.events {
             match event {
+                Event::Pen { pos, info } => {
+                    pen_or_touch = Some((PointerKind::Pen, *pos));
+                    self.pen = Some(*info);
+                }
+                Event::Touch { pos, .. } => {
+                    if kind_at(pen_or_touch, *pos) != PointerKind::Pen {
+                        pen_or_touch = Some((PointerKind::Touch, *pos));
+                    }
+                }
                 Event::PointerMoved(pos) => {
                     let pos = *pos;
+                    self.set_kind(kind_at(pen_or_touch, pos));
 
                     self.latest_pos = Some(pos);
                     self.interact_pos = Some(pos);
@@ -1140,6 +1171,7 @@ impl PointerState {
                     let button = *button;
                     let pressed = *pressed;
                     let modifiers = *modifiers;
+                    self.set_kind(kind_at(pen_or_touch, pos));
 
                     self.latest_pos = Some(pos);
                     self.interact_pos = Some(pos);
@@ -1207,6 +1239,7 @@ impl PointerState {
                 }
                 Event::PointerGone => {
                     self.latest_pos = None;
+                    self.pen = None;
                     // When dragging a slider and the mouse leaves the viewport, we still want the drag to work,
                     // so we don't treat this as a `PointerEvent::Released`.
                     // NOTE: we do NOT clear `self.interact_pos` here. It will be cleared next frame.
@@ -1228,6 +1261,7 @@ impl PointerState {
 
         if let Some(pos) = self.latest_pos {
             self.pos_history.add(time, pos);
+            self.last_known_pos = Some(pos);
         } else {
             // we do not clear the `pos_history` here, because it is exactly when a finger has
             // released from the touch screen that we may want to assign a velocity to whatever
@@ -1255,6 +1289,13 @@ impl PointerState {
         self
     }
 
+    fn set_kind(&mut self, kind: PointerKind) {
+        self.kind = kind;
+        if kind != PointerKind::Pen {
+            self.pen = None;
+        }
+    }
+
     fn wants_repaint(&self) -> bool {
         !self.pointer_events.is_empty() || self.delta != Vec2::ZERO
     }
@@ -1290,6 +1331,34 @@ impl PointerState {
         self.direction
     }
 
+    /// What kind of device last moved or pressed the pointer?
+    #[inline(always)]
+    pub fn kind(&self) -> PointerKind {
+        self.kind
+    }
+
+    /// The latest pressure, tilt, etc. of the pen,
+    /// if the pointer is controlled by a pen.
+    ///
+    /// This is `None` when the pen leaves the surface and stops hovering.
+    #[inline(always)]
+    pub fn pen(&self) -> Option<PenInfo> {
+        self.pen
+    }
+
+    /// How hard the pointer is pressed, from 0.0 to 1.0.
+    ///
+    /// This is the pressure of the pen, if any, otherwise 1.0 if the primary button is down.
+    pub fn pressure(&self) -> f32 {
+        if let Some(pen) = self.pen {
+            pen.pressure
+        } else if self.primary_down() {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
     /// Where did the current click/drag originate?
     /// `None` if no mouse button is down.
     #[inline(always)]
@@ -1332,6 +1401,15 @@ impl PointerState {
         self.interact_pos
     }
 
+    /// Latest reported pointer position, even if the pointer has since left the egui area.
+    ///
+    /// Useful when the integration doesn't report the pointer position,
+    /// e.g. while files are dragged over the window.
+    #[inline(always)]
+    pub fn last_known_pos(&self) -> Option<Pos2> {
+        self.last_known_pos
+    }
+
     /// Do we have a pointer?
     ///
     /// `false` if the mouse is not over the egui area, or if no touches are down on touch screens.
@@ -1528,6 +1606,15 @@ impl PointerState {
             })
     }
 
+    /// Would a press at this position be the second tap of a double-tap?
+    pub(crate) fn is_double_tap_press(&self, pos: Pos2) -> bool {
+        self.time - self.last_click_time < self.options.max_double_click_delay
+            && self.last_click_pos.is_some_and(|last_pos| {
+                last_pos.distance_sq(pos)
+                    < self.options.max_click_dist * self.options.max_click_dist
+            })
+    }
+
     /// Is the primary button currently down?
     ///
     /// Buttons released this frame are NOT considered down.
@@ -1653,10 +1740,13 @@ impl PointerState {
             time: _,
             latest_pos,
             interact_pos,
+            last_known_pos,
             delta,
             motion,
             velocity,
             direction,
+            kind,
+            pen,
             pos_history: _,
             down,
             press_origin,
@@ -1673,6 +1763,7 @@ impl PointerState {
 
         ui.label(format!("latest_pos: {latest_pos:?}"));
         ui.label(format!("interact_pos: {interact_pos:?}"));
+        ui.label(format!("last_known_pos: {last_known_pos:?}"));
         ui.label(format!("delta: {delta:?}"));
         ui.label(format!("motion: {motion:?}"));
         ui.label(format!(
@@ -1680,6 +1771,8 @@ impl PointerState {
             velocity.x, velocity.y
         ));
         ui.label(format!("direction: {direction:?}"));
+        ui.label(format!("kind: {kind:?}"));
+        ui.label(format!("pen: {pen:#?}"));
         ui.label(format!("down: {down:#?}"));
         ui.label(format!("press_origin: {press_origin:?}"));
         ui.label(format!("press_start_time: {press_start_time:?} s"));
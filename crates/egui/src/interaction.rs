@@ -1,6 +1,9 @@
 //! How mouse and touch interzcts with widgets.
 
-use crate::{Id, InputState, Key, WidgetRects, hit_test, id, input_state, memory};
+use crate::{
+    Direction, Id, InputState, Key, Vec2, WidgetRects, hit_test, id, input_state, memory,
+    style::Interaction,
+};
 
 use self::{hit_test::WidgetHits, id::IdSet, input_state::PointerEvent, memory::InteractionState};
 
@@ -18,6 +21,11 @@ pub struct InteractionSnapshot {
     /// so trigger a secondary click on it (context menu).
     pub long_touched: Option<Id>,
 
+    /// The pointer was held still on this widget for [`Interaction::long_press_duration`].
+    ///
+    /// Releasing the pointer after this will not register as a click.
+    pub long_pressed: Option<Id>,
+
     /// Drag started on this widget this frame.
     ///
     /// This will also be found in `dragged` this frame.
@@ -39,6 +47,20 @@ pub struct InteractionSnapshot {
     /// The widget will not be found in [`Self::dragged`] this frame.
     pub drag_stopped: Option<Id>,
 
+    /// This widget is being dragged by the second tap of a double-tap,
+    /// i.e. the user tapped, and then pressed again and dragged.
+    pub double_tap_dragged: Option<Id>,
+
+    /// This widget was swiped in this direction this frame.
+    ///
+    /// Set the same frame as [`Self::drag_stopped`].
+    pub swiped: Option<(Id, Direction)>,
+
+    /// This widget was let go with this velocity (in points per second) this frame.
+    ///
+    /// Set the same frame as [`Self::drag_stopped`].
+    pub flung: Option<(Id, Vec2)>,
+
     /// A small set of widgets (usually 0-1) that the pointer is hovering over.
     ///
     /// Show these widgets as highlighted, if they are interactive.
@@ -61,9 +83,13 @@ impl InteractionSnapshot {
         let Self {
             clicked,
             long_touched,
+            long_pressed,
             drag_started,
             dragged,
             drag_stopped,
+            double_tap_dragged,
+            swiped,
+            flung,
             hovered,
             contains_pointer,
         } = self;
@@ -83,6 +109,10 @@ impl InteractionSnapshot {
             id_ui(ui, long_touched);
             ui.end_row();
 
+            ui.label("long_pressed");
+            id_ui(ui, long_pressed);
+            ui.end_row();
+
             ui.label("drag_started");
             id_ui(ui, drag_started);
             ui.end_row();
@@ -95,6 +125,18 @@ impl InteractionSnapshot {
             id_ui(ui, drag_stopped);
             ui.end_row();
 
+            ui.label("double_tap_dragged");
+            id_ui(ui, double_tap_dragged);
+            ui.end_row();
+
+            ui.label("swiped");
+            id_ui(ui, swiped.as_ref().map(|(id, _)| id));
+            ui.end_row();
+
+            ui.label("flung");
+            id_ui(ui, flung.as_ref().map(|(id, _)| id));
+            ui.end_row();
+
             ui.label("hovered");
             id_ui(ui, hovered);
             ui.end_row();
@@ -112,6 +154,7 @@ pub(crate) fn interact(
     hits: &WidgetHits,
     input: &InputState,
     interaction: &mut InteractionState,
+    style: &Interaction,
 ) -> InteractionSnapshot {
     profiling::function_scope!();
 
@@ -133,6 +176,7 @@ pub(crate) fn interact(
     let mut clicked = None;
     let mut dragged = prev_snapshot.dragged;
     let mut long_touched = None;
+    let mut long_pressed = None;
 
     if input.key_pressed(Key::Escape) {
         // Abort dragging on escape
@@ -140,6 +184,13 @@ pub(crate) fn interact(
         interaction.potential_drag_id = None;
     }
 
+    if long_press_remaining(input, interaction, style).is_some_and(|remaining| remaining <= 0.0) {
+        long_pressed = interaction
+            .potential_click_id
+            .or(interaction.potential_drag_id);
+        interaction.long_pressed = true;
+    }
+
     if input.is_long_touch() {
         // We implement "press-and-hold for context menu" on touch screens here
         if let Some(widget) = interaction
@@ -159,7 +210,7 @@ pub(crate) fn interact(
         match pointer_event {
             PointerEvent::Moved(_) => {}
 
-            PointerEvent::Pressed { .. } => {
+            PointerEvent::Pressed { position, .. } => {
                 // Maybe new click?
                 if interaction.potential_click_id.is_none() {
                     interaction.potential_click_id = hits.click.map(|w| w.id);
@@ -169,10 +220,15 @@ pub(crate) fn interact(
                 if interaction.potential_drag_id.is_none() {
                     interaction.potential_drag_id = hits.drag.map(|w| w.id);
                 }
+
+                interaction.press_origin = Some(*position);
+                interaction.double_tap_press = input.pointer.is_double_tap_press(*position);
+                interaction.long_pressed = false;
             }
 
             PointerEvent::Released { click, button: _ } => {
                 if click.is_some()
+                    && !(interaction.long_pressed && style.long_press_cancels_click)
                     && !input.pointer.is_decidedly_dragging()
                     && let Some(widget) = interaction
                         .potential_click_id
@@ -183,6 +239,7 @@ pub(crate) fn interact(
 
                 interaction.potential_drag_id = None;
                 interaction.potential_click_id = None;
+                interaction.long_pressed = false;
                 dragged = None;
             }
         }
@@ -223,6 +280,29 @@ pub(crate) fn interact(
     let drag_changed = dragged != prev_snapshot.dragged;
     let drag_stopped = drag_changed.then_some(prev_snapshot.dragged).flatten();
     let drag_started = drag_changed.then_some(dragged).flatten();
+    let double_tap_dragged = dragged.filter(|_| interaction.double_tap_press);
+
+    let mut swiped = None;
+    let mut flung = None;
+    if let Some(id) = drag_stopped
+        && input.pointer.any_released()
+    {
+        let velocity = input.pointer.velocity();
+        let speed = velocity.length();
+        if style.fling_min_speed <= speed {
+            flung = Some((id, velocity));
+        }
+        if let (Some(origin), Some(pos)) = (interaction.press_origin, input.pointer.interact_pos())
+        {
+            let delta = pos - origin;
+            if style.swipe_min_distance <= delta.length()
+                && style.swipe_min_speed <= speed
+                && 0.0 < delta.dot(velocity)
+            {
+                swiped = Some((id, swipe_direction(delta)));
+            }
+        }
+    }
 
     // if let Some(drag_started) = drag_started {
     //     eprintln!(
@@ -290,10 +370,48 @@ pub(crate) fn interact(
     InteractionSnapshot {
         clicked,
         long_touched,
+        long_pressed,
         drag_started,
         dragged,
         drag_stopped,
+        double_tap_dragged,
+        swiped,
+        flung,
         hovered,
         contains_pointer,
     }
 }
+
+/// How many seconds until the current press becomes a long-press,
+/// or `None` if it can't become one.
+pub(crate) fn long_press_remaining(
+    input: &InputState,
+    interaction: &InteractionState,
+    style: &Interaction,
+) -> Option<f32> {
+    let is_pressing_widget =
+        interaction.potential_click_id.is_some() || interaction.potential_drag_id.is_some();
+    if !is_pressing_widget
+        || interaction.long_pressed
+        || input.pointer.has_moved_too_much_for_a_click
+    {
+        return None;
+    }
+    let press_duration = (input.time - input.pointer.press_start_time()?) as f32;
+    Some(style.long_press_duration - press_duration)
+}
+
+/// The main direction of a swipe.
+fn swipe_direction(delta: Vec2) -> Direction {
+    if delta.y.abs() <= delta.x.abs() {
+        if 0.0 < delta.x {
+            Direction::LeftToRight
+        } else {
+            Direction::RightToLeft
+        }
+    } else if 0.0 < delta.y {
+        Direction::TopDown
+    } else {
+        Direction::BottomUp
+    }
+}
@@ -212,11 +212,17 @@ impl Widget for &memory::InteractionState {
         let memory::InteractionState {
             potential_click_id,
             potential_drag_id,
+            press_origin,
+            double_tap_press,
+            long_pressed,
         } = self;
 
         ui.vertical(|ui| {
             ui.label(format!("potential_click_id: {potential_click_id:?}"));
             ui.label(format!("potential_drag_id: {potential_drag_id:?}"));
+            ui.label(format!("press_origin: {press_origin:?}"));
+            ui.label(format!("double_tap_press: {double_tap_press:?}"));
+            ui.label(format!("long_pressed: {long_pressed:?}"));
         })
         .response
     }
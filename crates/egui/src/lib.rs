@@ -388,6 +388,7 @@
 #![expect(clippy::float_cmp)]
 #![expect(clippy::manual_range_contains)]
 
+pub mod accessibility_audit;
 mod animation_manager;
 mod atomics;
 pub mod cache;
@@ -396,10 +397,13 @@ mod context;
 mod data;
 pub mod debug_text;
 mod drag_and_drop;
+mod file_drop;
 pub(crate) mod grid;
 pub mod gui_zoom;
 mod hit_test;
+pub mod i18n;
 mod id;
+pub mod input_recording;
 mod input_state;
 mod interaction;
 pub mod introspection;
@@ -414,6 +418,7 @@ mod painter;
 mod pass_state;
 pub(crate) mod placer;
 pub mod plugin;
+mod promise;
 pub mod response;
 mod sense;
 pub mod style;
@@ -471,19 +476,20 @@ pub use self::{
         Key, UserData,
         input::*,
         output::{
-            self, CursorIcon, FullOutput, OpenUrl, OutputCommand, PlatformOutput,
-            UserAttentionType, WidgetInfo,
+            self, ClipboardData, CursorIcon, CursorImage, FullOutput, OpenUrl, OutputCommand,
+            PlatformOutput, UserAttentionType, WidgetInfo,
         },
     },
     drag_and_drop::DragAndDrop,
     epaint::text::TextWrapMode,
+    file_drop::{FileDrop, FileFilter},
     grid::Grid,
     id::{Id, IdMap, IdSet},
     input_state::{InputOptions, InputState, MultiTouchInfo, PointerState, SurrenderFocusOn},
     layers::{LayerId, Order},
     layout::*,
     load::SizeHint,
-    memory::{FocusDirection, Memory, Options, Theme, ThemePreference},
+    memory::{FocusDirection, FocusScope, Memory, Options, Theme, ThemePreference},
     painter::Painter,
     plugin::Plugin,
     response::{InnerResponse, Response},
@@ -494,7 +500,7 @@ pub use self::{
     ui_builder::UiBuilder,
     ui_stack::*,
     viewport::*,
-    widget_rect::{InteractOptions, WidgetRect, WidgetRects},
+    widget_rect::{HitShape, InteractOptions, WidgetRect, WidgetRects},
     widget_text::{RichText, WidgetText},
     widgets::*,
 };
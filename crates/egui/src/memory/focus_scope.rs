@@ -0,0 +1,297 @@
+//! Focus scopes and Tab order.
+
+use crate::{Id, IdMap, IdSet, LayerId, Rect};
+
+use super::{Focus, FocusDirection};
+
+/// A group of widgets that keyboard focus navigation treats as a unit.
+///
+/// Use it with [`crate::Ui::focus_scope`]:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::FocusScope;
+///
+/// // A toolbar is a single Tab stop, and the arrow keys move between its buttons:
+/// let toolbar = FocusScope::new(ui.make_persistent_id("toolbar")).roving(true);
+/// ui.focus_scope(toolbar, |ui| {
+///     ui.horizontal(|ui| {
+///         ui.button("Cut");
+///         ui.button("Copy");
+///         ui.button("Paste");
+///     });
+/// });
+/// # });
+/// ```
+///
+/// Scopes can be nested.
+/// See also [`crate::Memory::set_tab_index`] for changing the order of the Tab stops.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FocusScope {
+    pub(crate) id: Id,
+    trap: bool,
+    roving: bool,
+    skip: bool,
+}
+
+impl FocusScope {
+    /// The id should be the same each frame, since it is used to remember
+    /// which widget last had focus in a [roving](Self::roving) scope.
+    pub fn new(id: Id) -> Self {
+        Self {
+            id,
+            trap: false,
+            roving: false,
+            skip: false,
+        }
+    }
+
+    /// Keep the focus inside this scope.
+    ///
+    /// While a widget in this scope has focus, Tab and Shift+Tab cycle through the widgets
+    /// of this scope only. This is useful for e.g. dialogs.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn trap(mut self, trap: bool) -> Self {
+        self.trap = trap;
+        self
+    }
+
+    /// Make this scope a single Tab stop, with the arrow keys moving the focus within it.
+    ///
+    /// This is the expected behavior for toolbars, tab bars and groups of radio buttons.
+    /// Tab and Shift+Tab leave the scope, and focus returns to the widget that last had it
+    /// when tabbing back in.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn roving(mut self, roving: bool) -> Self {
+        self.roving = roving;
+        self
+    }
+
+    /// Make Tab and Shift+Tab skip over all widgets in this scope.
+    ///
+    /// The widgets can still get focus in other ways, e.g. by clicking them.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn skip(mut self, skip: bool) -> Self {
+        self.skip = skip;
+        self
+    }
+}
+
+/// A [`FocusScope`] that is used this frame.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ScopeState {
+    pub scope: FocusScope,
+
+    /// The enclosing scope, if any.
+    pub parent: Option<Id>,
+}
+
+/// A widget interested in focus.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FocusCandidate {
+    pub id: Id,
+
+    /// The innermost [`FocusScope`] the widget is in, if any.
+    pub scope: Option<Id>,
+}
+
+/// See [`crate::Memory::restore_focus_when_closed`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FocusRestore {
+    pub layer_id: LayerId,
+
+    /// What had focus when the layer appeared.
+    pub focused_before: Option<Id>,
+
+    /// Was the layer shown this frame?
+    pub shown: bool,
+}
+
+impl Focus {
+    /// The given scope and all scopes around it, innermost first.
+    fn scope_chain(&self, scope: Option<Id>) -> impl Iterator<Item = &ScopeState> {
+        std::iter::successors(scope.and_then(|id| self.scopes.get(&id)), |state| {
+            let parent = state.parent?;
+            self.scopes.get(&parent)
+        })
+    }
+
+    fn is_in_scope(&self, candidate: &FocusCandidate, scope_id: Id) -> bool {
+        self.scope_chain(candidate.scope)
+            .any(|state| state.scope.id == scope_id)
+    }
+
+    /// The innermost roving scope the candidate is in, if any.
+    fn roving_scope(&self, candidate: &FocusCandidate) -> Option<Id> {
+        self.scope_chain(candidate.scope)
+            .find(|state| state.scope.roving)
+            .map(|state| state.scope.id)
+    }
+
+    fn tab_index(&self, id: Id) -> i32 {
+        self.tab_indices.get(&id).copied().unwrap_or(0)
+    }
+
+    /// Does Tab skip over the candidate, because of a negative tab index or a skipping scope?
+    ///
+    /// The widgets of a roving scope that aren't its Tab stop are not skipped,
+    /// since they can be reached with the arrow keys.
+    fn is_skipped_by_tab(&self, candidate: &FocusCandidate) -> bool {
+        self.tab_index(candidate.id) < 0
+            || self
+                .scope_chain(candidate.scope)
+                .any(|state| state.scope.skip)
+    }
+
+    /// The candidates of this frame, without duplicates.
+    ///
+    /// A widget can be interested in focus more than once per frame, e.g. a [`crate::DragValue`].
+    fn unique_candidates(&self) -> Vec<FocusCandidate> {
+        let mut seen = IdSet::default();
+        self.candidates
+            .iter()
+            .filter(|candidate| seen.insert(candidate.id))
+            .copied()
+            .collect()
+    }
+
+    fn focused_candidate(&self, candidates: &[FocusCandidate]) -> Option<FocusCandidate> {
+        let focused = self.focused()?;
+        candidates.iter().find(|c| c.id == focused).copied()
+    }
+
+    /// The widget of a roving scope that is its Tab stop.
+    fn roving_tab_stop(&self, roving: Id, candidates: &[FocusCandidate]) -> Option<Id> {
+        let mut members = candidates
+            .iter()
+            .filter(|c| self.is_in_scope(c, roving) && 0 <= self.tab_index(c.id));
+        let first = members.next()?.id;
+        let is_member = |id: Id| {
+            candidates
+                .iter()
+                .any(|c| c.id == id && self.is_in_scope(c, roving) && 0 <= self.tab_index(id))
+        };
+
+        if let Some(focused) = self.focused()
+            && is_member(focused)
+        {
+            Some(focused)
+        } else if let Some(&last) = self.roving_focus.get(&roving)
+            && is_member(last)
+        {
+            Some(last)
+        } else {
+            Some(first)
+        }
+    }
+
+    /// Where Tab or Shift+Tab should move the focus.
+    pub(super) fn find_widget_in_tab_order(&self) -> Option<Id> {
+        let candidates = self.unique_candidates();
+        let focused = self.focused_candidate(&candidates);
+
+        let trap = focused.and_then(|focused| {
+            self.scope_chain(focused.scope)
+                .find(|state| state.scope.trap)
+                .map(|state| state.scope.id)
+        });
+
+        let mut tab_stops: Vec<(i32, Id)> = candidates
+            .iter()
+            .filter(|c| {
+                !self.is_skipped_by_tab(c)
+                    && trap.is_none_or(|trap| self.is_in_scope(c, trap))
+                    && self.roving_scope(c).is_none_or(|roving| {
+                        self.roving_tab_stop(roving, &candidates) == Some(c.id)
+                    })
+            })
+            .map(|c| (self.tab_index(c.id), c.id))
+            .collect();
+
+        // Positive tab indices come first. The sort is stable, so the rest keep their order:
+        tab_stops.sort_by_key(|&(tab_index, _)| if 0 < tab_index { tab_index } else { i32::MAX });
+
+        let num_stops = tab_stops.len();
+        let position =
+            focused.and_then(|focused| tab_stops.iter().position(|&(_, id)| id == focused.id));
+        let next = match (self.focus_direction, position) {
+            (FocusDirection::Next, Some(i)) => (i + 1) % num_stops,
+            (FocusDirection::Next, None) => 0,
+            (FocusDirection::Previous, Some(i)) => (i + num_stops - 1) % num_stops,
+            (FocusDirection::Previous, None) => num_stops.checked_sub(1)?,
+            _ => return None,
+        };
+        tab_stops.get(next).map(|&(_, id)| id)
+    }
+
+    /// Where the arrow keys should move the focus, if the focused widget is in a roving scope.
+    pub(super) fn find_widget_in_roving_scope(&self) -> Option<Id> {
+        let candidates = self.unique_candidates();
+        let focused = self.focused_candidate(&candidates)?;
+        let roving = self.roving_scope(&focused)?;
+
+        let members: Vec<Id> = candidates
+            .iter()
+            .filter(|c| self.is_in_scope(c, roving) && 0 <= self.tab_index(c.id))
+            .map(|c| c.id)
+            .collect();
+        let i = members.iter().position(|&id| id == focused.id)?;
+        let num_members = members.len();
+        let next = match self.focus_direction {
+            FocusDirection::Right | FocusDirection::Down => (i + 1) % num_members,
+            FocusDirection::Left | FocusDirection::Up => (i + num_members - 1) % num_members,
+            _ => return None,
+        };
+        members.get(next).copied()
+    }
+
+    /// Remember which widget had focus in each roving scope, for when the user tabs back in.
+    pub(super) fn remember_roving_focus(&mut self) {
+        let candidates = self.unique_candidates();
+        if let Some(focused) = self.focused_candidate(&candidates)
+            && let Some(roving) = self.roving_scope(&focused)
+        {
+            self.roving_focus.insert(roving, focused.id);
+        }
+
+        let scopes = &self.scopes;
+        self.roving_focus
+            .retain(|scope_id, _| scopes.contains_key(scope_id));
+    }
+
+    /// Remember which widgets Tab skipped over, for [`crate::accessibility_audit`].
+    pub(super) fn remember_skipped_by_tab(&mut self) {
+        let skipped = self
+            .unique_candidates()
+            .iter()
+            .filter(|c| self.is_skipped_by_tab(c))
+            .map(|c| c.id)
+            .collect();
+        self.skipped_by_tab = skipped;
+    }
+
+    /// Return the focus to where it was before a popup or modal appeared,
+    /// if the focused widget disappeared with it.
+    pub(super) fn restore_focus_of_closed_layers(&mut self, used_ids: &IdMap<Rect>) {
+        let focused_is_gone = self.focused().is_none_or(|id| !used_ids.contains_key(&id));
+
+        if focused_is_gone
+            && let Some(id) = self
+                .restore_on_close
+                .iter()
+                .filter(|restore| !restore.shown)
+                .find_map(|restore| restore.focused_before)
+                .filter(|id| used_ids.contains_key(id))
+        {
+            self.id_next_frame = Some(id);
+        }
+
+        self.restore_on_close.retain(|restore| restore.shown);
+    }
+}
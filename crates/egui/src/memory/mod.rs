@@ -6,11 +6,15 @@ use ahash::{HashMap, HashSet};
 use epaint::emath::TSTransform;
 
 use crate::{
-    EventFilter, Id, IdMap, LayerId, Order, Pos2, Rangef, RawInput, Rect, Style, Vec2, ViewportId,
-    ViewportIdMap, ViewportIdSet, area, vec2,
+    EventFilter, Id, IdMap, IdSet, LayerId, Order, Pos2, Rangef, RawInput, Rect, Style, Vec2,
+    ViewportId, ViewportIdMap, ViewportIdSet, area, vec2,
 };
 
+mod focus_scope;
+#[cfg(feature = "persistence")]
+mod persistence;
 mod theme;
+pub use focus_scope::FocusScope;
 pub use theme::{Theme, ThemePreference};
 
 // ----------------------------------------------------------------------------
@@ -473,6 +477,18 @@ pub(crate) struct InteractionState {
     /// as that can only happen after the mouse has moved a bit
     /// (at least if the widget is interesated in both clicks and drags).
     pub potential_drag_id: Option<Id>,
+
+    /// Where the pointer was last pressed, for recognizing swipes.
+    pub press_origin: Option<crate::Pos2>,
+
+    /// Was the last press the second tap of a double-tap?
+    pub double_tap_press: bool,
+
+    /// Has the current press already been recognized as a long-press?
+    ///
+    /// If so, releasing it will not register as a click
+    /// if [`crate::style::Interaction::long_press_cancels_click`] is set.
+    pub long_pressed: bool,
 }
 
 /// Keeps tracks of what widget has keyboard focus
@@ -489,16 +505,30 @@ pub(crate) struct Focus {
 
     id_requested_by_accesskit: Option<accesskit::NodeId>,
 
-    /// If set, the next widget that is interested in focus will automatically get it.
-    /// Probably because the user pressed Tab.
-    give_to_next: bool,
-
-    /// The last widget interested in focus.
-    last_interested: Option<Id>,
-
     /// Set when looking for widget with navigational keys like arrows, tab, shift+tab.
     focus_direction: FocusDirection,
 
+    /// The widgets interested in focus this frame, in the order they were added.
+    candidates: Vec<focus_scope::FocusCandidate>,
+
+    /// The [`FocusScope`]s of this frame.
+    scopes: IdMap<focus_scope::ScopeState>,
+
+    /// The [`FocusScope`]s we are currently in, innermost last.
+    scope_stack: Vec<Id>,
+
+    /// Explicit tab indices set this frame, see [`Memory::set_tab_index`].
+    tab_indices: IdMap<i32>,
+
+    /// The widget that last had focus in each roving [`FocusScope`].
+    roving_focus: IdMap<Id>,
+
+    /// The widgets that Tab and Shift+Tab skipped over during the previous frame.
+    skipped_by_tab: IdSet,
+
+    /// Where to return focus when a popup or modal closes.
+    restore_on_close: Vec<focus_scope::FocusRestore>,
+
     /// The top-most modal layer from the previous frame.
     top_modal_layer: Option<LayerId>,
 
@@ -549,6 +579,14 @@ impl Focus {
 
         self.focus_direction = FocusDirection::None;
 
+        self.candidates.clear();
+        self.scopes.clear();
+        self.scope_stack.clear();
+        self.tab_indices.clear();
+        for restore in &mut self.restore_on_close {
+            restore.shown = false;
+        }
+
         for event in &new_input.events {
             if !event_filter.matches(event)
                 && let crate::Event::Key {
@@ -591,12 +629,28 @@ impl Focus {
     }
 
     pub(crate) fn end_pass(&mut self, used_ids: &IdMap<Rect>) {
-        if self.focus_direction.is_cardinal()
-            && let Some(found_widget) = self.find_widget_in_direction(used_ids)
+        if self.focus_direction.is_cardinal() {
+            if let Some(found_widget) = self
+                .find_widget_in_roving_scope()
+                .or_else(|| self.find_widget_in_direction(used_ids))
+            {
+                self.focused_widget = Some(FocusWidget::new(found_widget));
+            }
+        } else if matches!(
+            self.focus_direction,
+            FocusDirection::Next | FocusDirection::Previous
+        ) && let Some(found_widget) = self.find_widget_in_tab_order()
+            && self.focused() != Some(found_widget)
         {
-            self.focused_widget = Some(FocusWidget::new(found_widget));
+            // We only know the tab order now, after all widgets were added.
+            // Give the focus in the next pass, so gained_focus works:
+            self.id_next_frame = Some(found_widget);
         }
 
+        self.restore_focus_of_closed_layers(used_ids);
+        self.remember_roving_focus();
+        self.remember_skipped_by_tab();
+
         if let Some(focused_widget) = self.focused_widget {
             // Allow calling `request_focus` one frame and not using it until next frame
             let recently_gained_focus = self.id_previous_frame != Some(focused_widget.id);
@@ -610,15 +664,10 @@ impl Focus {
         self.top_modal_layer = self.top_modal_layer_current_frame.take();
     }
 
-    pub(crate) fn had_focus_last_frame(&self, id: Id) -> bool {
-        self.id_previous_frame == Some(id)
-    }
-
     fn interested_in_focus(&mut self, id: Id) {
         if self.id_requested_by_accesskit == Some(id.accesskit_id()) {
             self.focused_widget = Some(FocusWidget::new(id));
             self.id_requested_by_accesskit = None;
-            self.give_to_next = false;
             self.reset_focus();
         }
 
@@ -627,35 +676,16 @@ impl Focus {
             .entry(id)
             .or_insert(Rect::EVERYTHING);
 
-        if self.give_to_next && !self.had_focus_last_frame(id) {
-            self.focused_widget = Some(FocusWidget::new(id));
-            self.give_to_next = false;
-        } else if self.focused() == Some(id) {
-            if self.focus_direction == FocusDirection::Next {
-                self.focused_widget = None;
-                self.give_to_next = true;
-                self.reset_focus();
-            } else if self.focus_direction == FocusDirection::Previous {
-                self.id_next_frame = self.last_interested; // frame-delay so gained_focus works
-                self.reset_focus();
-            }
-        } else if self.focus_direction == FocusDirection::Next
-            && self.focused_widget.is_none()
-            && !self.give_to_next
-        {
-            // nothing has focus and the user pressed tab - give focus to the first widgets that wants it:
-            self.focused_widget = Some(FocusWidget::new(id));
-            self.reset_focus();
-        } else if self.focus_direction == FocusDirection::Previous
-            && self.focused_widget.is_none()
-            && !self.give_to_next
-        {
-            // nothing has focus and the user pressed Shift+Tab - give focus to the last widgets that wants it:
-            self.focused_widget = self.last_interested.map(FocusWidget::new);
-            self.reset_focus();
-        }
+        // Tab and Shift+Tab are handled at the end of the frame, when we know all candidates:
+        self.candidates.push(focus_scope::FocusCandidate {
+            id,
+            scope: self.scope_stack.last().copied(),
+        });
+    }
 
-        self.last_interested = Some(id);
+    /// Will the focus change next frame?
+    pub(crate) fn has_pending_change(&self) -> bool {
+        self.id_next_frame.is_some()
     }
 
     fn set_modal_layer(&mut self, layer_id: LayerId) {
@@ -891,10 +921,67 @@ impl Memory {
     }
 
     /// Move keyboard focus in a specific direction.
+    ///
+    /// [`FocusDirection::Next`] and [`FocusDirection::Previous`] (i.e. Tab and Shift+Tab) are
+    /// resolved at the end of the pass, once the tab order of all widgets is known.
+    /// The new widget gets the focus at the start of the next pass, so that
+    /// [`crate::Response::gained_focus`] works, and egui requests that pass for you.
     pub fn move_focus(&mut self, direction: FocusDirection) {
         self.focus_mut().focus_direction = direction;
     }
 
+    /// Set the position of a widget in the Tab order, like `tabindex` in HTML.
+    ///
+    /// * Widgets with a positive tab index come first, in increasing order.
+    /// * Then come the widgets with tab index `0` (the default), in the order they were added.
+    /// * Widgets with a negative tab index are skipped by Tab and Shift+Tab,
+    ///   but can still get focus in other ways (e.g. by clicking them).
+    ///
+    /// This must be called every frame. See also [`crate::Response::with_tab_index`].
+    pub fn set_tab_index(&mut self, id: Id, tab_index: i32) {
+        self.focus_mut().tab_indices.insert(id, tab_index);
+    }
+
+    /// Widgets that are interested in focus are part of this [`FocusScope`] until [`Self::end_focus_scope`].
+    ///
+    /// You should use [`crate::Ui::focus_scope`] instead.
+    pub(crate) fn begin_focus_scope(&mut self, scope: FocusScope) {
+        let focus = self.focus_mut();
+        let parent = focus.scope_stack.last().copied();
+        focus.scope_stack.push(scope.id);
+        focus
+            .scopes
+            .insert(scope.id, focus_scope::ScopeState { scope, parent });
+    }
+
+    /// End the scope started with [`Self::begin_focus_scope`].
+    pub(crate) fn end_focus_scope(&mut self) {
+        self.focus_mut().scope_stack.pop();
+    }
+
+    /// Return the focus to where it was when this layer appeared, once the layer is no longer shown.
+    ///
+    /// Call this every frame the layer is shown.
+    /// This is done by [`crate::Popup`] and [`crate::Modal`], so that e.g. closing a menu
+    /// with Escape returns focus to the button that opened it.
+    pub fn restore_focus_when_closed(&mut self, layer_id: LayerId) {
+        let focus = self.focus_mut();
+        if let Some(restore) = focus
+            .restore_on_close
+            .iter_mut()
+            .find(|restore| restore.layer_id == layer_id)
+        {
+            restore.shown = true;
+        } else {
+            let focused_before = focus.focused();
+            focus.restore_on_close.push(focus_scope::FocusRestore {
+                layer_id,
+                focused_before,
+                shown: true,
+            });
+        }
+    }
+
     /// Returns true if
     /// - this layer is the top-most modal layer or above it
     /// - there is no modal layer
@@ -956,6 +1043,14 @@ impl Memory {
         self.focus()?.top_modal_layer()
     }
 
+    /// The widgets that Tab and Shift+Tab skipped over during the previous frame,
+    /// because of a negative tab index or a skipping [`FocusScope`].
+    pub(crate) fn skipped_by_tab(&self) -> IdSet {
+        self.focus()
+            .map(|focus| focus.skipped_by_tab.clone())
+            .unwrap_or_default()
+    }
+
     /// Stop editing the active [`TextEdit`](crate::TextEdit) (if any).
     #[inline(always)]
     pub fn stop_text_input(&mut self) {
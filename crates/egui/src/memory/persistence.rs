@@ -0,0 +1,204 @@
+//! Lenient restoring of a persisted [`Memory`].
+
+use std::sync::Arc;
+
+use ron::{extensions::Extensions, value::RawValue};
+
+use crate::util::DiscardedState;
+
+use super::Memory;
+
+impl Memory {
+    /// Deserialize a [`Memory`] from [RON](https://github.com/ron-rs/ron),
+    /// keeping as much of it as possible.
+    ///
+    /// Normally a [`Memory`] is all-or-nothing: if any part of it fails to deserialize
+    /// (e.g. because the format of [`crate::Style`] changed in a new egui version),
+    /// the whole thing is lost, including all window positions.
+    ///
+    /// This instead restores each section of the [`Memory`] (options, data, areas, …) separately,
+    /// and uses the default for the sections that fail.
+    /// The discarded sections are reported in [`crate::util::IdTypeMap::discarded`] of [`Memory::data`],
+    /// together with the discarded values of [`Memory::data`] itself.
+    ///
+    /// If the RON can't be parsed at all, everything is discarded.
+    pub fn from_ron_lenient(ron: &str) -> Self {
+        profiling::function_scope!();
+
+        let err = match ron::from_str::<Self>(ron) {
+            Ok(memory) => return memory,
+            Err(err) => err,
+        };
+        log::debug!("Failed to decode egui memory ({err}), restoring it section by section");
+
+        let (extensions, fields) = match parse_fields(ron) {
+            Ok(parsed) => parsed,
+            Err(parse_err) => {
+                log::warn!("egui: Discarding persisted memory: {parse_err}");
+                let mut memory = Self::default();
+                memory
+                    .data
+                    .extend_discarded([discard("Memory", ron, err.to_string())]);
+                return memory;
+            }
+        };
+        let options = ron::Options::default().with_default_extension(extensions);
+
+        // Check each section on its own, by restoring a `Memory` with only that section,
+        // and then restore all the good ones together.
+        // Sections we no longer persist are ignored by serde.
+        let mut kept = String::from("(");
+        let mut discarded = vec![];
+        for (name, value) in &fields {
+            let value = value.trim().get_ron();
+            let section = format!("({name}: {value})");
+            match options.from_str::<Self>(&section) {
+                Ok(_) => {
+                    kept += &section[1..section.len() - 1];
+                    kept.push(',');
+                }
+                Err(error) => {
+                    log::warn!("egui: Discarding persisted memory section {name:?}: {error}");
+                    discarded.push(discard(name, value, error.to_string()));
+                }
+            }
+        }
+        kept.push(')');
+
+        let mut memory = options.from_str::<Self>(&kept).unwrap_or_else(|err| {
+            log::warn!("egui: Discarding persisted memory: {err}");
+            Self::default()
+        });
+        memory.data.extend_discarded(discarded);
+        memory
+    }
+}
+
+/// Parse a RON struct like `Memory(a: 1, b: (c: [2, 3]))` into the (unparsed) values of its fields,
+/// together with the extensions enabled in its header (e.g. `#![enable(implicit_some)]`).
+fn parse_fields(
+    ron: &str,
+) -> ron::error::SpannedResult<(Extensions, Vec<(String, Box<RawValue>)>)> {
+    struct FieldsVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for FieldsVisitor {
+        type Value = Vec<(String, Box<RawValue>)>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str("a struct")
+        }
+
+        fn visit_map<A: serde::de::MapAccess<'de>>(
+            self,
+            mut map: A,
+        ) -> Result<Self::Value, A::Error> {
+            let mut fields = vec![];
+            while let Some(field) = map.next_entry()? {
+                fields.push(field);
+            }
+            Ok(fields)
+        }
+    }
+
+    let mut deserializer = ron::Deserializer::from_str(ron)?;
+    let extensions = deserializer.extensions();
+    let fields =
+        serde::Deserializer::deserialize_struct(&mut deserializer, "Memory", &[], FieldsVisitor)
+            .map_err(|err| deserializer.span_error(err))?;
+    deserializer
+        .end()
+        .map_err(|err| deserializer.span_error(err))?;
+    Ok((extensions, fields))
+}
+
+fn discard(name: &str, ron: &str, error: String) -> DiscardedState {
+    DiscardedState {
+        id: None,
+        name: name.to_owned(),
+        version: 0,
+        ron: Arc::from(ron),
+        error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fields() {
+        let ron = r#"
+            #![enable(implicit_some)]
+            // A comment
+            Memory(
+                a: 1, /* another comment */
+                b: (c: [2, 3], s: "x, y)"),
+            )
+        "#;
+        let (extensions, fields) = parse_fields(ron).unwrap();
+        assert_eq!(extensions, Extensions::IMPLICIT_SOME);
+        let fields: Vec<_> = fields
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.trim().get_ron()))
+            .collect();
+        assert_eq!(fields, [("a", "1"), ("b", r#"(c: [2, 3], s: "x, y)")"#)]);
+
+        assert!(parse_fields("(a: (1, 2)").is_err());
+        assert!(parse_fields("[1, 2]").is_err());
+    }
+
+    #[test]
+    fn test_from_ron_lenient() {
+        let mut memory = Memory::default();
+        memory.options.zoom_factor = 2.0;
+        memory
+            .data
+            .insert_persisted(crate::Id::new("a"), "hello".to_owned());
+        let ron = ron::to_string(&memory).unwrap();
+
+        let restored = Memory::from_ron_lenient(&ron);
+        assert_eq!(restored.options.zoom_factor, 2.0);
+        assert!(restored.data.discarded().is_empty());
+
+        // Break the options, and we should still keep the data:
+        let broken = ron.replacen("zoom_factor:2.0", "zoom_factor:\"big\"", 1);
+        assert!(ron::from_str::<Memory>(&broken).is_err());
+
+        let mut restored = Memory::from_ron_lenient(&broken);
+        assert_eq!(restored.options.zoom_factor, 1.0);
+        assert_eq!(
+            restored.data.get_persisted::<String>(crate::Id::new("a")),
+            Some("hello".to_owned())
+        );
+        let discarded = restored.data.discarded();
+        assert_eq!(discarded.len(), 1);
+        assert_eq!(discarded[0].name, "options");
+        assert_eq!(discarded[0].id, None);
+    }
+
+    #[test]
+    fn test_from_ron_lenient_with_header_and_comments() {
+        let mut memory = Memory::default();
+        memory.options.zoom_factor = 2.0;
+        let ron = ron::to_string(&memory).unwrap();
+        let ron = ron.replacen("zoom_factor:2.0", "zoom_factor:2.0 /* zoomed */", 1);
+        let ron = format!(
+            "#![enable(implicit_some)]\n// Saved by egui\n{}",
+            ron.replacen("areas:", "// Window positions\nareas:", 1)
+                .replacen(
+                    "to_global:",
+                    "to_global:\"broken\",\nno_longer_persisted:",
+                    1
+                )
+        );
+        assert!(ron.contains("/* zoomed */") && ron.contains("// Window positions"));
+        assert!(ron::from_str::<Memory>(&ron).is_err());
+
+        let restored = Memory::from_ron_lenient(&ron);
+        assert_eq!(restored.options.zoom_factor, 2.0);
+        let discarded = restored.data.discarded();
+        assert_eq!(discarded.len(), 1);
+        assert_eq!(discarded[0].name, "to_global");
+        assert_eq!(&*discarded[0].ron, "\"broken\"");
+    }
+}
@@ -43,7 +43,7 @@ impl Theme {
         if self == Self::Dark {
             if ui
                 .add(Button::new("☀").frame(false))
-                .on_hover_text("Switch to light mode")
+                .on_hover_text(ui.ctx().tr("egui-theme-switch-to-light"))
                 .clicked()
             {
                 return Some(Self::Light);
@@ -51,7 +51,7 @@ impl Theme {
         } else {
             if ui
                 .add(Button::new("🌙").frame(false))
-                .on_hover_text("Switch to dark mode")
+                .on_hover_text(ui.ctx().tr("egui-theme-switch-to-dark"))
                 .clicked()
             {
                 return Some(Self::Dark);
@@ -91,30 +91,31 @@ impl ThemePreference {
         ui.horizontal(|ui| {
             let system_theme = ui.input(|i| i.raw.system_theme);
 
-            ui.selectable_value(self, Self::System, "💻 System")
+            ui.selectable_value(self, Self::System, ui.ctx().tr("egui-theme-system"))
                 .on_hover_ui(|ui| {
-                    ui.label("Follow the system theme preference.");
+                    ui.label(ui.ctx().tr("egui-theme-follow-system"));
 
                     ui.add_space(4.0);
 
                     if let Some(system_theme) = system_theme {
-                        ui.label(format!(
-                            "The current system theme is: {}",
-                            match system_theme {
-                                Theme::Dark => "dark",
-                                Theme::Light => "light",
-                            }
+                        let theme = match system_theme {
+                            Theme::Dark => "dark",
+                            Theme::Light => "light",
+                        };
+                        ui.label(ui.ctx().tr_args(
+                            "egui-theme-current-system",
+                            &crate::i18n::MessageArgs::new().with("theme", theme),
                         ));
                     } else {
-                        ui.label("The system theme is unknown.");
+                        ui.label(ui.ctx().tr("egui-theme-system-unknown"));
                     }
                 });
 
-            ui.selectable_value(self, Self::Dark, "🌙 Dark")
-                .on_hover_text("Use the dark mode theme");
+            ui.selectable_value(self, Self::Dark, ui.ctx().tr("egui-theme-dark"))
+                .on_hover_text(ui.ctx().tr("egui-theme-use-dark"));
 
-            ui.selectable_value(self, Self::Light, "☀ Light")
-                .on_hover_text("Use the light mode theme");
+            ui.selectable_value(self, Self::Light, ui.ctx().tr("egui-theme-light"))
+                .on_hover_text(ui.ctx().tr("egui-theme-use-light"));
         });
     }
 }
@@ -224,6 +224,11 @@ pub struct PassState {
     /// as when swiping down on a touch-screen or track-pad with natural scrolling.
     pub scroll_delta: (Vec2, style::ScrollAnimation),
 
+    /// Candidate anchors of the current scroll area, see [`crate::Ui::scroll_anchor`].
+    ///
+    /// `None` if the current scroll area doesn't use [`crate::ScrollArea::scroll_anchoring`].
+    pub scroll_anchors: Option<Vec<(Id, Rect)>>,
+
     pub accesskit_state: Option<AccessKitPassState>,
 
     /// Highlight these widgets the next pass.
@@ -245,6 +250,7 @@ impl Default for PassState {
             used_by_panels: Rect::NAN,
             scroll_target: [None, None],
             scroll_delta: (Vec2::default(), style::ScrollAnimation::none()),
+            scroll_anchors: None,
             accesskit_state: None,
             highlight_next_pass: Default::default(),
 
@@ -267,6 +273,7 @@ impl PassState {
             used_by_panels,
             scroll_target,
             scroll_delta,
+            scroll_anchors,
             accesskit_state,
             highlight_next_pass,
 
@@ -283,6 +290,7 @@ impl PassState {
         *used_by_panels = Rect::NOTHING;
         *scroll_target = [None, None];
         *scroll_delta = Default::default();
+        *scroll_anchors = None;
 
         #[cfg(debug_assertions)]
         {
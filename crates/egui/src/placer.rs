@@ -37,6 +37,12 @@ impl Placer {
         self.grid.as_ref()
     }
 
+    pub(crate) fn set_grid_span(&mut self, col_span: usize, row_span: usize) {
+        if let Some(grid) = &mut self.grid {
+            grid.set_next_span(col_span, row_span);
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn is_grid(&self) -> bool {
         self.grid.is_some()
@@ -0,0 +1,292 @@
+//! Run futures and blocking closures, and show their output once it is ready.
+//!
+//! See [`Context::promise`], [`Context::promise_local`], [`Context::promise_blocking`]
+//! and [`Ui::async_value`].
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    task::{Poll, Wake, Waker},
+};
+
+use epaint::mutex::Mutex;
+
+use crate::{
+    Context, Id, IdMap, Plugin, Ui, ViewportId, context::WeakContext, util::id_type_map::RawKey,
+};
+
+pub(crate) type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// Stored in [`crate::Memory::data`], under the id of the promise.
+type SharedState<T> = Arc<Mutex<PromiseState<T>>>;
+
+struct PromiseState<T> {
+    /// `None` once it has finished.
+    future: Option<BoxFuture<T>>,
+    output: Option<T>,
+    waker: Arc<PromiseWaker>,
+}
+
+/// Repaints when a future can make progress, so that it is polled again.
+struct PromiseWaker {
+    /// Weak, so the futures don't keep the [`Context`] alive.
+    ctx: WeakContext,
+    viewport_id: ViewportId,
+    woken: AtomicBool,
+}
+
+impl Wake for PromiseWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::Release);
+        if let Some(ctx) = self.ctx.upgrade() {
+            ctx.request_repaint_of(self.viewport_id);
+        }
+    }
+}
+
+/// Cancels the promises that weren't asked for during a pass.
+///
+/// This is a built-in plugin in egui, automatically registered during [`Context`] creation.
+#[derive(Default)]
+pub(crate) struct Promises {
+    promises: IdMap<PromiseEntry>,
+}
+
+struct PromiseEntry {
+    key: RawKey,
+    viewport_id: ViewportId,
+    used_this_pass: bool,
+}
+
+impl Plugin for Promises {
+    fn debug_name(&self) -> &'static str {
+        "Promises"
+    }
+
+    fn on_end_pass(&mut self, ui: &mut Ui) {
+        let viewport_id = ui.ctx().viewport_id();
+        let mut cancelled = vec![];
+        self.promises.retain(|_, entry| {
+            if entry.viewport_id != viewport_id {
+                return true;
+            }
+            let keep = std::mem::take(&mut entry.used_this_pass);
+            if !keep {
+                cancelled.push(entry.key);
+            }
+            keep
+        });
+
+        if !cancelled.is_empty() {
+            let removed: Vec<_> = ui.data_mut(|data| {
+                cancelled
+                    .into_iter()
+                    .filter_map(|key| data.remove_temp_raw(key))
+                    .collect()
+            });
+            // Drop the futures outside of the lock, in case they use the context:
+            drop(removed);
+        }
+    }
+}
+
+/// See [`Context::promise`].
+pub(crate) fn poll<T: Clone + Send + Sync + 'static>(
+    ctx: &Context,
+    id: Id,
+    start: impl FnOnce() -> BoxFuture<T>,
+) -> Option<T> {
+    let viewport_id = ctx.viewport_id();
+    let key = RawKey::new::<SharedState<T>>(id);
+    let replaced = ctx
+        .with_plugin(|promises: &mut Promises| {
+            promises.promises.insert(
+                id,
+                PromiseEntry {
+                    key,
+                    viewport_id,
+                    used_this_pass: true,
+                },
+            )
+        })
+        .flatten();
+    if let Some(replaced) = replaced
+        && replaced.key != key
+    {
+        // The id was used for a promise of another type, which is now cancelled:
+        let removed = ctx.data_mut(|data| data.remove_temp_raw(replaced.key));
+        drop(removed);
+    }
+
+    let state = ctx.data(|data| data.get_temp::<SharedState<T>>(id));
+    let state = state.unwrap_or_else(|| {
+        let state = Arc::new(Mutex::new(PromiseState {
+            future: Some(start()),
+            output: None,
+            waker: Arc::new(PromiseWaker {
+                ctx: ctx.downgrade(),
+                viewport_id,
+                woken: AtomicBool::new(true),
+            }),
+        }));
+        ctx.data_mut(|data| data.insert_temp(id, Arc::clone(&state)));
+        state
+    });
+
+    let mut state = state.lock();
+    let PromiseState {
+        future,
+        output,
+        waker,
+    } = &mut *state;
+    if let Some(running) = future
+        && waker.woken.swap(false, Ordering::AcqRel)
+    {
+        let std_waker = Waker::from(Arc::clone(waker));
+        let mut cx = std::task::Context::from_waker(&std_waker);
+        if let Poll::Ready(value) = running.as_mut().poll(&mut cx) {
+            *output = Some(value);
+            *future = None;
+        }
+    }
+    output.clone()
+}
+
+/// See [`Context::forget_promise`].
+pub(crate) fn forget(ctx: &Context, id: Id) {
+    let entry = ctx
+        .with_plugin(|promises: &mut Promises| promises.promises.remove(&id))
+        .flatten();
+    if let Some(entry) = entry {
+        let removed = ctx.data_mut(|data| data.remove_temp_raw(entry.key));
+        drop(removed);
+    }
+}
+
+thread_local! {
+    /// The futures of [`Context::promise_local`], which can't be stored in [`crate::Memory`].
+    static LOCAL_FUTURES: RefCell<ahash::HashMap<u64, Box<dyn Any>>> = Default::default();
+}
+
+/// Wrap a future that isn't [`Send`], so that it can be stored like any other promise.
+///
+/// The future itself stays in a thread-local, so it can only be polled on the current thread.
+/// Dropping the wrapper drops the future.
+pub(crate) fn local<T: 'static>(future: impl Future<Output = T> + 'static) -> BoxFuture<T> {
+    static NEXT_KEY: AtomicU64 = AtomicU64::new(0);
+    let key = NEXT_KEY.fetch_add(1, Ordering::Relaxed);
+    let future: Pin<Box<dyn Future<Output = T>>> = Box::pin(future);
+    LOCAL_FUTURES.with(|futures| futures.borrow_mut().insert(key, Box::new(future)));
+    Box::pin(LocalFuture::<T> {
+        key,
+        _output: PhantomData,
+    })
+}
+
+struct LocalFuture<T> {
+    key: u64,
+    _output: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static> Future for LocalFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<T> {
+        // Take it out while polling, in case it starts another local promise:
+        let Some(mut future) = LOCAL_FUTURES.with(|futures| futures.borrow_mut().remove(&self.key))
+        else {
+            log::warn!("A local promise can only be polled on the thread that started it");
+            return Poll::Pending;
+        };
+        let Some(running) = future.downcast_mut::<Pin<Box<dyn Future<Output = T>>>>() else {
+            log::error!("A local promise has the wrong output type");
+            return Poll::Pending;
+        };
+        let poll = running.as_mut().poll(cx);
+        if poll.is_pending() {
+            LOCAL_FUTURES.with(|futures| futures.borrow_mut().insert(self.key, future));
+        }
+        poll
+    }
+}
+
+impl<T> Drop for LocalFuture<T> {
+    fn drop(&mut self) {
+        // The thread-local may already be gone if the thread is exiting.
+        let removed = LOCAL_FUTURES
+            .try_with(|futures| futures.borrow_mut().remove(&self.key))
+            .ok()
+            .flatten();
+        // Drop the future outside of the borrow, in case it uses other local promises:
+        drop(removed);
+    }
+}
+
+/// Run `compute` on a new thread, and get its output as a future.
+///
+/// Resolves to an error if the thread couldn't be spawned, or if `compute` panicked.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn spawn_thread<T: Send + 'static>(
+    compute: impl FnOnce() -> T + Send + 'static,
+) -> BoxFuture<Result<T, String>> {
+    struct Shared<T> {
+        output: Option<Result<T, String>>,
+        waker: Option<Waker>,
+    }
+
+    /// Wakes the future when the thread is done, even if `compute` panicked.
+    struct Finish<T>(Arc<Mutex<Shared<T>>>);
+
+    impl<T> Drop for Finish<T> {
+        fn drop(&mut self) {
+            let waker = {
+                let mut shared = self.0.lock();
+                if shared.output.is_none() {
+                    shared.output = Some(Err("The promise thread panicked".to_owned()));
+                }
+                shared.waker.take()
+            };
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+
+    let shared = Arc::new(Mutex::new(Shared {
+        output: None,
+        waker: None,
+    }));
+
+    let finish = Finish(Arc::clone(&shared));
+    let spawned = std::thread::Builder::new()
+        .name("egui_promise".to_owned())
+        .spawn(move || {
+            let output = compute();
+            finish.0.lock().output = Some(Ok(output));
+        });
+    if let Err(err) = spawned {
+        log::error!("Failed to spawn promise thread: {err}");
+        shared.lock().output = Some(Err(format!("Failed to spawn promise thread: {err}")));
+    }
+
+    Box::pin(std::future::poll_fn(move |cx| {
+        let mut shared = shared.lock();
+        if let Some(output) = shared.output.take() {
+            Poll::Ready(output)
+        } else {
+            shared.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }))
+}
@@ -1,8 +1,8 @@
 use std::{any::Any, sync::Arc};
 
 use crate::{
-    Context, CursorIcon, Id, LayerId, PointerButton, Popup, PopupKind, Sense, Tooltip, Ui,
-    WidgetRect, WidgetText,
+    Context, CursorIcon, Direction, FileDrop, FileFilter, Id, LayerId, PointerButton, Popup,
+    PopupKind, Sense, Tooltip, Ui, WidgetRect, WidgetText,
     emath::{Align, Pos2, Rect, Vec2},
     pass_state,
 };
@@ -147,6 +147,12 @@ bitflags::bitflags! {
 
         /// Should this container be closed?
         const CLOSE = 1<<12;
+
+        /// The pointer was held still on this widget for a while.
+        const LONG_PRESSED = 1<<13;
+
+        /// The widget is being dragged by the second tap of a double-tap.
+        const DOUBLE_TAP_DRAGGED = 1<<14;
     }
 }
 
@@ -213,6 +219,19 @@ impl Response {
         self.flags.contains(Flags::LONG_TOUCHED)
     }
 
+    /// Was the pointer held still on this widget for
+    /// [`crate::style::Interaction::long_press_duration`] seconds?
+    ///
+    /// Unlike [`Self::long_touched`], this works for both touch screens and mice.
+    /// Releasing the pointer after a long-press still registers as a click,
+    /// unless [`crate::style::Interaction::long_press_cancels_click`] is set.
+    ///
+    /// Returns `true` only on one frame.
+    #[inline]
+    pub fn long_pressed(&self) -> bool {
+        self.flags.contains(Flags::LONG_PRESSED)
+    }
+
     /// Returns true if this widget was clicked this frame by the middle mouse button.
     #[inline]
     pub fn middle_clicked(&self) -> bool {
@@ -364,6 +383,24 @@ impl Response {
         self.ctx.memory_mut(|mem| mem.surrender_focus(self.id));
     }
 
+    /// Set the position of this widget in the Tab order.
+    ///
+    /// See [`crate::Memory::set_tab_index`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.text_edit_singleline(&mut String::new()).with_tab_index(2);
+    /// ui.button("Focused first").with_tab_index(1);
+    /// ui.button("Never focused by Tab").with_tab_index(-1);
+    /// # });
+    /// ```
+    #[inline]
+    pub fn with_tab_index(self, tab_index: i32) -> Self {
+        self.ctx
+            .memory_mut(|mem| mem.set_tab_index(self.id, tab_index));
+        self
+    }
+
     /// Did a drag on this widget begin this frame?
     ///
     /// This is only true if the widget sense drags.
@@ -465,6 +502,65 @@ impl Response {
         }
     }
 
+    /// Is this widget being dragged by the second tap of a double-tap?
+    ///
+    /// That is: the user tapped (or clicked) the widget,
+    /// and then quickly pressed it again and started dragging.
+    /// This is commonly used for one-finger zooming on touch screens.
+    #[inline]
+    pub fn double_tap_dragged(&self) -> bool {
+        self.flags.contains(Flags::DOUBLE_TAP_DRAGGED)
+    }
+
+    /// Was this widget swiped in the given direction this frame?
+    ///
+    /// A swipe is a quick drag that is released while still moving.
+    /// The thresholds are set in [`crate::style::Interaction`].
+    ///
+    /// The widget must sense drags.
+    #[inline]
+    pub fn swiped(&self, direction: Direction) -> bool {
+        self.swipe_direction() == Some(direction)
+    }
+
+    /// If this widget was swiped this frame, in which direction?
+    ///
+    /// See [`Self::swiped`].
+    pub fn swipe_direction(&self) -> Option<Direction> {
+        if !self.drag_stopped() {
+            return None;
+        }
+        self.ctx.viewport(|viewport| {
+            viewport
+                .interact_widgets
+                .swiped
+                .filter(|(id, _)| *id == self.id)
+                .map(|(_, direction)| direction)
+        })
+    }
+
+    /// If the drag of this widget was let go this frame while still moving,
+    /// what was the velocity, in points per second?
+    ///
+    /// Use this to continue the motion after the drag, like kinetic scrolling.
+    /// The velocity must be at least [`crate::style::Interaction::fling_min_speed`].
+    pub fn fling_velocity(&self) -> Option<Vec2> {
+        if !self.drag_stopped() {
+            return None;
+        }
+        let mut velocity = self.ctx.viewport(|viewport| {
+            viewport
+                .interact_widgets
+                .flung
+                .filter(|(id, _)| *id == self.id)
+                .map(|(_, velocity)| velocity)
+        })?;
+        if let Some(from_global) = self.ctx.layer_transform_from_global(self.layer_id) {
+            velocity *= from_global.scaling;
+        }
+        Some(velocity)
+    }
+
     /// If the user started dragging this widget this frame, store the payload for drag-and-drop.
     #[doc(alias = "drag and drop")]
     pub fn dnd_set_drag_payload<Payload: Any + Send + Sync>(&self, payload: Payload) {
@@ -510,6 +606,75 @@ impl Response {
         }
     }
 
+    /// Files dragged from outside the app over this widget, or dropped on it this frame.
+    ///
+    /// Only returns something if [`Self::contains_pointer`] is true,
+    /// and files are being hovered or dropped.
+    /// Some integrations (e.g. winit) don't report the pointer position while files are dragged
+    /// over the window, in which case the last known pointer position is used instead.
+    ///
+    /// The dropped files that the `filter` accepts are consumed, so that no other widget
+    /// receives them. Use [`FileDrop::can_accept`] and [`FileDrop::is_rejected`] to give feedback
+    /// while the files are hovered, or use [`Ui::file_drop_zone`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.label("Drop images here");
+    /// if let Some(drop) = response.file_drop_target(&egui::FileFilter::any().mime_type("image/*")) {
+    ///     for file in &drop.dropped {
+    ///         println!("Dropped {:?}", file.path);
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    #[doc(alias = "drag and drop")]
+    pub fn file_drop_target(&self, filter: &FileFilter) -> Option<FileDrop> {
+        let (has_files, pointer_is_gone) = self.ctx.input(|i| {
+            (
+                !i.raw.hovered_files.is_empty() || !i.raw.dropped_files.is_empty(),
+                !i.pointer.has_pointer(),
+            )
+        });
+        let is_over =
+            self.contains_pointer() || (pointer_is_gone && self.contains_last_known_pointer_pos());
+        if !has_files || !is_over {
+            return None;
+        }
+
+        self.ctx.input_mut(|i| {
+            let mut drop = FileDrop::default();
+            for file in &i.raw.hovered_files {
+                if filter.accepts_hovered(file) {
+                    drop.hovered.push(file.clone());
+                } else {
+                    drop.rejected += 1;
+                }
+            }
+
+            let (accepted, rejected) = std::mem::take(&mut i.raw.dropped_files)
+                .into_iter()
+                .partition(|file| filter.accepts_dropped(file));
+            drop.dropped = accepted;
+            drop.rejected += rejected.len();
+            i.raw.dropped_files = rejected;
+
+            Some(drop)
+        })
+    }
+
+    /// Was the pointer over this widget when it was last seen, see [`Self::file_drop_target`]?
+    fn contains_last_known_pointer_pos(&self) -> bool {
+        let Some(pos) = self.ctx.input(|i| i.pointer.last_known_pos()) else {
+            return false;
+        };
+        let to_global = self
+            .ctx
+            .layer_transform_to_global(self.layer_id)
+            .unwrap_or_default();
+        (to_global * self.interact_rect).contains(pos)
+            && self.ctx.layer_id_at(pos) == Some(self.layer_id)
+    }
+
     /// Where the pointer (mouse/touch) were when this widget was clicked or dragged.
     ///
     /// `None` if the widget is not being interacted with.
@@ -743,6 +908,42 @@ impl Response {
         self
     }
 
+    /// Only let the pointer hit this widget inside of the given shape,
+    /// instead of anywhere in its rectangle.
+    ///
+    /// This is useful for round knobs, node-editor wires, and other widgets
+    /// that would otherwise steal clicks from their neighbors.
+    /// The shape is in the same coordinates as [`Self::rect`],
+    /// and is clipped to [`Self::interact_rect`].
+    ///
+    /// Like all hit-testing, this takes effect from the next pass.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let (rect, response) = ui.allocate_exact_size(egui::vec2(32.0, 32.0), egui::Sense::drag());
+    /// let response = response.with_hit_shape(egui::HitShape::circle(rect.center(), 16.0));
+    /// ui.painter().circle_filled(rect.center(), 16.0, ui.visuals().widgets.style(&response).bg_fill);
+    /// # });
+    /// ```
+    pub fn with_hit_shape(self, shape: crate::HitShape) -> Self {
+        self.ctx.pass_state_mut(|pass_state| {
+            pass_state.widgets.set_hit_shape(self.id, shape);
+        });
+        self
+    }
+
+    /// The shape set with [`Self::with_hit_shape`], if any.
+    pub fn hit_shape(&self) -> Option<crate::HitShape> {
+        self.ctx.viewport(|viewport| {
+            viewport
+                .this_pass
+                .widgets
+                .hit_shape(self.id)
+                .or_else(|| viewport.prev_pass.widgets.hit_shape(self.id))
+                .cloned()
+        })
+    }
+
     /// Sense more interactions (e.g. sense clicks on a [`Response`] returned from a label).
     ///
     /// The interaction will occur on the same plane as the original widget,
@@ -13,25 +13,88 @@ use crate::{
     WidgetText,
     ecolor::Color32,
     emath::{Rangef, Rect, Vec2, pos2, vec2},
+    i18n::NumberSymbols,
     reset_button_with,
 };
 
-/// How to format numbers in e.g. a [`crate::DragValue`].
+/// How to format and parse numbers in e.g. a [`crate::DragValue`].
 #[derive(Clone)]
-pub struct NumberFormatter(
-    Arc<dyn 'static + Sync + Send + Fn(f64, RangeInclusive<usize>) -> String>,
-);
+pub struct NumberFormatter {
+    format: Arc<dyn 'static + Sync + Send + Fn(f64, RangeInclusive<usize>) -> String>,
+    parse: Arc<dyn 'static + Sync + Send + Fn(&str) -> Option<f64>>,
+
+    /// Set by [`Self::localized`].
+    symbols: Option<NumberSymbols>,
+}
+
+static DEFAULT_NUMBER_FORMATTER: std::sync::LazyLock<NumberFormatter> =
+    std::sync::LazyLock::new(|| NumberFormatter::new(emath::format_with_decimals_in_range));
+
+impl Default for NumberFormatter {
+    /// Formats numbers like Rust does, e.g. `1234.5`.
+    fn default() -> Self {
+        DEFAULT_NUMBER_FORMATTER.clone()
+    }
+}
 
 impl NumberFormatter {
     /// The first argument is the number to be formatted.
     /// The second argument is the range of the number of decimals to show.
     ///
     /// See [`Self::format`] for the meaning of the `decimals` argument.
+    ///
+    /// Numbers are parsed with [`crate::i18n::NumberSymbols::PLAIN`], see [`Self::with_parser`].
     #[inline]
     pub fn new(
         formatter: impl 'static + Sync + Send + Fn(f64, RangeInclusive<usize>) -> String,
     ) -> Self {
-        Self(Arc::new(formatter))
+        Self {
+            format: Arc::new(formatter),
+            parse: Arc::new(|text| NumberSymbols::PLAIN.parse(text)),
+            symbols: None,
+        }
+    }
+
+    /// Set how text entered by the user is parsed, to match [`Self::format`].
+    #[inline]
+    pub fn with_parser(
+        mut self,
+        parser: impl 'static + Sync + Send + Fn(&str) -> Option<f64>,
+    ) -> Self {
+        self.parse = Arc::new(parser);
+        self.symbols = None;
+        self
+    }
+
+    /// Format and parse numbers the way a language writes them, e.g. `1.234,5` in German.
+    ///
+    /// See also [`Self::for_language`].
+    pub fn localized(symbols: NumberSymbols) -> Self {
+        let mut formatter = Self::new(move |value, decimals| symbols.format(value, decimals))
+            .with_parser(move |text| symbols.parse(text));
+        formatter.symbols = Some(symbols);
+        formatter
+    }
+
+    /// The formatter [`crate::Context::set_language`] installs for a language, e.g. `"de"`.
+    ///
+    /// This is the [default](Self::default) for English, and [`Self::localized`] otherwise.
+    pub fn for_language(language: &str) -> Self {
+        let is_english = language
+            .split(['-', '_'])
+            .next()
+            .is_some_and(|primary| primary.eq_ignore_ascii_case("en"));
+        if is_english {
+            Self::default()
+        } else {
+            Self::localized(NumberSymbols::for_language(language))
+        }
+    }
+
+    /// Is this the default or a [localized](Self::localized) formatter,
+    /// which [`crate::Context::set_language`] may replace?
+    pub(crate) fn follows_language(&self) -> bool {
+        self.symbols.is_some() || *self == Self::default()
     }
 
     /// Format the given number with the given number of decimals.
@@ -44,7 +107,13 @@ impl NumberFormatter {
     /// more decimals will be shown, up to the given max.
     #[inline]
     pub fn format(&self, value: f64, decimals: RangeInclusive<usize>) -> String {
-        (self.0)(value, decimals)
+        (self.format)(value, decimals)
+    }
+
+    /// Parse a number entered by the user.
+    #[inline]
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        (self.parse)(text)
     }
 }
 
@@ -57,7 +126,7 @@ impl std::fmt::Debug for NumberFormatter {
 impl PartialEq for NumberFormatter {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.0, &other.0)
+        Arc::ptr_eq(&self.format, &other.format) && Arc::ptr_eq(&self.parse, &other.parse)
     }
 }
 
@@ -950,6 +1019,35 @@ pub struct Interaction {
     /// The default is `true`, but text selection can be slightly glitchy,
     /// so you may want to disable it.
     pub multi_widget_text_select: bool,
+
+    /// Hold the pointer still on a widget for this many seconds to long-press it.
+    ///
+    /// See [`crate::Response::long_pressed`].
+    pub long_press_duration: f32,
+
+    /// Should releasing the pointer after a long-press not register as a click?
+    ///
+    /// Off by default, so that holding e.g. a button for a while still clicks it.
+    /// Turn it on if long-pressing does something else, e.g. opens a context menu.
+    pub long_press_cancels_click: bool,
+
+    /// A drag must move at least this many points to count as a swipe.
+    ///
+    /// See [`crate::Response::swiped`].
+    pub swipe_min_distance: f32,
+
+    /// A drag must be released at at least this many points per second to count as a swipe.
+    pub swipe_min_speed: f32,
+
+    /// A drag must be released at at least this many points per second to count as a fling.
+    ///
+    /// See [`crate::Response::fling_velocity`].
+    pub fling_min_speed: f32,
+
+    /// How quickly a fling slows down, in points per second squared.
+    ///
+    /// This is used for kinetic scrolling in [`crate::ScrollArea`].
+    pub fling_friction: f32,
 }
 
 /// Look and feel of the text cursor.
@@ -1417,7 +1515,7 @@ impl Default for Style {
             override_text_valign: Some(Align::Center),
             text_styles: default_text_styles(),
             drag_value_text_style: TextStyle::Button,
-            number_formatter: NumberFormatter(Arc::new(emath::format_with_decimals_in_range)),
+            number_formatter: NumberFormatter::default(),
             wrap: None,
             wrap_mode: None,
             spacing: Spacing::default(),
@@ -1473,6 +1571,12 @@ impl Default for Interaction {
             tooltip_grace_time: 0.2,
             selectable_labels: true,
             multi_widget_text_select: true,
+            long_press_duration: 0.5,
+            long_press_cancels_click: false,
+            swipe_min_distance: 30.0,
+            swipe_min_speed: 300.0,
+            fling_min_speed: 20.0,
+            fling_friction: 1000.0,
         }
     }
 }
@@ -2024,6 +2128,12 @@ impl Interaction {
             tooltip_grace_time,
             selectable_labels,
             multi_widget_text_select,
+            long_press_duration,
+            long_press_cancels_click,
+            swipe_min_distance,
+            swipe_min_speed,
+            fling_min_speed,
+            fling_friction,
         } = self;
 
         ui.spacing_mut().item_spacing = vec2(12.0, 8.0);
@@ -2066,6 +2176,51 @@ impl Interaction {
                         .suffix(" s"),
                 );
                 ui.end_row();
+
+                ui.label("Long press duration").on_hover_text(
+                    "Hold the pointer still on a widget for this long to long-press it",
+                );
+                ui.add(
+                    DragValue::new(long_press_duration)
+                        .range(0.0..=5.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Swipe min distance")
+                    .on_hover_text("A drag must move at least this far to count as a swipe");
+                ui.add(DragValue::new(swipe_min_distance).range(0.0..=500.0));
+                ui.end_row();
+
+                ui.label("Swipe min speed").on_hover_text(
+                    "A drag must be released at at least this speed to count as a swipe",
+                );
+                ui.add(
+                    DragValue::new(swipe_min_speed)
+                        .range(0.0..=5000.0)
+                        .suffix(" pt/s"),
+                );
+                ui.end_row();
+
+                ui.label("Fling min speed").on_hover_text(
+                    "A drag must be released at at least this speed to count as a fling",
+                );
+                ui.add(
+                    DragValue::new(fling_min_speed)
+                        .range(0.0..=5000.0)
+                        .suffix(" pt/s"),
+                );
+                ui.end_row();
+
+                ui.label("Fling friction")
+                    .on_hover_text("How quickly kinetic scrolling slows down");
+                ui.add(
+                    DragValue::new(fling_friction)
+                        .range(0.0..=10000.0)
+                        .suffix(" pt/s²"),
+                );
+                ui.end_row();
             });
 
         ui.checkbox(
@@ -2073,6 +2228,8 @@ impl Interaction {
             "Only show tooltips if mouse is still",
         );
 
+        ui.checkbox(long_press_cancels_click, "Long-press cancels click");
+
         ui.horizontal(|ui| {
             ui.checkbox(selectable_labels, "Selectable text in labels");
             if *selectable_labels {
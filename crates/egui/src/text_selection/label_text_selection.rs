@@ -1,15 +1,15 @@
-use std::sync::Arc;
+use std::{fmt::Write as _, sync::Arc};
 
 use emath::TSTransform;
 
 use crate::{
-    Context, CursorIcon, Event, Galley, Id, LayerId, Plugin, Pos2, Rect, Response, Ui,
-    layers::ShapeIdx, text::CCursor, text_selection::CCursorRange,
+    Color32, Context, CursorIcon, Event, FontFamily, Galley, Id, LayerId, Plugin, Pos2, Rect,
+    Response, Ui, layers::ShapeIdx, text::CCursor, text_selection::CCursorRange,
 };
 
 use super::{
     TextCursorState,
-    text_cursor_state::cursor_rect,
+    text_cursor_state::{byte_index_from_char_index, cursor_rect},
     visuals::{RowVertexIndices, paint_text_selection},
 };
 
@@ -103,6 +103,9 @@ pub struct LabelSelectionState {
 
     /// Accumulated text to copy.
     text_to_copy: String,
+
+    /// The same as [`Self::text_to_copy`], but as HTML with the text formatting.
+    html_to_copy: String,
     last_copied_galley_rect: Option<Rect>,
 
     /// Painted selections this frame.
@@ -122,6 +125,7 @@ impl Default for LabelSelectionState {
             has_reached_primary: Default::default(),
             has_reached_secondary: Default::default(),
             text_to_copy: Default::default(),
+            html_to_copy: Default::default(),
             last_copied_galley_rect: Default::default(),
             painted_selections: Default::default(),
         }
@@ -146,6 +150,7 @@ impl Plugin for LabelSelectionState {
         self.has_reached_primary = false;
         self.has_reached_secondary = false;
         self.text_to_copy.clear();
+        self.html_to_copy.clear();
         self.last_copied_galley_rect = None;
         self.painted_selections.clear();
     }
@@ -208,8 +213,9 @@ impl Plugin for LabelSelectionState {
         }
 
         let text_to_copy = std::mem::take(&mut self.text_to_copy);
+        let html_to_copy = std::mem::take(&mut self.html_to_copy);
         if !text_to_copy.is_empty() {
-            ui.copy_text(text_to_copy);
+            ui.copy_rich(text_to_copy, html_to_copy);
         }
     }
 }
@@ -228,15 +234,14 @@ impl LabelSelectionState {
         if new_text.is_empty() {
             return;
         }
+        let new_html = selected_html(galley, cursor_range);
 
-        if self.text_to_copy.is_empty() {
-            self.text_to_copy = new_text;
-            self.last_copied_galley_rect = Some(new_galley_rect);
-            return;
-        }
-
-        let Some(last_copied_galley_rect) = self.last_copied_galley_rect else {
+        let last_copied_galley_rect = self
+            .last_copied_galley_rect
+            .filter(|_| !self.text_to_copy.is_empty());
+        let Some(last_copied_galley_rect) = last_copied_galley_rect else {
             self.text_to_copy = new_text;
+            self.html_to_copy = new_html;
             self.last_copied_galley_rect = Some(new_galley_rect);
             return;
         };
@@ -246,9 +251,11 @@ impl LabelSelectionState {
 
         if last_copied_galley_rect.bottom() <= new_galley_rect.top() {
             self.text_to_copy.push('\n');
+            self.html_to_copy.push_str("<br>\n");
             let vertical_distance = new_galley_rect.top() - last_copied_galley_rect.bottom();
             if estimate_row_height(galley) * 0.5 < vertical_distance {
                 self.text_to_copy.push('\n');
+                self.html_to_copy.push_str("<br>\n");
             }
         } else {
             let existing_ends_with_space =
@@ -262,10 +269,12 @@ impl LabelSelectionState {
             if existing_ends_with_space == Some(false) && !new_text_starts_with_space_or_punctuation
             {
                 self.text_to_copy.push(' ');
+                self.html_to_copy.push(' ');
             }
         }
 
         self.text_to_copy.push_str(&new_text);
+        self.html_to_copy.push_str(&new_html);
         self.last_copied_galley_rect = Some(new_galley_rect);
     }
 
@@ -686,6 +695,68 @@ fn selected_text(galley: &Galley, cursor_range: &CCursorRange) -> String {
     }
 }
 
+/// The same as [`selected_text`], but as HTML with the text formatting.
+fn selected_html(galley: &Galley, cursor_range: &CCursorRange) -> String {
+    let text = galley.text();
+    let everything_is_selected = cursor_range.contains(CCursorRange::select_all(galley));
+    let selected = if cursor_range.is_empty() || everything_is_selected {
+        0..text.len()
+    } else {
+        let [min, max] = cursor_range.sorted_cursors();
+        byte_index_from_char_index(text, min.index)..byte_index_from_char_index(text, max.index)
+    };
+
+    let mut html = String::new();
+    for section in &galley.job.sections {
+        let start = section.byte_range.start.max(selected.start);
+        let end = section.byte_range.end.min(selected.end);
+        if end <= start {
+            continue;
+        }
+
+        let format = &section.format;
+        let mut style = format!("font-size:{}px", format.font_id.size);
+        if format.color != Color32::PLACEHOLDER {
+            // Placeholder is the default text color, which we leave to the app we paste into.
+            _ = write!(style, ";color:{}", format.color.to_hex());
+        }
+        if format.font_id.family == FontFamily::Monospace {
+            style.push_str(";font-family:monospace");
+        }
+        if format.italics {
+            style.push_str(";font-style:italic");
+        }
+        if format.background != Color32::TRANSPARENT {
+            _ = write!(style, ";background-color:{}", format.background.to_hex());
+        }
+        match (format.underline.is_empty(), format.strikethrough.is_empty()) {
+            (false, false) => style.push_str(";text-decoration:underline line-through"),
+            (false, true) => style.push_str(";text-decoration:underline"),
+            (true, false) => style.push_str(";text-decoration:line-through"),
+            (true, true) => {}
+        }
+
+        _ = write!(html, "<span style=\"{style}\">");
+        html_escape(&mut html, &text[start..end]);
+        html.push_str("</span>");
+    }
+    html
+}
+
+/// Escape the text so it can be put in HTML, and keep its line breaks.
+fn html_escape(html: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\n' => html.push_str("<br>\n"),
+            c => html.push(c),
+        }
+    }
+}
+
 fn estimate_row_height(galley: &Galley) -> f32 {
     if let Some(placed_row) = galley.rows.first() {
         placed_row.height()
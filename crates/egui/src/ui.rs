@@ -89,6 +89,11 @@ pub struct Ui {
     /// This is an optimization, so we don't call [`Ui::remember_min_rect`] multiple times at the
     /// end of a [`Ui::scope`].
     min_rect_already_remembered: bool,
+
+    /// Is this inside a [`crate::ScrollArea`] with [`crate::ScrollArea::scroll_anchoring`]?
+    ///
+    /// If not, [`Ui::scroll_anchor`] can skip locking the pass state.
+    pub(crate) scroll_anchoring: bool,
 }
 
 /// Allow using [`Ui`] like a [`Context`].
@@ -161,6 +166,7 @@ impl Ui {
             stack: Arc::new(ui_stack),
             sense,
             min_rect_already_remembered: false,
+            scroll_anchoring: false,
         };
 
         if let Some(accessibility_parent) = accessibility_parent {
@@ -324,6 +330,7 @@ impl Ui {
             stack: Arc::new(ui_stack),
             sense,
             min_rect_already_remembered: false,
+            scroll_anchoring: self.scroll_anchoring,
         };
 
         if disabled {
@@ -1679,6 +1686,26 @@ impl Ui {
             state.scroll_delta.1 = animation;
         });
     }
+
+    /// Register where the item `id` is, as a candidate anchor for the
+    /// [`crate::ScrollArea::scroll_anchoring`] of the parent [`crate::ScrollArea`].
+    ///
+    /// Scopes with an id salt, like [`Self::push_id`], are registered automatically.
+    /// The `rect` may be outside the visible area, so content that only shows its visible items
+    /// can register the current anchor (see [`crate::scroll_area::State::scroll_anchor`]) even
+    /// if it isn't shown.
+    ///
+    /// Does nothing if the parent [`crate::ScrollArea`] doesn't use scroll anchoring.
+    pub fn scroll_anchor(&self, id: Id, rect: Rect) {
+        if !self.scroll_anchoring {
+            return;
+        }
+        self.ctx().pass_state_mut(|state| {
+            if let Some(scroll_anchors) = &mut state.scroll_anchors {
+                scroll_anchors.push((id, rect));
+            }
+        });
+    }
 }
 
 /// # Adding widgets
@@ -2178,6 +2205,47 @@ impl Ui {
         Spinner::new().ui(self)
     }
 
+    /// Run a future, showing a spinner while it runs and the error if it fails.
+    ///
+    /// Once it has succeeded, `add_contents` is called with its output every frame.
+    /// The future is cancelled when this stops being called, see [`Context::promise`].
+    ///
+    /// The future doesn't need to be [`Send`], see [`Context::promise_local`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.async_value(
+    ///     "greeting",
+    ///     || async { Ok::<_, String>("Hello".to_owned()) },
+    ///     |ui, greeting| ui.label(greeting),
+    /// );
+    /// # });
+    /// ```
+    pub fn async_value<T, E, F, R>(
+        &mut self,
+        id_salt: impl Hash,
+        start: impl FnOnce() -> F,
+        add_contents: impl FnOnce(&mut Self, T) -> R,
+    ) -> Option<R>
+    where
+        T: Clone + Send + Sync + 'static,
+        E: Clone + Send + Sync + std::fmt::Display + 'static,
+        F: std::future::Future<Output = Result<T, E>> + 'static,
+    {
+        let id = self.make_persistent_id(id_salt);
+        match self.ctx().promise_local(id, start) {
+            None => {
+                self.spinner();
+                None
+            }
+            Some(Ok(value)) => Some(add_contents(self, value)),
+            Some(Err(err)) => {
+                self.colored_label(self.visuals().error_fg_color, err.to_string());
+                None
+            }
+        }
+    }
+
     /// Modify an angle. The given angle should be in radians, but is shown to the user in degrees.
     /// The angle is NOT wrapped, so the user may select, for instance 720° = 2𝞃 = 4π
     pub fn drag_angle(&mut self, radians: &mut f32) -> Response {
@@ -2203,8 +2271,7 @@ impl Ui {
         let mut response = self.add(DragValue::new(&mut taus).speed(0.01).suffix("τ"));
 
         if self.style().explanation_tooltips {
-            response =
-                response.on_hover_text("1τ = one turn, 0.5τ = half a turn, etc. 0.25τ = 90°");
+            response = response.on_hover_text(self.ctx().tr("egui-drag-angle-tau"));
         }
 
         // only touch `*radians` if we actually changed the value
@@ -2381,6 +2448,21 @@ impl Ui {
         self.scope_dyn(UiBuilder::new().id_salt(id_salt), Box::new(add_contents))
     }
 
+    /// Put the widgets added by `add_contents` in a [`crate::FocusScope`].
+    ///
+    /// This controls how the keyboard focus moves between them, e.g. when pressing Tab.
+    pub fn focus_scope<R>(
+        &mut self,
+        focus_scope: crate::FocusScope,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.ctx()
+            .memory_mut(|mem| mem.begin_focus_scope(focus_scope));
+        let response = self.scope_dyn(UiBuilder::new(), Box::new(add_contents));
+        self.ctx().memory_mut(|mem| mem.end_focus_scope());
+        response
+    }
+
     /// Push another level onto the [`UiStack`].
     ///
     /// You can use this, for instance, to tag a group of widgets.
@@ -2428,10 +2510,14 @@ impl Ui {
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
         let next_auto_id_salt = self.next_auto_id_salt;
+        let has_id_salt = ui_builder.id_salt.is_some();
         let mut child_ui = self.new_child(ui_builder);
         self.next_auto_id_salt = next_auto_id_salt; // HACK: we want `scope` to only increment this once, so that `ui.scope` is equivalent to `ui.allocate_space`.
         let ret = add_contents(&mut child_ui);
         let response = child_ui.remember_min_rect();
+        if has_id_salt {
+            self.scroll_anchor(child_ui.id, child_ui.min_rect());
+        }
         self.advance_cursor_after_rect(child_ui.min_rect());
         InnerResponse::new(ret, response)
     }
@@ -2742,6 +2828,32 @@ impl Ui {
         self.placer.is_grid()
     }
 
+    /// Make the next cell of the current [`crate::Grid`] span several columns and/or rows.
+    ///
+    /// Call this right before adding the widget that fills the cell, e.g. a section header
+    /// that spans all columns. Cells spanning several rows are skipped over in the rows below.
+    /// If a spanning cell is larger than the columns or rows it spans,
+    /// the extra size is distributed evenly over them.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::Grid::new("properties").num_columns(2).show(ui, |ui| {
+    ///     ui.grid_span(2, 1);
+    ///     ui.heading("Section");
+    ///     ui.end_row();
+    ///
+    ///     ui.label("Name");
+    ///     ui.label("Value");
+    ///     ui.end_row();
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// Does nothing outside of a grid.
+    pub fn grid_span(&mut self, col_span: usize, row_span: usize) {
+        self.placer.set_grid_span(col_span, row_span);
+    }
+
     /// Move to the next row in a grid layout or wrapping layout.
     /// Otherwise does nothing.
     pub fn end_row(&mut self) {
@@ -2979,6 +3091,60 @@ impl Ui {
         (InnerResponse { inner, response }, payload)
     }
 
+    /// Surround the given ui with a frame which
+    /// changes colors when files are dragged over it from outside the app.
+    ///
+    /// Returns the files that were dropped on it this frame, if the `filter` accepts them.
+    ///
+    /// The given frame is used for its margins, but the color is ignored.
+    /// See also [`Response::file_drop_target`].
+    #[doc(alias = "drag and drop")]
+    pub fn file_drop_zone<R>(
+        &mut self,
+        frame: Frame,
+        filter: &FileFilter,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> (InnerResponse<R>, Vec<DroppedFile>) {
+        let (is_anything_being_dragged, can_accept_what_is_being_dragged) = self.input(|i| {
+            (
+                !i.raw.hovered_files.is_empty(),
+                i.raw
+                    .hovered_files
+                    .iter()
+                    .any(|file| filter.accepts_hovered(file)),
+            )
+        });
+
+        let mut frame = frame.begin(self);
+        let inner = add_contents(&mut frame.content_ui);
+        let response = frame.allocate_space(self);
+        let drop = response.file_drop_target(filter);
+
+        let style = if drop.as_ref().is_some_and(FileDrop::can_accept) {
+            self.visuals().widgets.active
+        } else {
+            self.visuals().widgets.inactive
+        };
+
+        let mut fill = style.bg_fill;
+        let mut stroke = style.bg_stroke;
+
+        if is_anything_being_dragged && !can_accept_what_is_being_dragged {
+            // When dragging other files, show that they can't be dropped here:
+            fill = self.visuals().disable(fill);
+            stroke.color = self.visuals().disable(stroke.color);
+        }
+
+        frame.frame.fill = fill;
+        frame.frame.stroke = stroke;
+
+        frame.paint(self);
+
+        let dropped = drop.map(|drop| drop.dropped).unwrap_or_default();
+
+        (InnerResponse { inner, response }, dropped)
+    }
+
     /// Create a new Scope and transform its contents via a [`emath::TSTransform`].
     /// This only affects visuals, inputs will not be transformed. So this is mostly useful
     /// to create visual effects on interactions, e.g. scaling a button on hover / click.
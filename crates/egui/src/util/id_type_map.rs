@@ -4,6 +4,9 @@
 // This will also allow users to pick their own serialization format per type.
 
 use std::{any::Any, sync::Arc};
+
+#[cfg(feature = "persistence")]
+use std::collections::BTreeMap;
 // -----------------------------------------------------------------------------------------------
 
 /// Like [`std::any::TypeId`], but can be serialized and deserialized.
@@ -69,6 +72,12 @@ struct SerializedElement {
     ///
     /// Used to garbage collect old values that hasn't been read in a while.
     generation: usize,
+
+    /// The version of the type when the value was serialized.
+    ///
+    /// See [`IdTypeMap::register_migration`].
+    #[cfg_attr(feature = "persistence", serde(default))]
+    version: u32,
 }
 
 #[cfg(feature = "persistence")]
@@ -124,11 +133,13 @@ impl std::fmt::Debug for Element {
                 type_id,
                 ron,
                 generation,
+                version,
             }) => f
                 .debug_struct("Element::Serialized")
                 .field("type_id", type_id)
                 .field("ron", ron)
                 .field("generation", generation)
+                .field("version", version)
                 .finish(),
         }
     }
@@ -245,12 +256,8 @@ impl Element {
                 }
             }
 
-            #[cfg(feature = "persistence")]
-            Self::Serialized(SerializedElement { ron, .. }) => {
-                *self = Self::new_persisted(from_ron_str::<T>(ron).unwrap_or_else(insert_with));
-            }
-
-            #[cfg(not(feature = "persistence"))]
+            // Serialized values have already been restored by `IdTypeMap::restore_persisted`,
+            // so if we still have one, it could not be restored.
             Self::Serialized(_) => {
                 *self = Self::new_persisted(insert_with());
             }
@@ -268,23 +275,13 @@ impl Element {
         match self {
             Self::Value { value, .. } => value.downcast_mut(),
 
-            #[cfg(feature = "persistence")]
-            Self::Serialized(SerializedElement { ron, .. }) => {
-                *self = Self::new_persisted(from_ron_str::<T>(ron)?);
-
-                match self {
-                    Self::Value { value, .. } => value.downcast_mut(),
-                    Self::Serialized(_) => unreachable!(),
-                }
-            }
-
-            #[cfg(not(feature = "persistence"))]
+            // See `get_persisted_mut_or_insert_with`
             Self::Serialized(_) => None,
         }
     }
 
     #[cfg(feature = "persistence")]
-    fn to_serialize(&self) -> Option<SerializedElement> {
+    fn to_serialize(&self, migrations: &Migrations) -> Option<SerializedElement> {
         match self {
             Self::Value {
                 value,
@@ -293,10 +290,12 @@ impl Element {
             } => {
                 if let Some(serialize_fn) = serialize_fn {
                     let ron = serialize_fn(value)?;
+                    let type_id = (**value).type_id().into();
                     Some(SerializedElement {
-                        type_id: (**value).type_id().into(),
+                        type_id,
                         ron: ron.into(),
                         generation: 1,
+                        version: migrations.current_version(type_id),
                     })
                 } else {
                     None
@@ -307,22 +306,92 @@ impl Element {
     }
 }
 
+// -----------------------------------------------------------------------------------------------
+
+/// Upgrades the RON of a persisted value by one version.
+///
+/// See [`IdTypeMap::register_migration`].
 #[cfg(feature = "persistence")]
-fn from_ron_str<T: serde::de::DeserializeOwned>(ron: &str) -> Option<T> {
-    match ron::from_str::<T>(ron) {
-        Ok(value) => Some(value),
-        Err(_err) => {
-            log::warn!(
-                "egui: Failed to deserialize {} from memory: {}, ron error: {:?}",
-                std::any::type_name::<T>(),
-                _err,
-                ron
-            );
-            None
+pub type MigrationFn = dyn Fn(&str) -> Result<String, String> + Send + Sync;
+
+/// The registered migrations of an [`IdTypeMap`], per type.
+#[cfg(feature = "persistence")]
+#[derive(Clone, Default)]
+struct Migrations(nohash_hasher::IntMap<TypeId, BTreeMap<u32, Arc<MigrationFn>>>);
+
+#[cfg(feature = "persistence")]
+impl std::fmt::Debug for Migrations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Migrations")
+            .field("num_types", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "persistence")]
+impl Migrations {
+    /// One more than the highest version we can migrate from, or zero.
+    fn current_version(&self, type_id: TypeId) -> u32 {
+        self.0
+            .get(&type_id)
+            .and_then(|steps| steps.keys().next_back())
+            .map_or(0, |from_version| from_version + 1)
+    }
+
+    /// Migrate the RON of a value persisted with the given version to the current version.
+    fn migrate<'a>(
+        &self,
+        type_id: TypeId,
+        version: u32,
+        ron: &'a str,
+    ) -> Result<std::borrow::Cow<'a, str>, String> {
+        let current_version = self.current_version(type_id);
+        if current_version < version {
+            return Err(format!(
+                "persisted with version {version}, which is newer than the current version {current_version}"
+            ));
         }
+
+        let mut ron = std::borrow::Cow::Borrowed(ron);
+        for from_version in version..current_version {
+            let migrate = self
+                .0
+                .get(&type_id)
+                .and_then(|steps| steps.get(&from_version))
+                .ok_or_else(|| format!("no migration registered from version {from_version}"))?;
+            ron = migrate(&ron)
+                .map_err(|err| format!("migration from version {from_version} failed: {err}"))?
+                .into();
+        }
+        Ok(ron)
     }
 }
 
+/// Persisted state that could not be restored, and was therefore discarded.
+///
+/// This happens when the format of a persisted type changes without a matching
+/// [`IdTypeMap::register_migration`], e.g. when updating egui or your app.
+///
+/// See [`IdTypeMap::discarded`].
+#[derive(Clone, Debug)]
+pub struct DiscardedState {
+    /// The [`Id`] of the discarded [`IdTypeMap`] value,
+    /// or `None` for a discarded section of [`crate::Memory`].
+    pub id: Option<Id>,
+
+    /// The type name of the discarded value, or the name of the [`crate::Memory`] section.
+    pub name: String,
+
+    /// The version the value was persisted with.
+    pub version: u32,
+
+    /// The persisted RON, in case you want to inspect or salvage it.
+    pub ron: Arc<str>,
+
+    /// Why the value could not be restored.
+    pub error: String,
+}
+
 // -----------------------------------------------------------------------------------------------
 
 use crate::Id;
@@ -407,6 +476,14 @@ pub struct IdTypeMap {
     map: nohash_hasher::IntMap<RawKey, Element>,
 
     max_bytes_per_type: usize,
+
+    /// Registered migrations. Not persisted.
+    #[cfg(feature = "persistence")]
+    migrations: Migrations,
+
+    /// Persisted values we failed to restore. Not persisted.
+    #[cfg(feature = "persistence")]
+    discarded: Vec<DiscardedState>,
 }
 
 impl Default for IdTypeMap {
@@ -414,6 +491,10 @@ impl Default for IdTypeMap {
         Self {
             map: Default::default(),
             max_bytes_per_type: 256 * 1024,
+            #[cfg(feature = "persistence")]
+            migrations: Default::default(),
+            #[cfg(feature = "persistence")]
+            discarded: Default::default(),
         }
     }
 }
@@ -477,6 +558,8 @@ impl IdTypeMap {
     /// The call clones the value (if found), so make sure it is cheap to clone!
     #[inline]
     pub fn get_persisted<T: SerializableAny>(&mut self, id: Id) -> Option<T> {
+        #[cfg(feature = "persistence")]
+        self.restore_persisted::<T>(id);
         let key = RawKey::new::<T>(id);
         self.map
             .get_mut(&key)
@@ -538,6 +621,8 @@ impl IdTypeMap {
         id: Id,
         insert_with: impl FnOnce() -> T,
     ) -> &mut T {
+        #[cfg(feature = "persistence")]
+        self.restore_persisted::<T>(id);
         let key = RawKey::new::<T>(id);
         use std::collections::hash_map::Entry;
         match self.map.entry(key) {
@@ -555,6 +640,105 @@ impl IdTypeMap {
         }
     }
 
+    /// Deserialize the persisted value of this type and id, if it hasn't been already.
+    ///
+    /// If it cannot be migrated or deserialized, it is discarded and added to [`Self::discarded`].
+    #[cfg(feature = "persistence")]
+    fn restore_persisted<T: SerializableAny>(&mut self, id: Id) {
+        let key = RawKey::new::<T>(id);
+        let Some(Element::Serialized(element)) = self.map.get(&key) else {
+            return;
+        };
+        let element = element.clone(); // cheap: the ron is an `Arc<str>`
+
+        let result = self
+            .migrations
+            .migrate(element.type_id, element.version, &element.ron)
+            .and_then(|ron| ron::from_str::<T>(&ron).map_err(|err| err.to_string()));
+
+        match result {
+            Ok(value) => {
+                self.map.insert(key, Element::new_persisted(value));
+            }
+            Err(error) => {
+                let name = std::any::type_name::<T>();
+                log::warn!(
+                    "egui: Discarding persisted {name} (version {}): {error}",
+                    element.version
+                );
+                self.discarded.push(DiscardedState {
+                    id: Some(id),
+                    name: name.to_owned(),
+                    version: element.version,
+                    ron: element.ron,
+                    error,
+                });
+                self.map.remove(&key);
+            }
+        }
+    }
+
+    /// Register a function that migrates persisted values of type `T`
+    /// from `from_version` to `from_version + 1`.
+    ///
+    /// Values of type `T` are persisted together with the current version of `T`,
+    /// which is one more than the highest registered `from_version` (or zero, if there are no migrations).
+    /// When an older value is read, all the migrations from its version up to the current version are applied
+    /// to its RON before it is deserialized.
+    ///
+    /// Values that fail to migrate or deserialize are discarded and reported in [`Self::discarded`].
+    ///
+    /// Migrations are not persisted, so register them before reading any persisted state,
+    /// e.g. when creating your app.
+    ///
+    /// ```
+    /// # use egui::{Id, util::IdTypeMap};
+    /// let mut map = IdTypeMap::default();
+    ///
+    /// // Version 0 was persisted as a plain number, version 1 is a tuple with a unit:
+    /// map.register_migration::<(f32, String)>(0, |ron| Ok(format!("({ron}, \"px\")")));
+    /// assert_eq!(map.persisted_version::<(f32, String)>(), 1);
+    /// ```
+    #[cfg(feature = "persistence")]
+    pub fn register_migration<T: SerializableAny>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(&str) -> Result<String, String> + Send + Sync + 'static,
+    ) {
+        self.migrations
+            .0
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .insert(from_version, Arc::new(migrate));
+    }
+
+    /// The version persisted values of type `T` are currently written with.
+    ///
+    /// See [`Self::register_migration`].
+    #[cfg(feature = "persistence")]
+    pub fn persisted_version<T: 'static>(&self) -> u32 {
+        self.migrations.current_version(TypeId::of::<T>())
+    }
+
+    /// Persisted state that could not be restored, and was therefore discarded.
+    ///
+    /// This also includes sections of [`crate::Memory`] discarded by [`crate::Memory::from_ron_lenient`].
+    #[cfg(feature = "persistence")]
+    pub fn discarded(&self) -> &[DiscardedState] {
+        &self.discarded
+    }
+
+    /// Take the list of [`Self::discarded`] state, leaving it empty.
+    #[cfg(feature = "persistence")]
+    pub fn take_discarded(&mut self) -> Vec<DiscardedState> {
+        std::mem::take(&mut self.discarded)
+    }
+
+    #[cfg(feature = "persistence")]
+    pub(crate) fn extend_discarded(&mut self, discarded: impl IntoIterator<Item = DiscardedState>) {
+        self.discarded.extend(discarded);
+    }
+
     /// For tests
     #[cfg(feature = "persistence")]
     #[allow(clippy::allow_attributes, unused)]
@@ -709,7 +893,7 @@ impl PersistedMap {
         {
             profiling::scope!("gather");
             for (key, element) in &map.map {
-                if let Some(element) = element.to_serialize() {
+                if let Some(element) = element.to_serialize(&map.migrations) {
                     let stats = types_map.entry(element.type_id).or_default();
                     stats.num_bytes += element.ron.len();
                     let generation_stats = stats.generations.entry(element.generation).or_default();
@@ -759,6 +943,7 @@ impl PersistedMap {
                         type_id,
                         ron,
                         generation,
+                        version,
                     },
                 )| {
                     (
@@ -767,6 +952,7 @@ impl PersistedMap {
                             type_id,
                             ron,
                             generation: generation + 1, // This is where we increment the generation!
+                            version,
                         }),
                     )
                 },
@@ -1098,3 +1284,56 @@ fn test_serialize_gc() {
         Some(B(2_000_000))
     );
 }
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_migrations() {
+    type Length = (f32, String);
+
+    fn persisted(id: Id, ron: &str, version: u32) -> (u64, SerializedElement) {
+        let element = SerializedElement {
+            type_id: TypeId::of::<Length>(),
+            ron: ron.into(),
+            generation: 0,
+            version,
+        };
+        (RawKey::new::<Length>(id).0, element)
+    }
+
+    fn register_migrations(map: &mut IdTypeMap) {
+        map.register_migration::<Length>(0, |ron| Ok(format!("({ron}, \"px\")")));
+    }
+
+    let (a, b, c) = (Id::new("a"), Id::new("b"), Id::new("c"));
+
+    let mut map = PersistedMap(vec![
+        persisted(a, "1.5", 0),
+        persisted(b, "oops", 0),
+        persisted(c, "(2.0, \"pt\")", 2),
+    ])
+    .into_map();
+    register_migrations(&mut map);
+    assert_eq!(map.persisted_version::<Length>(), 1);
+
+    assert_eq!(map.get_persisted::<Length>(a), Some((1.5, "px".to_owned())));
+    assert_eq!(map.get_persisted::<Length>(b), None, "Fails to deserialize");
+    assert_eq!(
+        map.get_persisted_mut_or_default::<Length>(c),
+        &Length::default(),
+        "Persisted by a newer version"
+    );
+
+    let discarded = map.take_discarded();
+    assert_eq!(discarded.len(), 2);
+    assert_eq!(discarded[0].id, Some(b));
+    assert_eq!(&*discarded[0].ron, "oops");
+    assert_eq!(discarded[1].id, Some(c));
+    assert_eq!(discarded[1].version, 2);
+    assert!(map.discarded().is_empty());
+
+    // Values are persisted with the current version:
+    let mut map: IdTypeMap = ron::from_str(&ron::to_string(&map).unwrap()).unwrap();
+    register_migrations(&mut map);
+    assert_eq!(map.get_persisted::<Length>(a), Some((1.5, "px".to_owned())));
+    assert!(map.discarded().is_empty());
+}
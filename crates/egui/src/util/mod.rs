@@ -2,9 +2,10 @@
 
 pub(crate) mod fixed_cache;
 pub mod id_type_map;
+pub mod undo_history;
 pub mod undoer;
 
-pub use id_type_map::IdTypeMap;
+pub use id_type_map::{DiscardedState, IdTypeMap};
 
 pub use epaint::emath::History;
 pub use epaint::util::{hash, hash_with};
@@ -0,0 +1,603 @@
+//! Application-level undo/redo history.
+//!
+//! See [`UndoHistory`].
+
+use std::collections::VecDeque;
+
+use crate::{Context, Id, Key, KeyboardShortcut, Modifiers, Response, Ui};
+
+/// Undo the latest change: `Cmd/Ctrl+Z`.
+pub const UNDO_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+
+/// Redo the latest undone change: `Cmd/Ctrl+Shift+Z`.
+pub const REDO_SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::Z);
+
+/// Alternative shortcut for redo: `Cmd/Ctrl+Y`.
+pub const REDO_SHORTCUT_ALT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Y);
+
+/// Limits on how much history an [`UndoHistory`] keeps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Settings {
+    /// Maximum number of undo entries.
+    ///
+    /// Default: `100`
+    pub max_undos: usize,
+
+    /// Maximum number of bytes used by the stored states (undo and redo entries combined),
+    /// as measured by the function given to [`UndoHistory::with_size_fn`].
+    ///
+    /// The oldest undo entries are dropped when this is exceeded,
+    /// but the latest one is always kept.
+    ///
+    /// Default: `usize::MAX`
+    pub max_bytes: usize,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_undos: 100,
+            max_bytes: usize::MAX,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct Entry<State> {
+    /// Human readable name of the change, e.g. "Move node".
+    name: String,
+
+    /// For undo entries: the state before the change.
+    /// For redo entries: the state after the change.
+    state: State,
+
+    /// Changes recorded with the same merge id are merged into this entry,
+    /// until the merge is ended.
+    merge_id: Option<Id>,
+
+    /// Size of the state in bytes.
+    size: usize,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct Group {
+    name: String,
+    depth: usize,
+    recorded: bool,
+}
+
+/// Undo/redo history for the state of an application, e.g. the document of an editor.
+///
+/// Unlike [`super::undoer::Undoer`], which compares snapshots of the state on a timer,
+/// you tell [`UndoHistory`] when a change happens, and give each change a name.
+///
+/// Before each change you [`Self::record`] the state as it was before the change.
+/// [`Self::undo`] and [`Self::redo`] then swap the stored states with the current one.
+///
+/// * Continuous edits, like dragging a [`crate::Slider`], are merged into one entry with [`Self::track`]
+///   (or [`Self::record_merged`] and [`Self::end_merge`]).
+/// * Several changes can be grouped into one entry with [`Self::begin_group`] and [`Self::end_group`].
+/// * The memory used is limited by [`Settings`].
+/// * [`Self::handle_shortcuts`] gives you the usual `Cmd/Ctrl+Z` behavior,
+///   and [`Self::ui`] shows the history in a list.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut history = egui::util::undo_history::UndoHistory::default();
+/// # let mut value = 0.0_f32;
+/// let before = value;
+/// let response = ui.add(egui::Slider::new(&mut value, 0.0..=1.0));
+/// history.track(&response, "Change value", &before);
+///
+/// history.handle_shortcuts(ui.ctx(), &mut value);
+/// # });
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct UndoHistory<State> {
+    settings: Settings,
+
+    #[cfg_attr(feature = "serde", serde(skip, default = "default_size_fn"))]
+    size_fn: fn(&State) -> usize,
+
+    /// New entries are added to the back.
+    undos: VecDeque<Entry<State>>,
+
+    /// The next entry to redo is at the back.
+    /// Gets cleared every time a new change is recorded.
+    redos: Vec<Entry<State>>,
+
+    /// Total [`Entry::size`] of all undo and redo entries.
+    num_bytes: usize,
+
+    /// Is the latest undo entry still accepting merges?
+    merging: bool,
+
+    group: Option<Group>,
+}
+
+fn default_size_fn<State>() -> fn(&State) -> usize {
+    |_| std::mem::size_of::<State>()
+}
+
+impl<State> std::fmt::Debug for UndoHistory<State> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            undos,
+            redos,
+            num_bytes,
+            ..
+        } = self;
+        f.debug_struct("UndoHistory")
+            .field("undo count", &undos.len())
+            .field("redo count", &redos.len())
+            .field("num bytes", num_bytes)
+            .finish()
+    }
+}
+
+impl<State> Default for UndoHistory<State> {
+    #[inline]
+    fn default() -> Self {
+        Self::with_settings(Settings::default())
+    }
+}
+
+impl<State> UndoHistory<State> {
+    /// Create a new [`UndoHistory`] with the given [`Settings`].
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            size_fn: default_size_fn(),
+            undos: VecDeque::new(),
+            redos: Vec::new(),
+            num_bytes: 0,
+            merging: false,
+            group: None,
+        }
+    }
+
+    /// How to measure the size of a state, for [`Settings::max_bytes`].
+    ///
+    /// Default: [`std::mem::size_of`], which ignores any heap allocations.
+    #[inline]
+    pub fn with_size_fn(mut self, size_fn: fn(&State) -> usize) -> Self {
+        self.size_fn = size_fn;
+        self
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn set_settings(&mut self, settings: Settings) {
+        self.settings = settings;
+        self.enforce_limits();
+    }
+
+    /// Is there anything to undo?
+    pub fn has_undo(&self) -> bool {
+        !self.undos.is_empty()
+    }
+
+    /// Is there anything to redo?
+    pub fn has_redo(&self) -> bool {
+        !self.redos.is_empty()
+    }
+
+    /// The name of the change that [`Self::undo`] would undo.
+    pub fn undo_name(&self) -> Option<&str> {
+        self.undos.back().map(|entry| entry.name.as_str())
+    }
+
+    /// The name of the change that [`Self::redo`] would redo.
+    pub fn redo_name(&self) -> Option<&str> {
+        self.redos.last().map(|entry| entry.name.as_str())
+    }
+
+    /// The names of all changes that can be undone, oldest first.
+    pub fn undo_names(&self) -> impl Iterator<Item = &str> {
+        self.undos.iter().map(|entry| entry.name.as_str())
+    }
+
+    /// The names of all changes that can be redone, in the order they would be redone.
+    pub fn redo_names(&self) -> impl Iterator<Item = &str> {
+        self.redos.iter().rev().map(|entry| entry.name.as_str())
+    }
+
+    /// The number of bytes used by the stored states, see [`Settings::max_bytes`].
+    pub fn num_bytes(&self) -> usize {
+        self.num_bytes
+    }
+
+    /// Forget all undo and redo entries.
+    pub fn clear(&mut self) {
+        self.undos.clear();
+        self.redos.clear();
+        self.num_bytes = 0;
+        self.merging = false;
+        self.group = None;
+    }
+
+    /// Record a change, given the state from before the change.
+    ///
+    /// This clears the redo entries.
+    pub fn record(&mut self, name: impl Into<String>, before: &State)
+    where
+        State: Clone,
+    {
+        if self.record_in_group(before) {
+            return;
+        }
+        self.push_new(name.into(), None, before);
+    }
+
+    /// Record a change that is part of a continuous edit, given the state from before the change.
+    ///
+    /// If the latest entry was recorded with the same `merge_id`,
+    /// and [`Self::end_merge`] wasn't called since,
+    /// the change is merged into that entry (so undoing it will go back to before the first change).
+    ///
+    /// Use e.g. the [`Id`] of the widget being dragged as the `merge_id`,
+    /// or use [`Self::track`] which does that for you.
+    pub fn record_merged(&mut self, name: impl Into<String>, merge_id: Id, before: &State)
+    where
+        State: Clone,
+    {
+        if self.record_in_group(before) {
+            return;
+        }
+        if self.merging
+            && self
+                .undos
+                .back()
+                .is_some_and(|entry| entry.merge_id == Some(merge_id))
+        {
+            return;
+        }
+        self.push_new(name.into(), Some(merge_id), before);
+        self.merging = true;
+    }
+
+    /// Stop merging changes into the latest entry, see [`Self::record_merged`].
+    pub fn end_merge(&mut self) {
+        self.merging = false;
+    }
+
+    /// Record the change made by a widget, merging continuous edits into one entry.
+    ///
+    /// `before` is the state from before the widget was shown.
+    /// Changes are merged for as long as the widget is being dragged or has keyboard focus,
+    /// so dragging a [`crate::Slider`] or [`crate::DragValue`] results in a single entry.
+    pub fn track(&mut self, response: &Response, name: impl Into<String>, before: &State)
+    where
+        State: Clone,
+    {
+        if response.changed() {
+            self.record_merged(name, response.id, before);
+        }
+        let is_latest = self
+            .undos
+            .back()
+            .is_some_and(|entry| entry.merge_id == Some(response.id));
+        if is_latest && !response.dragged() && !response.has_focus() {
+            self.end_merge();
+        }
+    }
+
+    /// Start a group of changes, which will be a single entry in the history.
+    ///
+    /// All changes recorded until the matching [`Self::end_group`] are merged,
+    /// so undoing the group goes back to before the first change.
+    ///
+    /// Groups can be nested, in which case the name of the outermost group is used.
+    ///
+    /// Undoing or redoing while a group is open completes the entry of the group so far,
+    /// and changes recorded after that start a new entry (with the same name).
+    pub fn begin_group(&mut self, name: impl Into<String>) {
+        if let Some(group) = &mut self.group {
+            group.depth += 1;
+        } else {
+            self.group = Some(Group {
+                name: name.into(),
+                depth: 1,
+                recorded: false,
+            });
+        }
+        self.merging = false;
+    }
+
+    /// End a group started with [`Self::begin_group`].
+    pub fn end_group(&mut self) {
+        if let Some(group) = &mut self.group {
+            group.depth -= 1;
+            if group.depth == 0 {
+                self.group = None;
+            }
+        } else {
+            log::warn!("UndoHistory::end_group called without a matching begin_group");
+        }
+    }
+
+    /// Undo the latest change, replacing `state` with the state from before it.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self, state: &mut State) -> bool {
+        let Some(entry) = self.undos.pop_back() else {
+            return false;
+        };
+        self.merging = false;
+        self.commit_group();
+        let redo = self.swap(entry, state);
+        self.redos.push(redo);
+        true
+    }
+
+    /// Redo the latest undone change, replacing `state` with the state from after it.
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self, state: &mut State) -> bool {
+        let Some(entry) = self.redos.pop() else {
+            return false;
+        };
+        self.merging = false;
+        self.commit_group();
+        let undo = self.swap(entry, state);
+        self.undos.push_back(undo);
+        self.enforce_limits();
+        true
+    }
+
+    /// Undo or redo on `Cmd/Ctrl+Z`, `Cmd/Ctrl+Shift+Z` and `Cmd/Ctrl+Y`.
+    ///
+    /// Does nothing while a [`crate::TextEdit`] has keyboard focus, since it has its own undo.
+    ///
+    /// Returns `true` if `state` was changed.
+    pub fn handle_shortcuts(&mut self, ctx: &Context, state: &mut State) -> bool {
+        if ctx.text_edit_focused() {
+            return false;
+        }
+
+        // Check redo first, since it is the more specific shortcut:
+        let (undo, redo) = ctx.input_mut(|i| {
+            let redo = i.consume_shortcut(&REDO_SHORTCUT) || i.consume_shortcut(&REDO_SHORTCUT_ALT);
+            (i.consume_shortcut(&UNDO_SHORTCUT), redo)
+        });
+
+        (undo && self.undo(state)) | (redo && self.redo(state))
+    }
+
+    /// Show the history as a list, where clicking an entry undoes or redoes up to that point.
+    ///
+    /// Returns `true` if `state` was changed.
+    pub fn ui(&mut self, ui: &mut Ui, state: &mut State) -> bool {
+        let position = self.undos.len();
+        let mut target = ui
+            .selectable_label(position == 0, "Initial state")
+            .clicked()
+            .then_some(0);
+        for (i, name) in self.undo_names().enumerate() {
+            if ui.selectable_label(i + 1 == position, name).clicked() {
+                target = Some(i + 1);
+            }
+        }
+        for (i, name) in self.redo_names().enumerate() {
+            let text = crate::RichText::new(name).weak();
+            if ui.selectable_label(false, text).clicked() {
+                target = Some(position + i + 1);
+            }
+        }
+
+        let Some(target) = target else {
+            return false;
+        };
+        let mut changed = false;
+        while self.undos.len() > target && self.undo(state) {
+            changed = true;
+        }
+        while self.undos.len() < target && self.redo(state) {
+            changed = true;
+        }
+        changed
+    }
+
+    /// Returns `true` if the change was handled by an open group.
+    fn record_in_group(&mut self, before: &State) -> bool
+    where
+        State: Clone,
+    {
+        let Some(group) = &mut self.group else {
+            return false;
+        };
+        if !group.recorded {
+            group.recorded = true;
+            let name = group.name.clone();
+            self.push_new(name, None, before);
+        }
+        true
+    }
+
+    /// Let the open group (if any) record a new entry for its next change,
+    /// so that changes after an undo or redo don't get merged into the previous entry.
+    fn commit_group(&mut self) {
+        if let Some(group) = &mut self.group {
+            group.recorded = false;
+        }
+    }
+
+    fn push_new(&mut self, name: String, merge_id: Option<Id>, before: &State)
+    where
+        State: Clone,
+    {
+        for entry in self.redos.drain(..) {
+            self.num_bytes -= entry.size;
+        }
+        let size = (self.size_fn)(before);
+        self.num_bytes += size;
+        self.undos.push_back(Entry {
+            name,
+            state: before.clone(),
+            merge_id,
+            size,
+        });
+        self.merging = false;
+        self.enforce_limits();
+    }
+
+    /// Put the state of `entry` into `state`, and return an entry with the previous `state`.
+    fn swap(&mut self, entry: Entry<State>, state: &mut State) -> Entry<State> {
+        let Entry {
+            name, state: other, ..
+        } = entry;
+        let previous = std::mem::replace(state, other);
+        let size = (self.size_fn)(&previous);
+        self.num_bytes = self.num_bytes - entry.size + size;
+        Entry {
+            name,
+            state: previous,
+            merge_id: None,
+            size,
+        }
+    }
+
+    fn enforce_limits(&mut self) {
+        while self.undos.len() > self.settings.max_undos
+            || (self.num_bytes > self.settings.max_bytes && self.undos.len() > 1)
+        {
+            let Some(entry) = self.undos.pop_front() else {
+                break;
+            };
+            self.num_bytes -= entry.size;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_redo() {
+        let mut history = UndoHistory::default();
+        let mut state = 0;
+
+        history.record("one", &state);
+        state = 1;
+        history.record("two", &state);
+        state = 2;
+        assert_eq!(history.undo_names().collect::<Vec<_>>(), ["one", "two"]);
+
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 1);
+        assert_eq!(history.redo_name(), Some("two"));
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 0);
+        assert!(!history.undo(&mut state));
+
+        assert!(history.redo(&mut state));
+        assert_eq!(state, 1);
+
+        // A new change clears the redos:
+        history.record("three", &state);
+        state = 3;
+        assert!(!history.has_redo());
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 1);
+        assert_eq!(history.num_bytes(), 2 * std::mem::size_of::<i32>());
+    }
+
+    #[test]
+    fn merging_and_groups() {
+        let mut history = UndoHistory::default();
+        let mut state = 0;
+        let slider = Id::new("slider");
+
+        for value in 1..=3 {
+            history.record_merged("drag", slider, &state);
+            state = value;
+        }
+        history.end_merge();
+        history.record_merged("drag", slider, &state);
+        state = 4;
+        assert_eq!(history.undo_names().count(), 2);
+
+        history.begin_group("group");
+        history.record("a", &state);
+        state = 5;
+        history.begin_group("nested");
+        history.record("b", &state);
+        state = 6;
+        history.end_group();
+        history.end_group();
+        assert_eq!(history.undo_name(), Some("group"));
+
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 4);
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 3);
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 0);
+    }
+
+    #[test]
+    fn undo_in_open_group() {
+        let mut history = UndoHistory::default();
+        let mut state = 0;
+
+        history.begin_group("group");
+        history.record("a", &state);
+        state = 1;
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 0);
+
+        // The group is still open, but changes after the undo get a new entry:
+        history.record("b", &state);
+        state = 2;
+        history.record("c", &state);
+        state = 3;
+        history.end_group();
+        assert_eq!(history.undo_names().collect::<Vec<_>>(), ["group"]);
+
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 0);
+
+        history.begin_group("other");
+        history.record("d", &state);
+        state = 4;
+        assert!(history.undo(&mut state));
+        assert!(history.redo(&mut state));
+        assert_eq!(state, 4);
+        history.record("e", &state);
+        state = 5;
+        history.end_group();
+        assert_eq!(history.undo_names().collect::<Vec<_>>(), ["other", "other"]);
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 4);
+    }
+
+    #[test]
+    fn limits() {
+        let mut history = UndoHistory::with_settings(Settings {
+            max_undos: 3,
+            max_bytes: 100,
+        })
+        .with_size_fn(|s: &Vec<u8>| s.len());
+        let mut state = vec![];
+
+        for i in 0..5 {
+            history.record("push", &state);
+            state.push(i);
+        }
+        assert_eq!(history.undo_names().count(), 3);
+
+        history.record("big", &state);
+        state = vec![0; 200];
+        history.record("huge", &state);
+        assert_eq!(history.undo_names().collect::<Vec<_>>(), ["huge"]);
+        assert_eq!(history.num_bytes(), 200);
+    }
+}
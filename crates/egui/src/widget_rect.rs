@@ -1,6 +1,8 @@
+use std::sync::Arc;
+
 use ahash::HashMap;
 
-use crate::{Id, IdMap, LayerId, Rect, Sense, WidgetInfo};
+use crate::{Id, IdMap, LayerId, Pos2, Rect, Sense, WidgetInfo};
 
 /// Used to store each widget's [Id], [Rect] and [Sense] each frame.
 ///
@@ -71,6 +73,139 @@ impl WidgetRect {
     }
 }
 
+/// A shape used instead of the rectangle of a widget when finding what is under the pointer.
+///
+/// Set it with [`crate::Response::with_hit_shape`].
+/// The shape is in local layer coordinates, like [`WidgetRect::rect`],
+/// and the widget can only be hit inside of its [`WidgetRect::interact_rect`].
+#[derive(Clone)]
+pub enum HitShape {
+    /// A filled circle.
+    Circle { center: Pos2, radius: f32 },
+
+    /// A filled polygon. Self-intersecting polygons are filled using the even-odd rule.
+    Polygon(Arc<[Pos2]>),
+
+    /// A line through the points, with the given thickness and round caps.
+    Path { points: Arc<[Pos2]>, thickness: f32 },
+
+    /// Returns the distance from the given position to the shape, which is zero inside of it.
+    Custom(Arc<dyn Fn(Pos2) -> f32 + Send + Sync>),
+}
+
+impl std::fmt::Debug for HitShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Circle { center, radius } => f
+                .debug_struct("Circle")
+                .field("center", center)
+                .field("radius", radius)
+                .finish(),
+            Self::Polygon(points) => f.debug_tuple("Polygon").field(points).finish(),
+            Self::Path { points, thickness } => f
+                .debug_struct("Path")
+                .field("points", points)
+                .field("thickness", thickness)
+                .finish(),
+            Self::Custom(_) => f.debug_tuple("Custom").finish_non_exhaustive(),
+        }
+    }
+}
+
+impl HitShape {
+    /// A filled circle.
+    pub fn circle(center: Pos2, radius: f32) -> Self {
+        Self::Circle { center, radius }
+    }
+
+    /// A filled polygon.
+    pub fn polygon(points: impl Into<Arc<[Pos2]>>) -> Self {
+        Self::Polygon(points.into())
+    }
+
+    /// A line through the points, with the given thickness.
+    pub fn path(points: impl Into<Arc<[Pos2]>>, thickness: f32) -> Self {
+        Self::Path {
+            points: points.into(),
+            thickness,
+        }
+    }
+
+    /// A line segment from `a` to `b` with round caps, e.g. for a connection wire.
+    pub fn capsule(a: Pos2, b: Pos2, radius: f32) -> Self {
+        Self::path(vec![a, b], 2.0 * radius)
+    }
+
+    /// Use a function that returns the distance from a position to the shape,
+    /// which should be zero (or negative) inside of the shape.
+    pub fn custom(distance: impl Fn(Pos2) -> f32 + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(distance))
+    }
+
+    /// The distance from the position to the shape, which is zero inside of it.
+    pub fn distance(&self, pos: Pos2) -> f32 {
+        let distance = match self {
+            Self::Circle { center, radius } => center.distance(pos) - radius,
+            Self::Polygon(points) => {
+                if polygon_contains(points, pos) {
+                    0.0
+                } else {
+                    segments(points, true)
+                        .map(|(a, b)| distance_to_segment(pos, a, b))
+                        .fold(f32::INFINITY, f32::min)
+                }
+            }
+            Self::Path { points, thickness } => {
+                let distance = if points.len() == 1 {
+                    points[0].distance(pos)
+                } else {
+                    segments(points, false)
+                        .map(|(a, b)| distance_to_segment(pos, a, b))
+                        .fold(f32::INFINITY, f32::min)
+                };
+                distance - thickness / 2.0
+            }
+            Self::Custom(distance) => distance(pos),
+        };
+        distance.max(0.0)
+    }
+
+    /// Is the position inside of the shape?
+    pub fn contains(&self, pos: Pos2) -> bool {
+        self.distance(pos) <= 0.0
+    }
+}
+
+/// The line segments between consecutive points, optionally back to the first point.
+fn segments(points: &[Pos2], closed: bool) -> impl Iterator<Item = (Pos2, Pos2)> + '_ {
+    let closing = closed
+        .then(|| Some((*points.last()?, *points.first()?)))
+        .flatten();
+    points.windows(2).map(|w| (w[0], w[1])).chain(closing)
+}
+
+fn distance_to_segment(pos: Pos2, a: Pos2, b: Pos2) -> f32 {
+    let ab = b - a;
+    let t = if 0.0 < ab.length_sq() {
+        ((pos - a).dot(ab) / ab.length_sq()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (a + t * ab).distance(pos)
+}
+
+/// Even-odd rule.
+fn polygon_contains(points: &[Pos2], pos: Pos2) -> bool {
+    let mut inside = false;
+    for (a, b) in segments(points, true) {
+        if (a.y > pos.y) != (b.y > pos.y) && pos.x < a.x + (pos.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
 /// How to handle multiple calls to [`crate::Response::interact`] and [`crate::Ui::interact_opt`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct InteractOptions {
@@ -103,6 +238,9 @@ pub struct WidgetRects {
     /// Only filled in if the widget is interacted with,
     /// or if this is a debug build.
     infos: IdMap<WidgetInfo>,
+
+    /// Custom hit shapes of some widgets.
+    hit_shapes: IdMap<HitShape>,
 }
 
 impl PartialEq for WidgetRects {
@@ -150,6 +288,7 @@ impl WidgetRects {
             by_layer,
             by_id,
             infos,
+            hit_shapes,
         } = self;
 
         #[expect(clippy::iter_over_hash_type)]
@@ -160,6 +299,8 @@ impl WidgetRects {
         by_id.clear();
 
         infos.clear();
+
+        hit_shapes.clear();
     }
 
     /// Insert the given widget rect in the given layer.
@@ -168,6 +309,7 @@ impl WidgetRects {
             by_layer,
             by_id,
             infos: _,
+            hit_shapes: _,
         } = self;
 
         let InteractOptions { move_to_top } = options;
@@ -230,4 +372,26 @@ impl WidgetRects {
     pub fn info(&self, id: Id) -> Option<&WidgetInfo> {
         self.infos.get(&id)
     }
+
+    pub fn set_hit_shape(&mut self, id: Id, shape: HitShape) {
+        self.hit_shapes.insert(id, shape);
+    }
+
+    /// The custom hit shape of the widget, if any.
+    pub fn hit_shape(&self, id: Id) -> Option<&HitShape> {
+        self.hit_shapes.get(&id)
+    }
+
+    /// The squared distance from the position to the widget, in local layer coordinates.
+    ///
+    /// Uses the [`HitShape`] of the widget, if it has one.
+    pub fn distance_sq_to_pos(&self, widget: &WidgetRect, pos: Pos2) -> f32 {
+        let rect_dist_sq = widget.interact_rect.distance_sq_to_pos(pos);
+        if let Some(shape) = self.hit_shape(widget.id) {
+            let shape_dist = shape.distance(pos);
+            rect_dist_sq.max(shape_dist * shape_dist)
+        } else {
+            rect_dist_sq
+        }
+    }
 }
@@ -551,7 +551,7 @@ impl Widget for DragValue<'_> {
             if let Some(value_text) = value_text {
                 // We were editing the value as text last frame, but lost focus.
                 // Make sure we applied the last text value:
-                let parsed_value = parse(custom_parser.as_ref(), &value_text);
+                let parsed_value = parse(ui, custom_parser.as_ref(), &value_text);
                 if let Some(mut parsed_value) = parsed_value {
                     // User edits always clamps:
                     parsed_value = clamp_value_to_range(parsed_value, range.clone());
@@ -591,7 +591,7 @@ impl Widget for DragValue<'_> {
                 response.lost_focus() && !ui.input(|i| i.key_pressed(Key::Escape))
             };
             if update {
-                let parsed_value = parse(custom_parser.as_ref(), &value_text);
+                let parsed_value = parse(ui, custom_parser.as_ref(), &value_text);
                 if let Some(mut parsed_value) = parsed_value {
                     // User edits always clamps:
                     parsed_value = clamp_value_to_range(parsed_value, range.clone());
@@ -733,28 +733,13 @@ impl Widget for DragValue<'_> {
     }
 }
 
-fn parse(custom_parser: Option<&NumParser<'_>>, value_text: &str) -> Option<f64> {
+fn parse(ui: &Ui, custom_parser: Option<&NumParser<'_>>, value_text: &str) -> Option<f64> {
     match custom_parser {
         Some(parser) => parser(value_text),
-        None => default_parser(value_text),
+        None => ui.style().number_formatter.parse(value_text),
     }
 }
 
-/// The default egui parser of numbers.
-///
-/// It ignored whitespaces anywhere in the input, and treats the special minus character (U+2212) as a normal minus.
-fn default_parser(text: &str) -> Option<f64> {
-    let text: String = text
-        .chars()
-        // Ignore whitespace (trailing, leading, and thousands separators):
-        .filter(|c| !c.is_whitespace())
-        // Replace special minus character with normal minus (hyphen):
-        .map(|c| if c == '−' { '-' } else { c })
-        .collect();
-
-    text.parse().ok()
-}
-
 /// Clamp the given value with careful handling of negative zero, and other corner cases.
 pub(crate) fn clamp_value_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
     let (mut min, mut max) = (*range.start(), *range.end());
@@ -813,29 +798,31 @@ mod tests {
 
     #[test]
     fn test_default_parser() {
-        assert_eq!(super::default_parser("123"), Some(123.0));
+        let default_parser = |text| crate::i18n::NumberSymbols::PLAIN.parse(text);
+
+        assert_eq!(default_parser("123"), Some(123.0));
 
-        assert_eq!(super::default_parser("1.23"), Some(1.230));
+        assert_eq!(default_parser("1.23"), Some(1.230));
 
         assert_eq!(
-            super::default_parser(" 1.23 "),
+            default_parser(" 1.23 "),
             Some(1.230),
             "We should handle leading and trailing spaces"
         );
 
         assert_eq!(
-            super::default_parser("1 234 567"),
+            default_parser("1 234 567"),
             Some(1_234_567.0),
             "We should handle thousands separators using half-space"
         );
 
         assert_eq!(
-            super::default_parser("-1.23"),
+            default_parser("-1.23"),
             Some(-1.23),
             "Should handle normal hyphen as minus character"
         );
         assert_eq!(
-            super::default_parser("−1.23"),
+            default_parser("−1.23"),
             Some(-1.23),
             "Should handle special minus character (https://www.compart.com/en/unicode/U+2212)"
         );
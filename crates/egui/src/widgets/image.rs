@@ -963,10 +963,74 @@ pub fn has_webp_header(bytes: &[u8]) -> bool {
     bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP"
 }
 
+/// Checks if uri is an animated png file
+fn is_apng_uri(uri: &str) -> bool {
+    uri.ends_with(".apng") || uri.contains(".apng#")
+}
+
+/// Checks if bytes are an animated png.
+///
+/// This walks the chunks of the png and looks for an `acTL` (animation control) chunk,
+/// which must come before the first `IDAT` chunk.
+pub fn has_apng_header(bytes: &[u8]) -> bool {
+    const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    let Some(mut chunks) = bytes.strip_prefix(PNG_SIGNATURE) else {
+        return false;
+    };
+
+    // Each chunk is: length (4 bytes, big endian), type (4 bytes), data, crc (4 bytes)
+    while chunks.len() >= 8 {
+        let length = u32::from_be_bytes([chunks[0], chunks[1], chunks[2], chunks[3]]) as usize;
+        match &chunks[4..8] {
+            b"acTL" => return true,
+            b"IDAT" | b"IEND" => return false,
+            _ => {}
+        }
+        let Some(rest) = length.checked_add(12).and_then(|end| chunks.get(end..)) else {
+            return false;
+        };
+        chunks = rest;
+    }
+
+    false
+}
+
 fn is_animated_image_uri(uri: &str) -> bool {
-    is_gif_uri(uri) || is_webp_uri(uri)
+    is_gif_uri(uri) || is_webp_uri(uri) || is_apng_uri(uri)
 }
 
 fn are_animated_image_bytes(bytes: &[u8]) -> bool {
-    has_gif_magic_header(bytes) || has_webp_header(bytes)
+    has_gif_magic_header(bytes) || has_webp_header(bytes) || has_apng_header(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png_chunk(kind: &[u8; 4], data: &[u8]) -> Vec<u8> {
+        let mut chunk = (data.len() as u32).to_be_bytes().to_vec();
+        chunk.extend_from_slice(kind);
+        chunk.extend_from_slice(data);
+        chunk.extend_from_slice(&[0; 4]); // crc is not checked
+        chunk
+    }
+
+    #[test]
+    fn apng_header() {
+        let mut still = b"\x89PNG\r\n\x1a\n".to_vec();
+        still.extend(png_chunk(b"IHDR", &[0; 13]));
+        still.extend(png_chunk(b"IDAT", &[0; 4]));
+        still.extend(png_chunk(b"IEND", &[]));
+        assert!(!has_apng_header(&still));
+
+        let mut animated = b"\x89PNG\r\n\x1a\n".to_vec();
+        animated.extend(png_chunk(b"IHDR", &[0; 13]));
+        animated.extend(png_chunk(b"acTL", &[0; 8]));
+        animated.extend(png_chunk(b"IDAT", &[0; 4]));
+        assert!(has_apng_header(&animated));
+
+        assert!(!has_apng_header(b"GIF89a"));
+        assert!(!has_apng_header(&animated[..20]));
+    }
 }
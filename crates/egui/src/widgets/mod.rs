@@ -32,7 +32,8 @@ pub use self::{
     hyperlink::{Hyperlink, Link},
     image::{
         FrameDurations, Image, ImageFit, ImageOptions, ImageSize, ImageSource,
-        decode_animated_image_uri, has_gif_magic_header, has_webp_header, paint_texture_at,
+        decode_animated_image_uri, has_apng_header, has_gif_magic_header, has_webp_header,
+        paint_texture_at,
     },
     image_button::ImageButton,
     label::Label,
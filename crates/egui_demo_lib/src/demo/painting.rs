@@ -5,6 +5,12 @@ use egui::{Color32, Frame, Pos2, Rect, Sense, Stroke, Ui, Window, emath, vec2};
 pub struct Painting {
     /// in 0-1 normalized coordinates
     lines: Vec<Vec<Pos2>>,
+
+    /// Pen pressure at each point of the corresponding line in `lines`.
+    ///
+    /// Empty for lines drawn with a mouse or finger.
+    pressures: Vec<Vec<f32>>,
+
     stroke: Stroke,
 }
 
@@ -12,6 +18,7 @@ impl Default for Painting {
     fn default() -> Self {
         Self {
             lines: Default::default(),
+            pressures: Default::default(),
             stroke: Stroke::new(1.0, Color32::from_rgb(25, 200, 100)),
         }
     }
@@ -25,6 +32,7 @@ impl Painting {
             ui.separator();
             if ui.button("Clear Painting").clicked() {
                 self.lines.clear();
+                self.pressures.clear();
             }
         })
         .response
@@ -43,30 +51,43 @@ impl Painting {
         if self.lines.is_empty() {
             self.lines.push(vec![]);
         }
+        self.pressures.resize_with(self.lines.len(), Vec::new);
 
         let current_line = self.lines.last_mut().unwrap();
+        let current_pressures = self.pressures.last_mut().unwrap();
 
         if let Some(pointer_pos) = response.interact_pointer_pos() {
             let canvas_pos = from_screen * pointer_pos;
             if current_line.last() != Some(&canvas_pos) {
                 current_line.push(canvas_pos);
+                if let Some(pen) = ui.input(|i| i.pointer.pen()) {
+                    current_pressures.push(pen.pressure);
+                }
                 response.mark_changed();
             }
         } else if !current_line.is_empty() {
             self.lines.push(vec![]);
+            self.pressures.push(vec![]);
             response.mark_changed();
         }
 
-        let shapes = self
-            .lines
-            .iter()
-            .filter(|line| line.len() >= 2)
-            .map(|line| {
-                let points: Vec<Pos2> = line.iter().map(|p| to_screen * *p).collect();
-                egui::Shape::line(points, self.stroke)
-            });
-
-        painter.extend(shapes);
+        for (line, pressures) in self.lines.iter().zip(&self.pressures) {
+            if line.len() < 2 {
+                continue;
+            }
+            let points: Vec<Pos2> = line.iter().map(|p| to_screen * *p).collect();
+            if pressures.len() == points.len() {
+                // Drawn with a pen: vary the width with the pressure.
+                for (i, segment) in points.windows(2).enumerate() {
+                    let pressure = 0.5 * (pressures[i] + pressures[i + 1]);
+                    let width = 2.0 * pressure * self.stroke.width;
+                    painter.line_segment([segment[0], segment[1]], (width, self.stroke.color));
+                    painter.circle_filled(segment[1], 0.5 * width, self.stroke.color);
+                }
+            } else {
+                painter.add(egui::Shape::line(points, self.stroke));
+            }
+        }
 
         response
     }
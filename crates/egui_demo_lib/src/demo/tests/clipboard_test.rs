@@ -1,11 +1,15 @@
 pub struct ClipboardTest {
     text: String,
+
+    /// What was pasted last, other than text.
+    pasted: Vec<String>,
 }
 
 impl Default for ClipboardTest {
     fn default() -> Self {
         Self {
             text: "Example text you can copy-and-paste".to_owned(),
+            pasted: Vec::new(),
         }
     }
 }
@@ -76,6 +80,42 @@ impl crate::View for ClipboardTest {
             }
         });
 
+        ui.separator();
+
+        ui.label("Paste an image or rich text to see what the clipboard had:");
+        let (pasted_anything, pasted) = ui.input(|i| {
+            let pasted_anything = i.events.iter().any(|event| {
+                matches!(
+                    event,
+                    egui::Event::Paste(_)
+                        | egui::Event::PasteImage(_)
+                        | egui::Event::PasteData { .. }
+                )
+            });
+            let pasted: Vec<String> = i
+                .events
+                .iter()
+                .filter_map(|event| match event {
+                    egui::Event::PasteImage(image) => Some(format!(
+                        "An image of {}×{} pixels",
+                        image.width(),
+                        image.height()
+                    )),
+                    egui::Event::PasteData { mime, bytes } => {
+                        Some(format!("{mime} ({} bytes)", bytes.len()))
+                    }
+                    _ => None,
+                })
+                .collect();
+            (pasted_anything, pasted)
+        });
+        if pasted_anything {
+            self.pasted = pasted;
+        }
+        for pasted in &self.pasted {
+            ui.label(pasted);
+        }
+
         ui.vertical_centered_justified(|ui| {
             ui.add(crate::egui_github_link_file!());
         });
@@ -5,6 +5,7 @@ mod popup;
 
 pub use button::DatePickerButton;
 use jiff::civil::{Date, ISOWeekDate, Weekday};
+pub use popup::DATE_PICKER_MESSAGES;
 
 #[derive(Debug)]
 struct Week {
@@ -1,11 +1,52 @@
 use jiff::civil::{Date, Weekday};
 
-use egui::{Align, Button, Color32, ComboBox, Direction, Id, Layout, RichText, Ui, Vec2};
+use egui::{
+    Align, Button, Color32, ComboBox, Context, Direction, Id, Layout, RichText, Ui, Vec2,
+    i18n::{MessageArgs, MessageBundle},
+};
 
 use super::{button::DatePickerButtonState, month_data};
 
 use crate::{Column, Size, StripBuilder, TableBuilder};
 
+/// The English originals of the strings in the date picker.
+///
+/// Translate these and add them with [`egui::Context::add_messages`] to localize the date picker.
+pub const DATE_PICKER_MESSAGES: &str = r"
+egui-extras-datepicker-month = { $month ->
+    [1] January
+    [2] February
+    [3] March
+    [4] April
+    [5] May
+    [6] June
+    [7] July
+    [8] August
+    [9] September
+    [10] October
+    [11] November
+   *[12] December
+}
+egui-extras-datepicker-weekday = { $day ->
+    [1] Mo
+    [2] Tu
+    [3] We
+    [4] Th
+    [5] Fr
+    [6] Sa
+   *[7] Su
+}
+egui-extras-datepicker-week = Week
+egui-extras-datepicker-previous-year = subtract one year
+egui-extras-datepicker-previous-month = subtract one month
+egui-extras-datepicker-previous-day = subtract one day
+egui-extras-datepicker-next-day = add one day
+egui-extras-datepicker-next-month = add one month
+egui-extras-datepicker-next-year = add one year
+egui-extras-datepicker-cancel = Cancel
+egui-extras-datepicker-save = Save
+";
+
 #[derive(Default, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 struct DatePickerPopupState {
@@ -41,6 +82,7 @@ impl DatePickerPopup<'_> {
     /// Returns `true` if user pressed `Save` button.
     pub fn draw(&mut self, ui: &mut Ui) -> bool {
         let id = ui.make_persistent_id("date_picker");
+        add_messages(ui.ctx());
         let today = jiff::Zoned::now().date();
         let mut popup_state = ui
             .data_mut(|data| data.get_persisted::<DatePickerPopupState>(id))
@@ -126,14 +168,14 @@ impl DatePickerPopup<'_> {
                             });
                             strip.cell(|ui| {
                                 ComboBox::from_id_salt("date_picker_month")
-                                    .selected_text(month_name(popup_state.month))
+                                    .selected_text(month_name(ui.ctx(), popup_state.month))
                                     .show_ui(ui, |ui| {
                                         for month in 1i8..=12 {
                                             if ui
                                                 .selectable_value(
                                                     &mut popup_state.month,
                                                     month,
-                                                    month_name(month),
+                                                    month_name(ui.ctx(), month),
                                                 )
                                                 .changed()
                                             {
@@ -180,7 +222,9 @@ impl DatePickerPopup<'_> {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                     if ui
                                         .button("<<<")
-                                        .on_hover_text("subtract one year")
+                                        .on_hover_text(
+                                            ui.ctx().tr("egui-extras-datepicker-previous-year"),
+                                        )
                                         .clicked()
                                     {
                                         popup_state.year -= 1;
@@ -196,7 +240,9 @@ impl DatePickerPopup<'_> {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                     if ui
                                         .button("<<")
-                                        .on_hover_text("subtract one month")
+                                        .on_hover_text(
+                                            ui.ctx().tr("egui-extras-datepicker-previous-month"),
+                                        )
                                         .clicked()
                                     {
                                         popup_state.month -= 1;
@@ -214,7 +260,13 @@ impl DatePickerPopup<'_> {
                             });
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                    if ui.button("<").on_hover_text("subtract one day").clicked() {
+                                    if ui
+                                        .button("<")
+                                        .on_hover_text(
+                                            ui.ctx().tr("egui-extras-datepicker-previous-day"),
+                                        )
+                                        .clicked()
+                                    {
                                         popup_state.day -= 1;
                                         if popup_state.day == 0 {
                                             popup_state.month -= 1;
@@ -232,7 +284,13 @@ impl DatePickerPopup<'_> {
                             });
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                    if ui.button(">").on_hover_text("add one day").clicked() {
+                                    if ui
+                                        .button(">")
+                                        .on_hover_text(
+                                            ui.ctx().tr("egui-extras-datepicker-next-day"),
+                                        )
+                                        .clicked()
+                                    {
                                         popup_state.day += 1;
                                         if popup_state.day > popup_state.last_day_of_month() {
                                             popup_state.day = 1;
@@ -250,7 +308,13 @@ impl DatePickerPopup<'_> {
                             });
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                    if ui.button(">>").on_hover_text("add one month").clicked() {
+                                    if ui
+                                        .button(">>")
+                                        .on_hover_text(
+                                            ui.ctx().tr("egui-extras-datepicker-next-month"),
+                                        )
+                                        .clicked()
+                                    {
                                         popup_state.month += 1;
                                         if popup_state.month > 12 {
                                             popup_state.month = 1;
@@ -266,7 +330,13 @@ impl DatePickerPopup<'_> {
                             });
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                    if ui.button(">>>").on_hover_text("add one year").clicked() {
+                                    if ui
+                                        .button(">>>")
+                                        .on_hover_text(
+                                            ui.ctx().tr("egui-extras-datepicker-next-year"),
+                                        )
+                                        .clicked()
+                                    {
                                         popup_state.year += 1;
                                         popup_state.day =
                                             popup_state.day.min(popup_state.last_day_of_month());
@@ -292,19 +362,23 @@ impl DatePickerPopup<'_> {
                                         ui.with_layout(
                                             Layout::centered_and_justified(Direction::TopDown),
                                             |ui| {
-                                                ui.label("Week");
+                                                ui.label(
+                                                    ui.ctx().tr("egui-extras-datepicker-week"),
+                                                );
                                             },
                                         );
                                     });
                                 }
 
-                                //TODO(elwerene): Locale
-                                for name in ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"] {
+                                for day in 1..=7 {
                                     header.col(|ui| {
                                         ui.with_layout(
                                             Layout::centered_and_justified(Direction::TopDown),
                                             |ui| {
-                                                ui.label(name);
+                                                ui.label(ui.ctx().tr_args(
+                                                    "egui-extras-datepicker-weekday",
+                                                    &MessageArgs::new().with("day", day),
+                                                ));
                                             },
                                         );
                                     });
@@ -403,14 +477,20 @@ impl DatePickerPopup<'_> {
                         strip.empty();
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                if ui.button("Cancel").clicked() {
+                                if ui
+                                    .button(ui.ctx().tr("egui-extras-datepicker-cancel"))
+                                    .clicked()
+                                {
                                     close = true;
                                 }
                             });
                         });
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                if ui.button("Save").clicked() {
+                                if ui
+                                    .button(ui.ctx().tr("egui-extras-datepicker-save"))
+                                    .clicked()
+                                {
                                     *self.selection = Date::new(
                                         popup_state.year,
                                         popup_state.month,
@@ -439,20 +519,19 @@ impl DatePickerPopup<'_> {
     }
 }
 
-fn month_name(i: i8) -> &'static str {
-    match i {
-        1 => "January",
-        2 => "February",
-        3 => "March",
-        4 => "April",
-        5 => "May",
-        6 => "June",
-        7 => "July",
-        8 => "August",
-        9 => "September",
-        10 => "October",
-        11 => "November",
-        12 => "December",
-        _ => panic!("Unknown month: {i}"),
+/// Add the English [`DATE_PICKER_MESSAGES`], unless already added.
+fn add_messages(ctx: &Context) {
+    if !ctx.localization(|l| l.has_message("en", "egui-extras-datepicker-month")) {
+        ctx.add_messages(
+            MessageBundle::parse("en", DATE_PICKER_MESSAGES)
+                .expect("The date picker messages should parse"),
+        );
     }
 }
+
+fn month_name(ctx: &Context, month: i8) -> String {
+    ctx.tr_args(
+        "egui-extras-datepicker-month",
+        &MessageArgs::new().with("month", month),
+    )
+}
@@ -10,6 +10,8 @@
 /// - `http` feature: `http(s)://` loader
 /// - `image` feature: Loader of png, jpeg etc using the [`image`] crate
 /// - `svg` feature: `.svg` loader
+/// - `gif`, `webp` and `apng` features: animated image loaders
+/// - `jxl` feature: `.jxl` loader
 ///
 /// Calling this multiple times on the same [`egui::Context`] is safe.
 /// It will never install duplicate loaders.
@@ -26,6 +28,9 @@
 /// ⚠ You have to configure both the supported loaders in `egui_extras` _and_ the supported image formats
 /// in `image` to get any output!
 ///
+/// `.avif` images can be loaded by the `image` loader if you enable the `avif-native` feature of `image`.
+/// Note that it requires the [`dav1d`](https://code.videolan.org/videolan/dav1d) library to be installed on the system.
+///
 /// ## Loader-specific information
 ///
 /// ⚠ The exact way bytes, images, and textures are loaded is subject to change,
@@ -54,6 +59,17 @@
 /// The content type specified by [`BytesPoll::Ready::mime`][`egui::load::BytesPoll::Ready::mime`] always takes precedence,
 /// and must include `svg` for it to be considered supported. For example, `image/svg+xml` would be loaded by the `svg` loader.
 ///
+/// The `gif`, `webp` and `apng` loaders are [`ImageLoader`][`egui::load::ImageLoader`]s for animated images.
+/// They store the duration of each frame as [`egui::FrameDurations`] in [`egui::Memory`],
+/// which [`egui::Image`] uses to pick the frame to show.
+/// Only URIs ending in `.apng` (or raw bytes containing an animated png) are treated as animated png,
+/// since a `.png` URI may just as well be a still image.
+///
+/// The `jxl` loader is an [`ImageLoader`][`egui::load::ImageLoader`].
+/// It will attempt to load any URI with a `jxl` extension, or without an extension,
+/// and only accepts bytes that start with a JPEG XL signature.
+/// Only the first frame of animated JPEG XL images is shown.
+///
 /// See [`egui::load`] for more information about how loaders work.
 pub fn install_image_loaders(ctx: &egui::Context) {
     #[cfg(all(not(target_arch = "wasm32"), feature = "file"))]
@@ -90,6 +106,18 @@ pub fn install_image_loaders(ctx: &egui::Context) {
         log::trace!("installed WebPLoader");
     }
 
+    #[cfg(feature = "apng")]
+    if !ctx.is_loader_installed(self::apng_loader::ApngLoader::ID) {
+        ctx.add_image_loader(std::sync::Arc::new(self::apng_loader::ApngLoader::default()));
+        log::trace!("installed ApngLoader");
+    }
+
+    #[cfg(feature = "jxl")]
+    if !ctx.is_loader_installed(self::jxl_loader::JxlLoader::ID) {
+        ctx.add_image_loader(std::sync::Arc::new(self::jxl_loader::JxlLoader::default()));
+        log::trace!("installed JxlLoader");
+    }
+
     #[cfg(feature = "svg")]
     if !ctx.is_loader_installed(self::svg_loader::SvgLoader::ID) {
         ctx.add_image_loader(std::sync::Arc::new(self::svg_loader::SvgLoader::default()));
@@ -100,6 +128,7 @@ pub fn install_image_loaders(ctx: &egui::Context) {
         any(target_arch = "wasm32", not(feature = "file")),
         not(feature = "http"),
         not(feature = "image"),
+        not(feature = "jxl"),
         not(feature = "svg")
     ))]
     log::warn!("`install_image_loaders` was called, but no loaders are enabled");
@@ -113,10 +142,16 @@ pub mod file_loader;
 #[cfg(feature = "http")]
 pub mod http_loader;
 
+#[cfg(any(feature = "apng", feature = "gif"))]
+mod animated_image;
+#[cfg(feature = "apng")]
+pub mod apng_loader;
 #[cfg(feature = "gif")]
 pub mod gif_loader;
 #[cfg(feature = "image")]
 pub mod image_loader;
+#[cfg(feature = "jxl")]
+pub mod jxl_loader;
 #[cfg(feature = "svg")]
 pub mod svg_loader;
 #[cfg(feature = "webp")]
@@ -0,0 +1,146 @@
+use ahash::HashMap;
+use egui::{
+    ColorImage, FrameDurations, Id, decode_animated_image_uri,
+    load::{BytesPoll, ImageLoadResult, ImagePoll, LoadError},
+    mutex::Mutex,
+};
+use image::{Frame, ImageResult};
+use std::{mem::size_of, sync::Arc, time::Duration};
+
+/// Array of Frames and the duration for how long each frame should be shown
+#[derive(Debug, Clone)]
+pub struct AnimatedImage {
+    frames: Vec<Arc<ColorImage>>,
+    frame_durations: FrameDurations,
+}
+
+impl AnimatedImage {
+    /// Collects the decoded frames of an animated image.
+    ///
+    /// `format` is only used in error messages.
+    pub(crate) fn from_frames(
+        format: &str,
+        frames: impl IntoIterator<Item = ImageResult<Frame>>,
+    ) -> Result<Self, String> {
+        let mut images = vec![];
+        let mut durations = vec![];
+        for frame in frames {
+            let frame = frame.map_err(|err| format!("Failed to decode {format}: {err}"))?;
+            let img = frame.buffer();
+            let pixels = img.as_flat_samples();
+
+            let delay: Duration = frame.delay().into();
+            images.push(Arc::new(ColorImage::from_rgba_unmultiplied(
+                [img.width() as usize, img.height() as usize],
+                pixels.as_slice(),
+            )));
+            durations.push(delay);
+        }
+        if images.is_empty() {
+            return Err(format!("Failed to decode {format}: no frames"));
+        }
+        Ok(Self {
+            frames: images,
+            frame_durations: FrameDurations::new(durations),
+        })
+    }
+
+    pub fn byte_len(&self) -> usize {
+        size_of::<Self>()
+            + self
+                .frames
+                .iter()
+                .map(|image| {
+                    image.pixels.len() * size_of::<egui::Color32>() + size_of::<Duration>()
+                })
+                .sum::<usize>()
+    }
+
+    /// Number of frames in the animation.
+    pub fn num_frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Gets image at index
+    pub fn get_image(&self, index: usize) -> Arc<ColorImage> {
+        Arc::clone(&self.frames[index % self.frames.len()])
+    }
+}
+
+type Entry = Result<Arc<AnimatedImage>, String>;
+
+/// The cache shared by the loaders of animated images.
+#[derive(Default)]
+pub(crate) struct AnimatedImageCache {
+    cache: Mutex<HashMap<String, Entry>>,
+}
+
+impl AnimatedImageCache {
+    /// Loads the frame encoded in `frame_uri`, decoding the whole animation on first use.
+    ///
+    /// Bytes for which `has_header` returns `false` are left to other loaders.
+    pub(crate) fn load(
+        &self,
+        ctx: &egui::Context,
+        frame_uri: &str,
+        has_header: fn(&[u8]) -> bool,
+        decode: fn(&[u8]) -> Result<AnimatedImage, String>,
+    ) -> ImageLoadResult {
+        let (image_uri, frame_index) =
+            decode_animated_image_uri(frame_uri).map_err(|_err| LoadError::NotSupported)?;
+        let mut cache = self.cache.lock();
+        if let Some(entry) = cache.get(image_uri).cloned() {
+            match entry {
+                Ok(image) => Ok(ImagePoll::Ready {
+                    image: image.get_image(frame_index),
+                }),
+                Err(err) => Err(LoadError::Loading(err)),
+            }
+        } else {
+            match ctx.try_load_bytes(image_uri) {
+                Ok(BytesPoll::Ready { bytes, .. }) => {
+                    if !has_header(&bytes) {
+                        return Err(LoadError::NotSupported);
+                    }
+                    log::trace!("started loading {image_uri:?}");
+                    let result = decode(&bytes).map(Arc::new);
+                    if let Ok(v) = &result {
+                        ctx.data_mut(|data| {
+                            *data.get_temp_mut_or_default(Id::new(image_uri)) =
+                                v.frame_durations.clone();
+                        });
+                    }
+                    log::trace!("finished loading {image_uri:?}");
+                    cache.insert(image_uri.into(), result.clone());
+                    match result {
+                        Ok(image) => Ok(ImagePoll::Ready {
+                            image: image.get_image(frame_index),
+                        }),
+                        Err(err) => Err(LoadError::Loading(err)),
+                    }
+                }
+                Ok(BytesPoll::Pending { size }) => Ok(ImagePoll::Pending { size }),
+                Err(err) => Err(err),
+            }
+        }
+    }
+
+    pub(crate) fn forget(&self, uri: &str) {
+        let _ = self.cache.lock().remove(uri);
+    }
+
+    pub(crate) fn forget_all(&self) {
+        self.cache.lock().clear();
+    }
+
+    pub(crate) fn byte_size(&self) -> usize {
+        self.cache
+            .lock()
+            .values()
+            .map(|v| match v {
+                Ok(v) => v.byte_len(),
+                Err(e) => e.len(),
+            })
+            .sum()
+    }
+}
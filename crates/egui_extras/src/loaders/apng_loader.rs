@@ -0,0 +1,97 @@
+use egui::{
+    has_apng_header,
+    load::{ImageLoadResult, ImageLoader, SizeHint},
+};
+use image::{AnimationDecoder as _, codecs::png::PngDecoder};
+use std::io::Cursor;
+
+pub use super::animated_image::AnimatedImage;
+use super::animated_image::AnimatedImageCache;
+
+fn load_apng(data: &[u8]) -> Result<AnimatedImage, String> {
+    let decoder = PngDecoder::new(Cursor::new(data))
+        .map_err(|err| format!("Failed to decode apng: {err}"))?;
+    let decoder = decoder
+        .apng()
+        .map_err(|err| format!("Failed to decode apng: {err}"))?;
+    AnimatedImage::from_frames("apng", decoder.into_frames())
+}
+
+/// Loads animated png images (APNG).
+///
+/// Still png images are left to the [`super::image_loader::ImageCrateLoader`].
+#[derive(Default)]
+pub struct ApngLoader {
+    cache: AnimatedImageCache,
+}
+
+impl ApngLoader {
+    pub const ID: &'static str = egui::generate_loader_id!(ApngLoader);
+}
+
+impl ImageLoader for ApngLoader {
+    fn id(&self) -> &str {
+        Self::ID
+    }
+
+    fn load(&self, ctx: &egui::Context, frame_uri: &str, _: SizeHint) -> ImageLoadResult {
+        self.cache.load(ctx, frame_uri, has_apng_header, load_apng)
+    }
+
+    fn forget(&self, uri: &str) {
+        self.cache.forget(uri);
+    }
+
+    fn forget_all(&self) {
+        self.cache.forget_all();
+    }
+
+    fn byte_size(&self) -> usize {
+        self.cache.byte_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 2x1 animated png with a red and a blue frame, 100 ms each.
+    const TWO_FRAMES: &[u8] = &[
+        0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44,
+        0x52, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0xf4,
+        0x22, 0x7f, 0x8a, 0x00, 0x00, 0x00, 0x08, 0x61, 0x63, 0x54, 0x4c, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0xf3, 0x8d, 0x93, 0x70, 0x00, 0x00, 0x00, 0x1a, 0x66, 0x63, 0x54,
+        0x4c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0xf9, 0x29, 0xb6,
+        0x79, 0x00, 0x00, 0x00, 0x0e, 0x49, 0x44, 0x41, 0x54, 0x78, 0xda, 0x63, 0xf8, 0xcf, 0xc0,
+        0xf0, 0x1f, 0x84, 0x01, 0x11, 0xf7, 0x03, 0xfd, 0xfe, 0xad, 0xbb, 0x99, 0x00, 0x00, 0x00,
+        0x1a, 0x66, 0x63, 0x54, 0x4c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x0a, 0x00,
+        0x00, 0x62, 0x5a, 0x5c, 0xad, 0x00, 0x00, 0x00, 0x12, 0x66, 0x64, 0x41, 0x54, 0x00, 0x00,
+        0x00, 0x02, 0x78, 0xda, 0x63, 0x60, 0x60, 0xf8, 0xff, 0x1f, 0x84, 0x01, 0x0d, 0xfb, 0x03,
+        0xfd, 0xb3, 0xfc, 0x5e, 0xc7, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42,
+        0x60, 0x82,
+    ];
+
+    #[test]
+    fn decode_fixture() {
+        assert!(
+            has_apng_header(TWO_FRAMES),
+            "fixture should be an animated png"
+        );
+
+        let image = load_apng(TWO_FRAMES).expect("fixture should decode");
+        assert_eq!(image.num_frames(), 2, "both frames should be decoded");
+
+        let first = image.get_image(0);
+        let second = image.get_image(1);
+        assert_eq!(first.size, [2, 1], "unexpected frame size");
+        assert_eq!(second.size, [2, 1], "unexpected frame size");
+        assert_eq!(first.pixels[0], egui::Color32::RED, "first frame is red");
+        assert_eq!(
+            second.pixels[0],
+            egui::Color32::BLUE,
+            "second frame is blue"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use ahash::HashMap;
+use egui::{
+    Color32, ColorImage,
+    load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, SizeHint},
+    mutex::Mutex,
+};
+use jxl_oxide::JxlImage;
+use std::{mem::size_of, path::Path, sync::Arc};
+
+type Entry = Result<Arc<ColorImage>, String>;
+
+/// Loads JPEG XL images using [`jxl_oxide`].
+///
+/// Only the first frame of animated JPEG XL images is shown.
+#[derive(Default)]
+pub struct JxlLoader {
+    cache: Mutex<HashMap<String, Entry>>,
+}
+
+impl JxlLoader {
+    pub const ID: &'static str = egui::generate_loader_id!(JxlLoader);
+}
+
+fn is_supported_uri(uri: &str) -> bool {
+    let Some(ext) = Path::new(uri)
+        .extension()
+        .and_then(|ext| ext.to_str().map(|ext| ext.to_lowercase()))
+    else {
+        // `true` because if there's no extension, assume that we support it
+        return true;
+    };
+
+    ext == "jxl"
+}
+
+/// Checks if bytes are a JPEG XL image, either a bare codestream or an ISOBMFF container.
+fn has_jxl_header(bytes: &[u8]) -> bool {
+    const CODESTREAM: &[u8] = &[0xFF, 0x0A];
+    const CONTAINER: &[u8] = &[
+        0x00, 0x00, 0x00, 0x0C, b'J', b'X', b'L', b' ', 0x0D, 0x0A, 0x87, 0x0A,
+    ];
+    bytes.starts_with(CODESTREAM) || bytes.starts_with(CONTAINER)
+}
+
+fn load_jxl(data: &[u8]) -> Result<ColorImage, String> {
+    let image = JxlImage::builder()
+        .read(data)
+        .map_err(|err| format!("Failed to decode jxl: {err}"))?;
+    let render = image
+        .render_frame(0)
+        .map_err(|err| format!("Failed to render jxl: {err}"))?;
+
+    let mut stream = render.stream();
+    let size = [stream.width() as usize, stream.height() as usize];
+    let channels = stream.channels() as usize;
+    let mut buffer = vec![0_u8; size[0] * size[1] * channels];
+    stream.write_to_buffer(&mut buffer);
+
+    let pixels = match channels {
+        1 => buffer.iter().map(|&l| Color32::from_gray(l)).collect(),
+        2 => buffer
+            .chunks_exact(2)
+            .map(|p| Color32::from_rgba_unmultiplied(p[0], p[0], p[0], p[1]))
+            .collect(),
+        3 => buffer
+            .chunks_exact(3)
+            .map(|p| Color32::from_rgb(p[0], p[1], p[2]))
+            .collect(),
+        4 => buffer
+            .chunks_exact(4)
+            .map(|p| Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3]))
+            .collect(),
+        _ => return Err(format!("Unsupported jxl channel count: {channels}")),
+    };
+
+    Ok(ColorImage::new(size, pixels))
+}
+
+impl ImageLoader for JxlLoader {
+    fn id(&self) -> &str {
+        Self::ID
+    }
+
+    fn load(&self, ctx: &egui::Context, uri: &str, _: SizeHint) -> ImageLoadResult {
+        if !is_supported_uri(uri) {
+            return Err(LoadError::NotSupported);
+        }
+
+        let mut cache = self.cache.lock();
+        if let Some(entry) = cache.get(uri).cloned() {
+            match entry {
+                Ok(image) => Ok(ImagePoll::Ready { image }),
+                Err(err) => Err(LoadError::Loading(err)),
+            }
+        } else {
+            match ctx.try_load_bytes(uri) {
+                Ok(BytesPoll::Ready { bytes, .. }) => {
+                    if !has_jxl_header(&bytes) {
+                        return Err(LoadError::NotSupported);
+                    }
+                    log::trace!("started loading {uri:?}");
+                    let result = load_jxl(&bytes).map(Arc::new);
+                    log::trace!("finished loading {uri:?}");
+                    cache.insert(uri.into(), result.clone());
+                    match result {
+                        Ok(image) => Ok(ImagePoll::Ready { image }),
+                        Err(err) => Err(LoadError::Loading(err)),
+                    }
+                }
+                Ok(BytesPoll::Pending { size }) => Ok(ImagePoll::Pending { size }),
+                Err(err) => Err(err),
+            }
+        }
+    }
+
+    fn forget(&self, uri: &str) {
+        let _ = self.cache.lock().remove(uri);
+    }
+
+    fn forget_all(&self) {
+        self.cache.lock().clear();
+    }
+
+    fn byte_size(&self) -> usize {
+        self.cache
+            .lock()
+            .values()
+            .map(|entry| match entry {
+                Ok(image) => image.pixels.len() * size_of::<Color32>(),
+                Err(err) => err.len(),
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A tiny JPEG XL codestream, taken from the `jxl-oxide` documentation.
+    const SAMPLE: &[u8] = &[
+        0xff, 0x0a, 0x30, 0x54, 0x10, 0x09, 0x08, 0x06, 0x01, 0x00, 0x78, 0x00, 0x4b, 0x38, 0x41,
+        0x3c, 0xb6, 0x3a, 0x51, 0xfe, 0x00, 0x47, 0x1e, 0xa0, 0x85, 0xb8, 0x27, 0x1a, 0x48, 0x45,
+        0x84, 0x1b, 0x71, 0x4f, 0xa8, 0x3e, 0x8e, 0x30, 0x03, 0x92, 0x84, 0x01,
+    ];
+
+    #[test]
+    fn check_support() {
+        assert!(is_supported_uri("https://test.jxl"));
+        assert!(is_supported_uri("file://test.JXL"));
+        assert!(is_supported_uri("bytes://test"));
+        assert!(!is_supported_uri("test.png"));
+
+        assert!(has_jxl_header(&[0xFF, 0x0A, 0x30]));
+        assert!(!has_jxl_header(b"\x89PNG"));
+    }
+
+    #[test]
+    fn decode_fixture() {
+        assert!(has_jxl_header(SAMPLE), "fixture should be a jxl codestream");
+
+        let image = load_jxl(SAMPLE).expect("fixture should decode");
+        assert_eq!(image.size, [240, 135], "unexpected image size");
+        assert_eq!(
+            image.pixels.len(),
+            image.size[0] * image.size[1],
+            "one pixel per texel"
+        );
+    }
+}
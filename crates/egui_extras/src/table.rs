@@ -178,7 +178,7 @@ fn to_sizing(columns: &[Column]) -> crate::sizing::Sizing {
 
 // -----------------------------------------------------------------=----------
 
-struct TableScrollOptions {
+struct TableScrollOptions<'a> {
     vscroll: bool,
     drag_to_scroll: bool,
     stick_to_bottom: bool,
@@ -189,9 +189,11 @@ struct TableScrollOptions {
     auto_shrink: Vec2b,
     scroll_bar_visibility: ScrollBarVisibility,
     animated: bool,
+    scroll_anchoring: bool,
+    row_key: Option<Box<dyn Fn(usize) -> Id + 'a>>,
 }
 
-impl Default for TableScrollOptions {
+impl Default for TableScrollOptions<'_> {
     fn default() -> Self {
         Self {
             vscroll: true,
@@ -204,6 +206,8 @@ impl Default for TableScrollOptions {
             auto_shrink: Vec2b::TRUE,
             scroll_bar_visibility: ScrollBarVisibility::VisibleWhenNeeded,
             animated: true,
+            scroll_anchoring: false,
+            row_key: None,
         }
     }
 }
@@ -251,7 +255,7 @@ pub struct TableBuilder<'a> {
     striped: Option<bool>,
     resizable: bool,
     cell_layout: egui::Layout,
-    scroll_options: TableScrollOptions,
+    scroll_options: TableScrollOptions<'a>,
     sense: egui::Sense,
 }
 
@@ -420,6 +424,31 @@ impl<'a> TableBuilder<'a> {
         self
     }
 
+    /// Keep the rows the user is looking at in place when rows above them change height,
+    /// or when rows are inserted or removed above them.
+    ///
+    /// Rows are identified by their index, unless you set a key with [`Self::row_key`],
+    /// which you need to keep rows in place when rows are inserted or removed.
+    ///
+    /// See [`ScrollArea::scroll_anchoring`] for more.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn scroll_anchoring(mut self, scroll_anchoring: bool) -> Self {
+        self.scroll_options.scroll_anchoring = scroll_anchoring;
+        self
+    }
+
+    /// Identify rows by a key, for [`Self::scroll_anchoring`].
+    ///
+    /// The key of a row should stay the same when rows are inserted or removed,
+    /// e.g. the id of the item shown in the row.
+    #[inline]
+    pub fn row_key<K: std::hash::Hash>(mut self, row_key: impl Fn(usize) -> K + 'a) -> Self {
+        self.scroll_options.row_key = Some(Box::new(move |row_index| Id::new(row_key(row_index))));
+        self
+    }
+
     /// What layout should we use for the individual cells?
     #[inline]
     pub fn cell_layout(mut self, cell_layout: egui::Layout) -> Self {
@@ -693,7 +722,7 @@ pub struct Table<'a> {
     striped: bool,
     cell_layout: egui::Layout,
 
-    scroll_options: TableScrollOptions,
+    scroll_options: TableScrollOptions<'a>,
 
     sense: egui::Sense,
 }
@@ -738,6 +767,8 @@ impl Table<'_> {
             auto_shrink,
             scroll_bar_visibility,
             animated,
+            scroll_anchoring,
+            row_key,
         } = scroll_options;
 
         let cursor_position = ui.cursor().min;
@@ -753,12 +784,21 @@ impl Table<'_> {
             .max_height(max_scroll_height)
             .auto_shrink(auto_shrink)
             .scroll_bar_visibility(scroll_bar_visibility)
-            .animated(animated);
+            .animated(animated)
+            .scroll_anchoring(scroll_anchoring);
 
         if let Some(scroll_offset_y) = scroll_offset_y {
             scroll_area = scroll_area.vertical_scroll_offset(scroll_offset_y);
         }
 
+        let row_key = row_key.unwrap_or_else(|| Box::new(Id::new));
+        let scroll_anchor = scroll_anchoring.then(|| {
+            // Same as the id of the `ScrollArea`, which hashes its id salt:
+            let scroll_area_id = ui.make_persistent_id(Id::new(state_id.with("__scroll_area")));
+            egui::scroll_area::State::load(ui.ctx(), scroll_area_id)?.scroll_anchor()
+        });
+        let row_key_ref = &row_key;
+
         let columns_ref = &columns;
         let widths_ref = &state.column_widths;
         let max_used_widths_ref = &mut max_used_widths;
@@ -791,6 +831,8 @@ impl Table<'_> {
                     scroll_to_y_range: &mut scroll_to_y_range,
                     hovered_row_index,
                     hovered_row_index_id,
+                    scroll_anchor: scroll_anchor.map(|anchor| (row_key_ref.as_ref(), anchor)),
+                    anchor_row_index_id: self.state_id.with("__table_anchor_row"),
                 });
 
                 if scroll_to_row.is_some() && scroll_to_y_range.is_none() {
@@ -945,8 +987,20 @@ pub struct TableBody<'a> {
 
     /// Used to store the hovered row index between frames.
     hovered_row_index_id: egui::Id,
+
+    /// If [`TableBuilder::scroll_anchoring`] is on: the key of each row,
+    /// and the key of the row the scroll area is anchored to.
+    scroll_anchor: Option<(&'a dyn Fn(usize) -> Id, Option<Id>)>,
+
+    /// Used to store the index of the anchor row between frames,
+    /// so that [`Self::rows`] can look for it nearby.
+    anchor_row_index_id: egui::Id,
 }
 
+/// How many rows [`TableBody::rows`] looks through, in each direction,
+/// for the anchor row when it isn't shown.
+const ANCHOR_SEARCH_RADIUS: usize = 1000;
+
 impl<'a> TableBody<'a> {
     /// Access the contained [`egui::Ui`].
     ///
@@ -1002,6 +1056,7 @@ impl<'a> TableBody<'a> {
         if Some(self.row_index) == self.scroll_to_row {
             *self.scroll_to_y_range = Some(Rangef::new(top_y, bottom_y));
         }
+        self.register_scroll_anchor(self.row_index, Rangef::new(top_y, bottom_y), true);
 
         self.row_index += 1;
     }
@@ -1038,6 +1093,7 @@ impl<'a> TableBody<'a> {
     ) {
         let spacing = self.layout.ui.spacing().item_spacing;
         let row_height_with_spacing = row_height_sans_spacing + spacing.y;
+        let rows_top = self.layout.cursor.y;
 
         if let Some(scroll_to_row) = self.scroll_to_row {
             let scroll_to_row = scroll_to_row.at_most(total_rows.saturating_sub(1)) as f32;
@@ -1063,6 +1119,7 @@ impl<'a> TableBody<'a> {
         let max_row = max_row.min(total_rows);
 
         for row_index in min_row..max_row {
+            let top_y = self.layout.cursor.y;
             let mut response: Option<Response> = None;
             add_row_content(TableRow {
                 layout: &mut self.layout,
@@ -1079,6 +1136,18 @@ impl<'a> TableBody<'a> {
                 response: &mut response,
             });
             self.capture_hover_state(response.as_ref(), row_index);
+            let y_range = Rangef::new(top_y, top_y + row_height_sans_spacing);
+            self.register_scroll_anchor(row_index, y_range, true);
+        }
+
+        if let Some((row_key, Some(anchor))) = self.scroll_anchor
+            && let Some(row_index) = self.find_anchor_row(row_key, anchor, total_rows)
+            && !(min_row..max_row).contains(&row_index)
+        {
+            // The anchor row isn't shown, but the scroll area needs to know where it is:
+            let top_y = rows_top + row_index as f32 * row_height_with_spacing;
+            let y_range = Rangef::new(top_y, top_y + row_height_sans_spacing);
+            self.register_scroll_anchor(row_index, y_range, false);
         }
 
         if total_rows - max_row > 0 {
@@ -1142,6 +1211,9 @@ impl<'a> TableBody<'a> {
                 ));
             }
 
+            let top_y = (scroll_to_y_range_offset + old_cursor_y) as f32;
+            let y_range = Rangef::new(top_y, top_y + row_height);
+
             if cursor_y >= scroll_offset_y {
                 // This row is visible:
                 self.add_buffer(old_cursor_y as f32); // skip all the invisible rows
@@ -1161,8 +1233,10 @@ impl<'a> TableBody<'a> {
                     response: &mut response,
                 });
                 self.capture_hover_state(response.as_ref(), row_index);
+                self.register_scroll_anchor(row_index, y_range, true);
                 break;
             }
+            self.register_scroll_anchor(row_index, y_range, false);
         }
 
         // populate visible rows:
@@ -1192,6 +1266,8 @@ impl<'a> TableBody<'a> {
                     (scroll_to_y_range_offset + cursor_y) as f32,
                 ));
             }
+            let top_y = (scroll_to_y_range_offset + top_y) as f32;
+            self.register_scroll_anchor(row_index, Rangef::new(top_y, top_y + row_height), true);
 
             if cursor_y > scroll_offset_y + max_height as f64 {
                 break;
@@ -1211,6 +1287,8 @@ impl<'a> TableBody<'a> {
                     (scroll_to_y_range_offset + cursor_y) as f32,
                 ));
             }
+            let top_y = (scroll_to_y_range_offset + top_y) as f32;
+            self.register_scroll_anchor(row_index, Rangef::new(top_y, top_y + row_height), false);
         }
 
         if self.scroll_to_row.is_some() && self.scroll_to_y_range.is_none() {
@@ -1226,6 +1304,49 @@ impl<'a> TableBody<'a> {
         }
     }
 
+    // Look for the anchor row near where it was last frame, without calling `row_key` for every row.
+    // The anchor is lost if it moved further than that, e.g. when many rows were inserted above it.
+    fn find_anchor_row(
+        &self,
+        row_key: &dyn Fn(usize) -> Id,
+        anchor: Id,
+        total_rows: usize,
+    ) -> Option<usize> {
+        let last_row_index = self
+            .layout
+            .ui
+            .data(|data| data.get_temp::<usize>(self.anchor_row_index_id))?
+            .min(total_rows.checked_sub(1)?);
+        (0..=ANCHOR_SEARCH_RADIUS)
+            .flat_map(|distance| {
+                let below = last_row_index.checked_add(distance);
+                let above = (distance > 0)
+                    .then(|| last_row_index.checked_sub(distance))
+                    .flatten();
+                below.into_iter().chain(above)
+            })
+            .filter(|&row_index| row_index < total_rows)
+            .find(|&row_index| row_key(row_index) == anchor)
+    }
+
+    // Tell the scroll area where a row is, for `TableBuilder::scroll_anchoring`.
+    // Rows that aren't visible are only registered if they are the current anchor.
+    fn register_scroll_anchor(&self, row_index: usize, y_range: Rangef, is_visible: bool) {
+        let Some((row_key, anchor)) = self.scroll_anchor else {
+            return;
+        };
+        let key = row_key(row_index);
+        if Some(key) == anchor {
+            self.layout
+                .ui
+                .data_mut(|data| data.insert_temp(self.anchor_row_index_id, row_index));
+        }
+        if is_visible || Some(key) == anchor {
+            let rect = Rect::from_x_y_ranges(self.layout.rect.x_range(), y_range);
+            self.layout.ui.scroll_anchor(key, rect);
+        }
+    }
+
     // Create a table row buffer of the given height to represent the non-visible portion of the
     // table.
     fn add_buffer(&mut self, height: f32) {
@@ -15,6 +15,7 @@ pub struct HarnessBuilder<State = ()> {
     pub(crate) state: PhantomData<State>,
     pub(crate) renderer: Box<dyn TestRenderer>,
     pub(crate) wait_for_pending_images: bool,
+    pub(crate) embed_viewports: bool,
 
     #[cfg(feature = "snapshot")]
     pub(crate) default_snapshot_options: crate::SnapshotOptions,
@@ -34,6 +35,7 @@ impl<State> Default for HarnessBuilder<State> {
             max_steps: 4,
             step_dt: 1.0 / 4.0,
             wait_for_pending_images: true,
+            embed_viewports: true,
             os: egui::os::OperatingSystem::Nix,
 
             #[cfg(feature = "snapshot")]
@@ -125,6 +127,22 @@ impl<State> HarnessBuilder<State> {
         self
     }
 
+    /// Should viewports be shown as [`egui::Window`]s inside the root viewport?
+    ///
+    /// If `false`, the [`Harness`] simulates a backend with support for multiple viewports:
+    /// it runs the immediate and deferred viewports shown with
+    /// [`egui::Context::show_viewport_immediate`] and [`egui::Context::show_viewport_deferred`],
+    /// applies the [`egui::ViewportCommand`]s sent to them,
+    /// and lets you query, interact with and snapshot each viewport separately.
+    /// See [`Harness::viewport`].
+    ///
+    /// Default: `true` (see [`egui::Context::set_embed_viewports`]).
+    #[inline]
+    pub fn with_embed_viewports(mut self, embed_viewports: bool) -> Self {
+        self.embed_viewports = embed_viewports;
+        self
+    }
+
     /// Configures the [`egui_wgpu::RendererOptions`] used by this harness.
     ///
     /// The default is [`egui_wgpu::RendererOptions::PREDICTABLE`].
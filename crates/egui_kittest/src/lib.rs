@@ -7,38 +7,48 @@
 mod builder;
 #[cfg(feature = "snapshot")]
 mod snapshot;
+#[cfg(feature = "snapshot")]
+mod snapshot_report;
 
 #[cfg(feature = "snapshot")]
-pub use crate::snapshot::*;
+pub use crate::{snapshot::*, snapshot_report::*};
 
 mod app_kind;
 mod config;
+mod monkey;
 mod node;
 mod renderer;
 #[cfg(feature = "wgpu")]
 mod texture_to_image;
+mod viewports;
 #[cfg(feature = "wgpu")]
 pub mod wgpu;
 
 // re-exports:
 pub use {
-    self::{builder::*, node::*, renderer::*},
+    self::{builder::*, monkey::*, node::*, renderer::*},
     kittest,
 };
 
 use std::{
+    cell::RefCell,
     fmt::{Debug, Display, Formatter},
+    rc::Rc,
     time::Duration,
 };
 
 use egui::{
-    Color32, Key, Modifiers, PointerButton, Pos2, Rect, RepaintCause, Shape, Vec2, ViewportId,
+    Color32, Key, Modifiers, OrderedViewportIdMap, PointerButton, Pos2, Rect, RepaintCause, Shape,
+    Vec2, ViewportId,
     epaint::{ClippedShape, RectShape},
     style::ScrollAnimation,
 };
 use kittest::Queryable;
 
-use crate::app_kind::AppKind;
+use crate::{
+    app_kind::AppKind,
+    viewports::{ChildViewport, ImmediateViewports},
+};
 
 #[derive(Debug, Clone)]
 pub struct ExceededMaxStepsError {
@@ -83,6 +93,17 @@ pub struct Harness<'a, State = ()> {
     wait_for_pending_images: bool,
     queued_events: EventQueue,
 
+    /// The virtual clock: the [`egui::RawInput::time`] of the last frame.
+    time: f64,
+
+    /// The viewports other than the root viewport, if they are not embedded.
+    viewports: OrderedViewportIdMap<ChildViewport>,
+    immediate_viewports: Rc<RefCell<ImmediateViewports>>,
+
+    /// Did any viewport get a [`egui::ViewportEvent`] in the last step?
+    /// Like a native backend, we then run another step.
+    had_viewport_events: bool,
+
     #[cfg(feature = "snapshot")]
     default_snapshot_options: SnapshotOptions,
     #[cfg(feature = "snapshot")]
@@ -111,8 +132,9 @@ impl<'a, State> Harness<'a, State> {
             max_steps,
             step_dt,
             state: _,
-            mut renderer,
+            renderer,
             wait_for_pending_images,
+            embed_viewports,
 
             #[cfg(feature = "snapshot")]
             default_snapshot_options,
@@ -126,6 +148,7 @@ impl<'a, State> Harness<'a, State> {
         ctx.set_theme(theme);
         ctx.set_os(os);
         ctx.enable_accesskit();
+        ctx.set_embed_viewports(embed_viewports);
         ctx.all_styles_mut(|style| {
             // Disable cursor blinking so it doesn't interfere with snapshots
             style.visuals.text_cursor.blink = false;
@@ -134,11 +157,17 @@ impl<'a, State> Harness<'a, State> {
         });
         let mut input = egui::RawInput {
             screen_rect: Some(screen_rect),
+            time: Some(0.0),
             ..Default::default()
         };
         let viewport = input.viewports.get_mut(&ViewportId::ROOT).unwrap();
         viewport.native_pixels_per_point = Some(pixels_per_point);
 
+        let immediate_viewports = Rc::new(RefCell::new(ImmediateViewports::default()));
+        if !embed_viewports {
+            ImmediateViewports::install(&immediate_viewports);
+        }
+
         let mut response = None;
 
         // We need to run egui for a single frame so that the AccessKit state can be initialized
@@ -147,8 +176,6 @@ impl<'a, State> Harness<'a, State> {
             response = app.run(ui, &mut state, false);
         });
 
-        renderer.handle_delta(&output.textures_delta);
-
         let mut harness = Self {
             app,
             ctx,
@@ -168,6 +195,10 @@ impl<'a, State> Harness<'a, State> {
             step_dt,
             wait_for_pending_images,
             queued_events: Default::default(),
+            time: 0.0,
+            viewports: Default::default(),
+            immediate_viewports,
+            had_viewport_events: false,
 
             #[cfg(feature = "snapshot")]
             default_snapshot_options,
@@ -175,6 +206,16 @@ impl<'a, State> Harness<'a, State> {
             #[cfg(feature = "snapshot")]
             snapshot_results: SnapshotResults::default(),
         };
+        if !embed_viewports {
+            harness.collect_immediate_viewports();
+        }
+        harness
+            .renderer
+            .handle_delta(&harness.output.textures_delta);
+        if !embed_viewports {
+            harness.end_viewports_pass();
+        }
+
         // Run the harness until it is stable, ensuring that all Areas are shown and animations are done
         harness.run_ok();
         harness
@@ -273,19 +314,21 @@ impl<'a, State> Harness<'a, State> {
     /// Run a frame for each queued event (or a single frame if there are no events).
     /// This will call the app closure with each queued event and
     /// update the Harness.
+    ///
+    /// Each viewport processes at most one of its queued events per frame.
     pub fn step(&mut self) {
         let events = std::mem::take(&mut *self.queued_events.lock());
-        if events.is_empty() {
-            self._step(false);
-        }
-        for event in events {
-            match event {
-                EventType::Event(event) => {
+        let num_steps = events.len().max(self.take_viewport_events()).max(1);
+        let mut events = events.into_iter();
+        for _ in 0..num_steps {
+            match events.next() {
+                Some(EventType::Event(event)) => {
                     self.input.events.push(event);
                 }
-                EventType::Modifiers(modifiers) => {
+                Some(EventType::Modifiers(modifiers)) => {
                     self.input.modifiers = modifiers;
                 }
+                None => {}
             }
             self._step(false);
         }
@@ -294,6 +337,13 @@ impl<'a, State> Harness<'a, State> {
     /// Run a single step. This will not process any events.
     fn _step(&mut self, sizing_pass: bool) {
         self.input.predicted_dt = self.step_dt;
+        self.time += f64::from(self.step_dt);
+        self.input.time = Some(self.time);
+
+        let embed_viewports = self.ctx.embed_viewports();
+        if !embed_viewports {
+            self.begin_viewports_pass();
+        }
 
         let mut output = self.ctx.run_ui(self.input.take(), |ui| {
             self.response = self.app.run(ui, &mut self.state, sizing_pass);
@@ -305,8 +355,15 @@ impl<'a, State> Harness<'a, State> {
                 .take()
                 .expect("AccessKit was disabled"),
         );
+        if !embed_viewports {
+            self.collect_immediate_viewports();
+        }
         self.renderer.handle_delta(&output.textures_delta);
         self.output = output;
+
+        if !embed_viewports {
+            self.end_viewports_pass();
+        }
     }
 
     /// Calculate the rect that includes all popups and tooltips.
@@ -380,7 +437,9 @@ impl<'a, State> Harness<'a, State> {
             let wait_for_images = self.wait_for_pending_images && self.ctx.has_pending_images();
 
             // We only care about immediate repaints
-            if self.root_viewport_output().repaint_delay != Duration::ZERO && !wait_for_images {
+            let needs_repaint = self.root_viewport_output().repaint_delay == Duration::ZERO
+                || self.viewports_need_repaint();
+            if !needs_repaint && !wait_for_images {
                 break;
             } else if sleep || wait_for_images {
                 std::thread::sleep(Duration::from_secs_f32(self.step_dt));
@@ -463,6 +522,184 @@ impl<'a, State> Harness<'a, State> {
         }
     }
 
+    /// The time of the virtual clock, in seconds.
+    ///
+    /// This is the [`egui::RawInput::time`] of the last frame.
+    /// Each [`Self::step`] advances the clock by [`HarnessBuilder::with_step_dt`].
+    /// The clock doesn't depend on the wall clock, so tests involving time are deterministic.
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Let `duration` pass, then run a frame.
+    ///
+    /// Use this to fast-forward to e.g. the end of an animation or the hover delay of a tooltip.
+    /// The first frame of this [`Self::step`] happens `duration` after the last frame.
+    pub fn advance_time(&mut self, duration: Duration) {
+        self.time += duration.as_secs_f64() - f64::from(self.step_dt);
+        self.step();
+    }
+
+    /// How long until the ui wants to be repainted, according to the last frame.
+    ///
+    /// Returns `None` if no repaint is scheduled (see [`egui::Context::request_repaint_after`]).
+    pub fn next_repaint(&self) -> Option<Duration> {
+        std::iter::once(self.root_viewport_output().repaint_delay)
+            .chain(self.viewport_repaint_delays())
+            .min()
+            .filter(|delay| *delay != Duration::MAX)
+    }
+
+    /// Advance the clock to the next scheduled repaint and run a frame.
+    ///
+    /// Returns how far the clock was advanced, or `None` if no repaint is scheduled,
+    /// in which case no frame is run.
+    pub fn skip_to_next_repaint(&mut self) -> Option<Duration> {
+        let delay = self.next_repaint()?;
+        self.advance_time(delay);
+        Some(delay)
+    }
+
+    /// Run frames until `predicate` returns `true`, skipping ahead to each scheduled repaint.
+    ///
+    /// Returns the number of frames that were run.
+    ///
+    /// # Errors
+    /// Returns an error if `predicate` is still `false` after
+    /// [`HarnessBuilder::with_max_steps`] frames.
+    pub fn try_run_until(
+        &mut self,
+        mut predicate: impl FnMut(&Self) -> bool,
+    ) -> Result<u64, ExceededMaxStepsError> {
+        let mut steps = 0;
+        while !predicate(self) {
+            if steps >= self.max_steps {
+                return Err(ExceededMaxStepsError {
+                    max_steps: self.max_steps,
+                    repaint_causes: self.ctx.repaint_causes(),
+                });
+            }
+            steps += 1;
+
+            let step_dt = Duration::from_secs_f32(self.step_dt);
+            match self.next_repaint() {
+                Some(delay) if step_dt < delay => self.advance_time(delay),
+                _ => self.step(),
+            }
+        }
+        Ok(steps)
+    }
+
+    /// Run frames until `predicate` returns `true`, skipping ahead to each scheduled repaint.
+    ///
+    /// Returns the number of frames that were run.
+    ///
+    /// # Panics
+    /// Panics if `predicate` is still `false` after [`HarnessBuilder::with_max_steps`] frames.
+    #[track_caller]
+    pub fn run_until(&mut self, predicate: impl FnMut(&Self) -> bool) -> u64 {
+        match self.try_run_until(predicate) {
+            Ok(steps) => steps,
+            Err(err) => {
+                panic!("{err}");
+            }
+        }
+    }
+
+    /// Is an animation (or anything else) asking for an immediate repaint?
+    pub fn is_animating(&self) -> bool {
+        self.next_repaint() == Some(Duration::ZERO)
+    }
+
+    /// Assert that no animation is running, i.e. that no immediate repaint was requested.
+    ///
+    /// # Panics
+    /// Panics, listing the causes of the repaint, if [`Self::is_animating`].
+    #[track_caller]
+    pub fn assert_no_pending_animations(&self) {
+        assert!(
+            !self.is_animating(),
+            "Expected no pending animations, but a repaint was requested.\nRepaint causes: {:#?}",
+            self.ctx.repaint_causes()
+        );
+    }
+
+    /// Replay a recording made with [`egui::input_recording::InputRecorder`],
+    /// running one step per recorded frame.
+    ///
+    /// The clock of the harness follows the recorded timestamps.
+    /// Only frames of the root viewport are replayed.
+    pub fn replay(&mut self, recording: &egui::input_recording::InputRecording) {
+        self.replay_with(recording, |_, _| {});
+    }
+
+    /// Like [`Self::replay`], but calls `on_marker` after every frame that was marked with
+    /// [`egui::input_recording::InputRecorder::mark`].
+    pub fn replay_with(
+        &mut self,
+        recording: &egui::input_recording::InputRecording,
+        mut on_marker: impl FnMut(&mut Self, &str),
+    ) {
+        let mut start = None;
+        for (input, markers) in recording.inputs() {
+            if input.viewport_id != ViewportId::ROOT {
+                continue;
+            }
+            if let Some(time) = input.time {
+                // The first frame happens one step after the last one, like with `Self::step`:
+                let (recording_start, harness_start) =
+                    *start.get_or_insert_with(|| (time, self.time + f64::from(self.step_dt)));
+                // `_step` adds `step_dt`, so subtract it to land on the recorded time:
+                self.time = harness_start + (time - recording_start) - f64::from(self.step_dt);
+            }
+            self.input = input;
+            self._step(false);
+            for marker in markers {
+                on_marker(self, marker);
+            }
+        }
+    }
+
+    /// Check the ui for common accessibility problems, using the default
+    /// [`AuditOptions`](egui::accessibility_audit::AuditOptions).
+    ///
+    /// See [`egui::accessibility_audit`] for what is checked.
+    pub fn accessibility_audit(&self) -> egui::accessibility_audit::AuditReport {
+        self.accessibility_audit_options(&Default::default())
+    }
+
+    /// Check the ui for common accessibility problems.
+    ///
+    /// See [`egui::accessibility_audit`] for what is checked.
+    pub fn accessibility_audit_options(
+        &self,
+        options: &egui::accessibility_audit::AuditOptions,
+    ) -> egui::accessibility_audit::AuditReport {
+        fn collect(
+            node: &kittest::AccessKitNode<'_>,
+            nodes: &mut Vec<(egui::accesskit::NodeId, egui::accesskit::Node)>,
+        ) {
+            nodes.push((node.locate().0, node.data().clone()));
+            for child in node.children() {
+                collect(&child, nodes);
+            }
+        }
+
+        let mut nodes = Vec::new();
+        collect(&self.kittest.root(), &mut nodes);
+        egui::accessibility_audit::audit(&self.ctx, &nodes, options)
+    }
+
+    /// Assert that [`Self::accessibility_audit_options`] finds no problems.
+    ///
+    /// # Panics
+    /// Panics, listing the problems, if any were found.
+    #[track_caller]
+    pub fn assert_accessible(&self, options: &egui::accessibility_audit::AuditOptions) {
+        let report = self.accessibility_audit_options(options);
+        assert!(report.is_empty(), "{report}");
+    }
+
     /// Access the [`egui::RawInput`] for the next frame.
     pub fn input(&self) -> &egui::RawInput {
         &self.input
@@ -675,9 +912,28 @@ impl<'a, State> Harness<'a, State> {
     /// Returns an error if the rendering fails.
     #[cfg(any(feature = "wgpu", feature = "snapshot"))]
     pub fn render(&mut self) -> Result<image::RgbaImage, String> {
-        let mut output = self.output.clone();
+        self.render_viewport(ViewportId::ROOT)
+    }
+
+    /// Render the last output of the given viewport to an image.
+    ///
+    /// See [`HarnessBuilder::with_embed_viewports`].
+    ///
+    /// # Errors
+    /// Returns an error if the viewport is not open or if the rendering fails.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    pub fn render_viewport(&mut self, viewport_id: ViewportId) -> Result<image::RgbaImage, String> {
+        use egui::emath::GuiRounding as _;
+
+        let mut output = self
+            .viewport_output(viewport_id)
+            .ok_or_else(|| format!("The viewport {viewport_id:?} is not open"))?
+            .clone();
+        let (hover_pos, content_rect) = self.ctx.input_for(viewport_id, |i| {
+            (i.pointer.hover_pos(), i.content_rect().round_ui())
+        });
 
-        if let Some(mouse_pos) = self.ctx.input(|i| i.pointer.hover_pos()) {
+        if let Some(mouse_pos) = hover_pos {
             // Paint a mouse cursor:
             let triangle = vec![
                 mouse_pos,
@@ -686,7 +942,7 @@ impl<'a, State> Harness<'a, State> {
             ];
 
             output.shapes.push(ClippedShape {
-                clip_rect: self.ctx.content_rect(),
+                clip_rect: content_rect,
                 shape: egui::epaint::PathShape::convex_polygon(
                     triangle,
                     Color32::WHITE,
@@ -696,7 +952,8 @@ impl<'a, State> Harness<'a, State> {
             });
         }
 
-        self.renderer.render(&self.ctx, &output)
+        self.renderer
+            .render_viewport(&self.ctx, viewport_id, &output)
     }
 
     /// Get the root viewport output
@@ -0,0 +1,595 @@
+//! Random input fuzzing ("monkey testing") for egui apps.
+
+use std::{
+    fmt::{Display, Formatter},
+    panic::AssertUnwindSafe,
+    time::Duration,
+};
+
+use egui::{Key, Modifiers, MouseWheelUnit, PointerButton, Pos2, Rect, TouchPhase, Vec2, pos2};
+
+use crate::{ExceededMaxStepsError, Harness};
+
+/// A single random input action, generated by [`Monkey`].
+///
+/// All positions are in points, so a sequence of actions can be replayed with [`Monkey::replay`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum MonkeyAction {
+    /// Move the pointer to `pos` and press and release `button`.
+    Click { pos: Pos2, button: PointerButton },
+
+    /// Press the primary button at `from`, move to `to` and release it there.
+    Drag { from: Pos2, to: Pos2 },
+
+    /// Move the pointer to `pos`.
+    Hover { pos: Pos2 },
+
+    /// Scroll with the pointer at `pos`.
+    Scroll { pos: Pos2, delta: Vec2 },
+
+    /// Press and release `key`, with `repeats` key repeat events in between.
+    Key {
+        key: Key,
+        modifiers: Modifiers,
+        repeats: usize,
+    },
+
+    /// Type some text.
+    Text { text: String },
+
+    /// Resize the root viewport.
+    Resize { size: Vec2 },
+
+    /// Let some time pass.
+    Wait { seconds: f32 },
+
+    /// Move the pointer out of the window.
+    PointerGone,
+}
+
+impl MonkeyAction {
+    /// Queue the events of this action in the harness.
+    fn apply<State>(&self, harness: &mut Harness<'_, State>) {
+        match self {
+            Self::Click { pos, button } => {
+                harness.hover_at(*pos);
+                for pressed in [true, false] {
+                    harness.event(egui::Event::PointerButton {
+                        pos: *pos,
+                        button: *button,
+                        pressed,
+                        modifiers: Modifiers::NONE,
+                    });
+                }
+            }
+            Self::Drag { from, to } => {
+                harness.hover_at(*from);
+                harness.drag_at(*from);
+                harness.hover_at(from.lerp(*to, 0.5));
+                harness.hover_at(*to);
+                harness.event(egui::Event::PointerButton {
+                    pos: *to,
+                    button: PointerButton::Primary,
+                    pressed: false,
+                    modifiers: Modifiers::NONE,
+                });
+            }
+            Self::Hover { pos } => harness.hover_at(*pos),
+            Self::Scroll { pos, delta } => {
+                harness.hover_at(*pos);
+                harness.event(egui::Event::MouseWheel {
+                    unit: MouseWheelUnit::Point,
+                    delta: *delta,
+                    phase: TouchPhase::Move,
+                    modifiers: Modifiers::NONE,
+                });
+            }
+            Self::Key {
+                key,
+                modifiers,
+                repeats,
+            } => {
+                harness.key_down_modifiers(*modifiers, *key);
+                for _ in 0..*repeats {
+                    harness.event_modifiers(
+                        egui::Event::Key {
+                            key: *key,
+                            pressed: true,
+                            modifiers: *modifiers,
+                            repeat: true,
+                            physical_key: None,
+                        },
+                        *modifiers,
+                    );
+                }
+                harness.key_up_modifiers(*modifiers, *key);
+            }
+            Self::Text { text } => harness.event(egui::Event::Text(text.clone())),
+            Self::Resize { size } => {
+                harness.set_size(*size);
+            }
+            Self::Wait { seconds } => harness.advance_time(Duration::from_secs_f32(*seconds)),
+            Self::PointerGone => harness.remove_cursor(),
+        }
+    }
+}
+
+/// Formats the action as a Rust expression, so failing sequences can be pasted into a test.
+impl Display for MonkeyAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let pos = |pos: &Pos2| format!("egui::pos2({:?}, {:?})", pos.x, pos.y);
+        let vec = |vec: &Vec2| format!("egui::vec2({:?}, {:?})", vec.x, vec.y);
+        match self {
+            Self::Click { pos: p, button } => write!(
+                f,
+                "MonkeyAction::Click {{ pos: {}, button: egui::PointerButton::{button:?} }}",
+                pos(p)
+            ),
+            Self::Drag { from, to } => write!(
+                f,
+                "MonkeyAction::Drag {{ from: {}, to: {} }}",
+                pos(from),
+                pos(to)
+            ),
+            Self::Hover { pos: p } => write!(f, "MonkeyAction::Hover {{ pos: {} }}", pos(p)),
+            Self::Scroll { pos: p, delta } => write!(
+                f,
+                "MonkeyAction::Scroll {{ pos: {}, delta: {} }}",
+                pos(p),
+                vec(delta)
+            ),
+            Self::Key {
+                key,
+                modifiers,
+                repeats,
+            } => {
+                let Modifiers {
+                    alt,
+                    ctrl,
+                    shift,
+                    mac_cmd,
+                    command,
+                } = modifiers;
+                write!(
+                    f,
+                    "MonkeyAction::Key {{ key: egui::Key::{key:?}, modifiers: egui::Modifiers {{ \
+                    alt: {alt}, ctrl: {ctrl}, shift: {shift}, mac_cmd: {mac_cmd}, command: {command} \
+                    }}, repeats: {repeats} }}"
+                )
+            }
+            Self::Text { text } => {
+                write!(f, "MonkeyAction::Text {{ text: {text:?}.to_owned() }}")
+            }
+            Self::Resize { size } => write!(f, "MonkeyAction::Resize {{ size: {} }}", vec(size)),
+            Self::Wait { seconds } => write!(f, "MonkeyAction::Wait {{ seconds: {seconds:?} }}"),
+            Self::PointerGone => write!(f, "MonkeyAction::PointerGone"),
+        }
+    }
+}
+
+/// Why a [`Monkey`] run failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MonkeyFailureKind {
+    /// The app panicked, with this message.
+    Panic(String),
+
+    /// The app kept requesting repaints (see [`Harness::try_run`]).
+    RepaintLoop { max_steps: u64 },
+}
+
+impl Display for MonkeyFailureKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Panic(message) => write!(f, "panicked: {message}"),
+            Self::RepaintLoop { max_steps } => {
+                write!(f, "kept repainting for more than {max_steps} steps")
+            }
+        }
+    }
+}
+
+impl From<ExceededMaxStepsError> for MonkeyFailureKind {
+    fn from(err: ExceededMaxStepsError) -> Self {
+        Self::RepaintLoop {
+            max_steps: err.max_steps,
+        }
+    }
+}
+
+/// A failing [`Monkey`] run.
+#[derive(Clone, Debug)]
+pub struct MonkeyFailure {
+    /// The seed of the failing run.
+    pub seed: u64,
+
+    pub kind: MonkeyFailureKind,
+
+    /// How many actions were run before the failure.
+    pub num_actions_run: usize,
+
+    /// The (minimized, see [`Monkey::with_minimize`]) actions that reproduce the failure.
+    pub actions: Vec<MonkeyAction>,
+}
+
+impl Display for MonkeyFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            seed,
+            kind,
+            num_actions_run,
+            actions,
+        } = self;
+        writeln!(
+            f,
+            "Monkey test with seed {seed} {kind}\n\
+            Failed after {num_actions_run} actions, reproduce with these {} actions:\n",
+            actions.len()
+        )?;
+        writeln!(f, "Monkey::new({seed}).replay(&mut harness, &[")?;
+        for action in actions {
+            writeln!(f, "    {action},")?;
+        }
+        write!(f, "]).unwrap();")
+    }
+}
+
+/// Random input fuzzing ("monkey testing") for egui apps.
+///
+/// Generates random but plausible input, mostly targeted at the widgets in the AccessKit tree,
+/// and reports panics and repaint loops.
+/// Failing sequences are minimized to a short list of [`MonkeyAction`]s that reproduce the problem.
+///
+/// The same seed always generates the same actions for the same ui.
+///
+/// ```
+/// use egui_kittest::{Harness, Monkey};
+///
+/// Monkey::new(42).with_steps(20).run(|| {
+///     Harness::new_ui(|ui| {
+///         _ = ui.button("Click me");
+///         ui.text_edit_singleline(&mut String::new());
+///     })
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    seed: u64,
+    steps: usize,
+    check_repaint_loops: bool,
+    minimize: bool,
+}
+
+impl Monkey {
+    /// Create a new monkey with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            steps: 100,
+            check_repaint_loops: true,
+            minimize: true,
+        }
+    }
+
+    /// How many actions to run. Default is 100.
+    #[inline]
+    pub fn with_steps(mut self, steps: usize) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    /// Should a ui that keeps repainting (see [`Harness::try_run`]) count as a failure?
+    ///
+    /// Disable this for uis with continuous animations, like a spinner.
+    /// Default is `true`.
+    #[inline]
+    pub fn with_check_repaint_loops(mut self, check_repaint_loops: bool) -> Self {
+        self.check_repaint_loops = check_repaint_loops;
+        self
+    }
+
+    /// Should failing action sequences be minimized? Default is `true`.
+    ///
+    /// Minimizing runs the app many times, with fresh harnesses from `make_harness`.
+    #[inline]
+    pub fn with_minimize(mut self, minimize: bool) -> Self {
+        self.minimize = minimize;
+        self
+    }
+
+    /// Run random actions on a harness created by `make_harness`.
+    ///
+    /// `make_harness` is called again for every attempt while minimizing,
+    /// and should always create the same ui with the same state.
+    ///
+    /// Note that the panic messages of all attempts are printed as usual.
+    ///
+    /// # Errors
+    /// Returns a [`MonkeyFailure`] if the app panicked or kept repainting.
+    pub fn try_run<'a, State>(
+        &self,
+        make_harness: impl Fn() -> Harness<'a, State>,
+    ) -> Result<(), MonkeyFailure> {
+        let mut rng = Rng::new(self.seed);
+        let mut actions = Vec::new();
+
+        let result = catch_panic(|| {
+            let mut harness = make_harness();
+            for _ in 0..self.steps {
+                let action = random_action(&mut rng, &harness);
+                actions.push(action.clone());
+                self.run_action(&mut harness, &action)?;
+            }
+            Ok(())
+        });
+
+        let Err(kind) = result else {
+            return Ok(());
+        };
+
+        let num_actions_run = actions.len();
+        if self.minimize {
+            actions = self.minimize_actions(&make_harness, actions, &kind);
+        }
+
+        Err(MonkeyFailure {
+            seed: self.seed,
+            kind,
+            num_actions_run,
+            actions,
+        })
+    }
+
+    /// Like [`Self::try_run`], but panics on failure.
+    ///
+    /// # Panics
+    /// If the app panicked or kept repainting, with a script to reproduce the failure.
+    #[track_caller]
+    pub fn run<'a, State>(&self, make_harness: impl Fn() -> Harness<'a, State>) {
+        if let Err(failure) = self.try_run(make_harness) {
+            panic!("{failure}");
+        }
+    }
+
+    /// Run the given actions, e.g. from a [`MonkeyFailure`], on the harness.
+    ///
+    /// Panics in the app are not caught, so the failure can be debugged as usual.
+    ///
+    /// # Errors
+    /// Returns an error if the ui kept repainting and [`Self::with_check_repaint_loops`] is enabled.
+    pub fn replay<State>(
+        &self,
+        harness: &mut Harness<'_, State>,
+        actions: &[MonkeyAction],
+    ) -> Result<(), ExceededMaxStepsError> {
+        for action in actions {
+            self.run_action(harness, action)?;
+        }
+        Ok(())
+    }
+
+    fn run_action<State>(
+        &self,
+        harness: &mut Harness<'_, State>,
+        action: &MonkeyAction,
+    ) -> Result<(), ExceededMaxStepsError> {
+        action.apply(harness);
+        if self.check_repaint_loops {
+            harness.try_run()?;
+        } else {
+            harness.step();
+        }
+        Ok(())
+    }
+
+    /// Does running `actions` on a fresh harness fail the same way?
+    fn reproduces<'a, State>(
+        &self,
+        make_harness: &impl Fn() -> Harness<'a, State>,
+        actions: &[MonkeyAction],
+        kind: &MonkeyFailureKind,
+    ) -> bool {
+        let result = catch_panic(|| {
+            let mut harness = make_harness();
+            self.replay(&mut harness, actions)?;
+            Ok(())
+        });
+        result.as_ref().err() == Some(kind)
+    }
+
+    /// Remove chunks of actions, halving the chunk size, as long as the failure still reproduces.
+    fn minimize_actions<'a, State>(
+        &self,
+        make_harness: &impl Fn() -> Harness<'a, State>,
+        mut actions: Vec<MonkeyAction>,
+        kind: &MonkeyFailureKind,
+    ) -> Vec<MonkeyAction> {
+        let mut chunk_size = actions.len().div_ceil(2);
+        while 0 < chunk_size {
+            let mut start = 0;
+            while start < actions.len() {
+                let end = (start + chunk_size).min(actions.len());
+                let mut candidate = actions[..start].to_vec();
+                candidate.extend_from_slice(&actions[end..]);
+                if self.reproduces(make_harness, &candidate, kind) {
+                    actions = candidate;
+                } else {
+                    start = end;
+                }
+            }
+            chunk_size /= 2;
+        }
+        actions
+    }
+}
+
+fn catch_panic(
+    f: impl FnOnce() -> Result<(), ExceededMaxStepsError>,
+) -> Result<(), MonkeyFailureKind> {
+    #[expect(
+        clippy::disallowed_methods,
+        reason = "Tests are always compiled with panic = unwind"
+    )]
+    let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+
+    match result {
+        Ok(result) => result.map_err(MonkeyFailureKind::from),
+        Err(payload) => {
+            let message = if let Some(message) = payload.downcast_ref::<&str>() {
+                (*message).to_owned()
+            } else if let Some(message) = payload.downcast_ref::<String>() {
+                message.clone()
+            } else {
+                "<unknown panic payload>".to_owned()
+            };
+            Err(MonkeyFailureKind::Panic(message))
+        }
+    }
+}
+
+fn random_action<State>(rng: &mut Rng, harness: &Harness<'_, State>) -> MonkeyAction {
+    const KEYS: &[Key] = &[
+        Key::Tab,
+        Key::Enter,
+        Key::Escape,
+        Key::Space,
+        Key::Backspace,
+        Key::Delete,
+        Key::ArrowUp,
+        Key::ArrowDown,
+        Key::ArrowLeft,
+        Key::ArrowRight,
+        Key::Home,
+        Key::End,
+        Key::PageUp,
+        Key::PageDown,
+        Key::A,
+        Key::C,
+        Key::V,
+        Key::X,
+        Key::Z,
+    ];
+    const MODIFIERS: &[Modifiers] = &[
+        Modifiers::NONE,
+        Modifiers::NONE,
+        Modifiers::NONE,
+        Modifiers::SHIFT,
+        Modifiers::COMMAND,
+        Modifiers::ALT,
+        Modifiers::SHIFT.plus(Modifiers::COMMAND),
+    ];
+    const TEXTS: &[&str] = &[
+        "a", "Hello", " ", "\t", "0", "-1", "1e309", "ü", "日本", "🙂", "\u{200b}", "\n",
+    ];
+
+    let screen = harness.ctx.content_rect();
+    let targets = target_rects(harness, screen);
+    let random_pos = |rng: &mut Rng| {
+        // Mostly aim at widgets, but sometimes at empty space:
+        let rect = if !targets.is_empty() && rng.chance(0.8) {
+            targets[rng.below(targets.len())]
+        } else {
+            screen
+        };
+        pos2(
+            rng.range(rect.min.x, rect.max.x).round(),
+            rng.range(rect.min.y, rect.max.y).round(),
+        )
+    };
+
+    match rng.below(100) {
+        0..30 => MonkeyAction::Click {
+            pos: random_pos(rng),
+            button: if rng.chance(0.9) {
+                PointerButton::Primary
+            } else {
+                PointerButton::Secondary
+            },
+        },
+        30..40 => MonkeyAction::Drag {
+            from: random_pos(rng),
+            to: random_pos(rng),
+        },
+        40..50 => MonkeyAction::Hover {
+            pos: random_pos(rng),
+        },
+        50..58 => MonkeyAction::Scroll {
+            pos: random_pos(rng),
+            delta: Vec2::new(rng.range(-50.0, 50.0), rng.range(-200.0, 200.0)).round(),
+        },
+        58..80 => MonkeyAction::Key {
+            key: KEYS[rng.below(KEYS.len())],
+            modifiers: MODIFIERS[rng.below(MODIFIERS.len())],
+            repeats: if rng.chance(0.2) { rng.below(5) } else { 0 },
+        },
+        80..88 => MonkeyAction::Text {
+            text: TEXTS[rng.below(TEXTS.len())].to_owned(),
+        },
+        88..91 => MonkeyAction::Resize {
+            size: Vec2::new(rng.range(50.0, 1200.0), rng.range(50.0, 900.0)).round(),
+        },
+        91..96 => MonkeyAction::Wait {
+            seconds: (rng.range(0.05, 3.0) * 100.0).round() / 100.0,
+        },
+        _ => MonkeyAction::PointerGone,
+    }
+}
+
+/// The visible rects of all nodes in the AccessKit tree.
+fn target_rects<State>(harness: &Harness<'_, State>, screen: Rect) -> Vec<Rect> {
+    fn collect(node: &kittest::AccessKitNode<'_>, screen: Rect, rects: &mut Vec<Rect>) {
+        if let Some(rect) = node.bounding_box() {
+            let rect = Rect::from_min_max(
+                pos2(rect.x0 as f32, rect.y0 as f32),
+                pos2(rect.x1 as f32, rect.y1 as f32),
+            )
+            .intersect(screen);
+            if rect.is_positive() {
+                rects.push(rect);
+            }
+        }
+        for child in node.children() {
+            collect(&child, screen, rects);
+        }
+    }
+
+    let mut rects = Vec::new();
+    for child in harness.kittest.root().children() {
+        collect(&child, screen, &mut rects);
+    }
+    rects
+}
+
+/// A small deterministic random number generator (`SplitMix64`).
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A random number in `0..n`.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A random number in `0.0..1.0`.
+    fn unit(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1_u64 << 24) as f32
+    }
+
+    fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.unit()
+    }
+
+    fn chance(&mut self, probability: f32) -> bool {
+        self.unit() < probability
+    }
+}
@@ -18,6 +18,29 @@ pub trait TestRenderer {
         ctx: &egui::Context,
         output: &egui::FullOutput,
     ) -> Result<image::RgbaImage, String>;
+
+    /// Render a single viewport of the [`crate::Harness`] and return the resulting image.
+    ///
+    /// The `output` is the last output of that viewport.
+    /// The default implementation only supports the root viewport.
+    ///
+    /// # Errors
+    /// Returns an error if the rendering fails.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    fn render_viewport(
+        &mut self,
+        ctx: &egui::Context,
+        viewport_id: egui::ViewportId,
+        output: &egui::FullOutput,
+    ) -> Result<image::RgbaImage, String> {
+        if viewport_id == egui::ViewportId::ROOT {
+            self.render(ctx, output)
+        } else {
+            Err(format!(
+                "This TestRenderer does not support rendering the viewport {viewport_id:?}"
+            ))
+        }
+    }
 }
 
 /// A lazy renderer that initializes the renderer on the first render call.
@@ -52,6 +75,29 @@ impl LazyRenderer {
             builder: Some(Box::new(move || Box::new(create_renderer()))),
         }
     }
+
+    /// Create the renderer, if that hasn't happened yet.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    fn initialized(&mut self) -> Result<&mut Box<dyn TestRenderer>, String> {
+        if let Self::Uninitialized {
+            texture_ops,
+            builder: build,
+        } = self
+        {
+            let mut renderer = build.take().ok_or({
+                "No default renderer available. \
+                Enable the wgpu feature or set one via HarnessBuilder::renderer"
+            })?();
+            for delta in texture_ops.drain(..) {
+                renderer.handle_delta(&delta);
+            }
+            *self = Self::Initialized { renderer };
+        }
+        match self {
+            Self::Initialized { renderer } => Ok(renderer),
+            Self::Uninitialized { .. } => unreachable!(),
+        }
+    }
 }
 
 impl TestRenderer for LazyRenderer {
@@ -68,23 +114,17 @@ impl TestRenderer for LazyRenderer {
         ctx: &egui::Context,
         output: &egui::FullOutput,
     ) -> Result<image::RgbaImage, String> {
-        match self {
-            Self::Uninitialized {
-                texture_ops,
-                builder: build,
-            } => {
-                let mut renderer = build.take().ok_or({
-                    "No default renderer available. \
-                    Enable the wgpu feature or set one via HarnessBuilder::renderer"
-                })?();
-                for delta in texture_ops.drain(..) {
-                    renderer.handle_delta(&delta);
-                }
-                let image = renderer.render(ctx, output)?;
-                *self = Self::Initialized { renderer };
-                Ok(image)
-            }
-            Self::Initialized { renderer } => renderer.render(ctx, output),
-        }
+        self.initialized()?.render(ctx, output)
+    }
+
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    fn render_viewport(
+        &mut self,
+        ctx: &egui::Context,
+        viewport_id: egui::ViewportId,
+        output: &egui::FullOutput,
+    ) -> Result<image::RgbaImage, String> {
+        self.initialized()?
+            .render_viewport(ctx, viewport_id, output)
     }
 }
@@ -4,7 +4,11 @@ use std::path::PathBuf;
 
 use image::ImageError;
 
-use crate::{Harness, config::config};
+use crate::{
+    Harness,
+    config::config,
+    snapshot_report::{snapshot_report_dir_from_env, update_snapshot_report},
+};
 
 pub type SnapshotResult = Result<(), SnapshotError>;
 
@@ -31,6 +35,12 @@ pub struct SnapshotOptions {
     ///
     /// Can be configured via kittest.toml. The fallback is `tests/snapshots`.
     pub output_path: PathBuf,
+
+    /// Where to write the aggregated HTML report of failing snapshots, see [`crate::SnapshotReport`].
+    ///
+    /// Defaults to the directory set by the `SNAPSHOT_REPORT` env-var,
+    /// see [`snapshot_report_dir_from_env`]. `None` means no report.
+    pub report_path: Option<PathBuf>,
 }
 
 /// Helper struct to define the number of pixels that can differ before the snapshot is considered a failure.
@@ -159,6 +169,7 @@ impl Default for SnapshotOptions {
             threshold: config().threshold(),
             output_path: config().output_path(),
             failed_pixel_count_threshold: config().failed_pixel_count_threshold(),
+            report_path: snapshot_report_dir_from_env(),
         }
     }
 }
@@ -186,6 +197,16 @@ impl SnapshotOptions {
         self
     }
 
+    /// Write an aggregated HTML report of failing snapshots to this directory.
+    /// The default is the directory set by the `SNAPSHOT_REPORT` env-var.
+    ///
+    /// See [`crate::SnapshotReport`].
+    #[inline]
+    pub fn report_path(mut self, report_path: impl Into<PathBuf>) -> Self {
+        self.report_path = Some(report_path.into());
+        self
+    }
+
     /// Change the number of pixels that can differ before the snapshot is considered a failure.
     ///
     /// Preferably, you should use [`Self::threshold`] to control the sensitivity of the image comparison.
@@ -251,6 +272,18 @@ pub enum SnapshotError {
         /// The error that occurred
         err: String,
     },
+
+    /// The AccessKit tree did not match the snapshot
+    AccessKitDiff {
+        /// Name of the test
+        name: String,
+
+        /// The lines that differ, prefixed with `-` (snapshot) or `+` (new)
+        diff: String,
+
+        /// Path where the new tree was saved
+        new_path: PathBuf,
+    },
 }
 
 const HOW_TO_UPDATE_SCREENSHOTS: &str =
@@ -317,6 +350,18 @@ impl Display for SnapshotError {
             Self::RenderError { err } => {
                 write!(f, "Error rendering image: {err}")
             }
+            Self::AccessKitDiff {
+                name,
+                diff,
+                new_path,
+            } => {
+                let new_path = std::path::absolute(new_path).unwrap_or_else(|_| new_path.clone());
+                write!(
+                    f,
+                    "'{name}' AccessKit tree did not match snapshot. New tree: {}. {HOW_TO_UPDATE_SCREENSHOTS}\n{diff}",
+                    new_path.display()
+                )
+            }
         }
     }
 }
@@ -376,7 +421,12 @@ pub fn try_image_snapshot_options(
     name: impl Into<String>,
     options: &SnapshotOptions,
 ) -> SnapshotResult {
-    try_image_snapshot_options_impl(new, name.into(), options)
+    let name = name.into();
+    let result = try_image_snapshot_options_impl(new, name.clone(), options);
+    if let Some(report_path) = &options.report_path {
+        update_snapshot_report(report_path, &name, options, &result);
+    }
+    result
 }
 
 fn try_image_snapshot_options_impl(
@@ -392,6 +442,7 @@ fn try_image_snapshot_options_impl(
         threshold,
         output_path,
         failed_pixel_count_threshold,
+        report_path: _,
     } = options;
 
     let parent_path = if let Some(parent) = PathBuf::from(&name).parent() {
@@ -523,6 +574,151 @@ fn try_image_snapshot_options_impl(
     }
 }
 
+/// Write the AccessKit tree below `node` as indented text, one node per line.
+///
+/// Each line has the role, label, value and states of the node,
+/// and its bounds rounded to whole points.
+fn write_accesskit_tree(
+    out: &mut String,
+    node: &kittest::AccessKitNode<'_>,
+    depth: usize,
+) -> std::fmt::Result {
+    use std::fmt::Write as _;
+
+    write!(out, "{:indent$}{:?}", "", node.role(), indent = 2 * depth)?;
+    if let Some(label) = node.label() {
+        write!(out, " {label:?}")?;
+    }
+    if let Some(value) = node.value() {
+        write!(out, " value={value:?}")?;
+    } else if let Some(value) = node.numeric_value() {
+        write!(out, " value={value}")?;
+    }
+
+    let mut states = Vec::new();
+    if node.is_disabled() {
+        states.push("disabled".to_owned());
+    }
+    if node.is_focused() {
+        states.push("focused".to_owned());
+    }
+    if let Some(toggled) = node.toggled() {
+        states.push(format!("toggled={toggled:?}"));
+    }
+    if let Some(expanded) = node.data().is_expanded() {
+        states.push(if expanded { "expanded" } else { "collapsed" }.to_owned());
+    }
+    if node.is_selected() == Some(true) {
+        states.push("selected".to_owned());
+    }
+    if node.is_hidden() {
+        states.push("hidden".to_owned());
+    }
+    if !states.is_empty() {
+        write!(out, " [{}]", states.join(", "))?;
+    }
+
+    if let Some(rect) = node.bounding_box() {
+        // `as i64` avoids printing `-0`:
+        let [x0, y0, x1, y1] = [rect.x0, rect.y0, rect.x1, rect.y1].map(|v| v.round() as i64);
+        write!(out, " @ ({x0}, {y0})-({x1}, {y1})")?;
+    }
+    writeln!(out)?;
+
+    for child in node.children() {
+        write_accesskit_tree(out, &child, depth + 1)?;
+    }
+    Ok(())
+}
+
+/// The lines that differ between `old` and `new`, prefixed with `-` and `+`.
+fn line_diff(old: &str, new: &str) -> String {
+    let mut diff = String::new();
+    for change in similar::TextDiff::from_lines(old, new).iter_all_changes() {
+        let sign = match change.tag() {
+            similar::ChangeTag::Equal => continue,
+            similar::ChangeTag::Delete => '-',
+            similar::ChangeTag::Insert => '+',
+        };
+        diff.push(sign);
+        diff.push_str(change.value().trim_end_matches(['\r', '\n']));
+        diff.push('\n');
+    }
+    diff
+}
+
+/// Compare the AccessKit tree below `node` to the snapshot under `{output_path}/{name}.accesskit.txt`.
+///
+/// This works like [`try_image_snapshot_options`], including the `UPDATE_SNAPSHOTS` env-var.
+/// The new tree will be saved under `{output_path}/{name}.accesskit.new.txt` if it didn't match.
+///
+/// # Errors
+/// Returns a [`SnapshotError`] if the tree does not match the snapshot or if there was an error
+/// reading or writing the snapshot.
+pub fn try_accesskit_snapshot_options(
+    node: &crate::Node<'_>,
+    name: impl Into<String>,
+    options: &SnapshotOptions,
+) -> SnapshotResult {
+    #![expect(clippy::print_stdout)]
+
+    let name = name.into();
+    let mode = Mode::from_env();
+
+    let mut new = String::new();
+    write_accesskit_tree(&mut new, &node.accesskit_node, 0)
+        .expect("Writing to a String can't fail");
+
+    let output_path = &options.output_path;
+    let snapshot_path = output_path.join(format!("{name}.accesskit.txt"));
+    let new_path = output_path.join(format!("{name}.accesskit.new.txt"));
+    if let Some(parent) = snapshot_path.parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+    std::fs::remove_file(&new_path).ok();
+
+    let write = |path: &PathBuf| {
+        std::fs::write(path, &new).map_err(|err| SnapshotError::WriteSnapshot {
+            path: path.clone(),
+            err: ImageError::IoError(err),
+        })
+    };
+
+    let previous = match std::fs::read_to_string(&snapshot_path) {
+        Ok(previous) => previous,
+        Err(err) => {
+            if mode.is_update() {
+                write(&snapshot_path)?;
+                println!("Updated snapshot: {}", snapshot_path.display());
+                return Ok(());
+            }
+            write(&new_path)?;
+            return Err(SnapshotError::OpenSnapshot {
+                path: snapshot_path,
+                err: ImageError::IoError(err),
+            });
+        }
+    };
+
+    // Ignore line ending differences, e.g. from git on Windows:
+    if previous.replace("\r\n", "\n") == new {
+        return Ok(());
+    }
+
+    if mode.is_update() {
+        write(&snapshot_path)?;
+        println!("Updated snapshot: {}", snapshot_path.display());
+        Ok(())
+    } else {
+        write(&new_path)?;
+        Err(SnapshotError::AccessKitDiff {
+            name,
+            diff: line_diff(&previous, &new),
+            new_path,
+        })
+    }
+}
+
 /// Image snapshot test.
 ///
 /// This uses the default [`SnapshotOptions`]. Use [`try_image_snapshot_options`] if you want to
@@ -693,6 +889,84 @@ impl<State> Harness<'_, State> {
         self.snapshot_results.add(result);
     }
 
+    /// Replay a recording, like [`Self::replay`], and take a [`Self::snapshot`] named
+    /// `{name}_{marker}` at every marker.
+    ///
+    /// # Panics
+    /// The results are added to the [`Harness`]'s internal [`SnapshotResults`].
+    ///
+    /// The harness will panic when dropped if there were any snapshot errors.
+    #[track_caller]
+    pub fn replay_with_snapshots(
+        &mut self,
+        recording: &egui::input_recording::InputRecording,
+        name: &str,
+    ) {
+        self.replay_with(recording, |harness, marker| {
+            harness.snapshot(format!("{name}_{marker}"));
+        });
+    }
+
+    /// Compare the AccessKit tree of the ui to a text snapshot.
+    ///
+    /// This catches semantic regressions that image snapshots miss, e.g. a missing label.
+    /// The snapshot will be saved under `tests/snapshots/{name}.accesskit.txt`.
+    /// If the tree didn't match, the new tree will be saved under `tests/snapshots/{name}.accesskit.new.txt`.
+    ///
+    /// # Errors
+    /// Returns a [`SnapshotError`] if the tree does not match the snapshot or if there was an
+    /// error reading or writing the snapshot.
+    pub fn try_accesskit_snapshot(&self, name: impl Into<String>) -> SnapshotResult {
+        try_accesskit_snapshot_options(&self.root(), name, &self.default_snapshot_options)
+    }
+
+    /// Compare the AccessKit tree of the ui to a text snapshot.
+    ///
+    /// See [`Self::try_accesskit_snapshot`].
+    ///
+    /// # Panics
+    /// The result is added to the [`Harness`]'s internal [`SnapshotResults`].
+    ///
+    /// The harness will panic when dropped if there were any snapshot errors.
+    #[track_caller]
+    pub fn accesskit_snapshot(&mut self, name: impl Into<String>) {
+        let result = self.try_accesskit_snapshot(name);
+        self.snapshot_results.add(result);
+    }
+
+    /// Render an image of the given viewport and compare it to the snapshot.
+    ///
+    /// This is like [`Self::try_snapshot`], but for a viewport other than the root viewport.
+    /// See [`crate::HarnessBuilder::with_embed_viewports`].
+    ///
+    /// # Errors
+    /// Returns a [`SnapshotError`] if the image does not match the snapshot, if there was an
+    /// error reading or writing the snapshot, if the viewport is not open or if the rendering fails.
+    pub fn try_snapshot_viewport(
+        &mut self,
+        viewport_id: egui::ViewportId,
+        name: impl Into<String>,
+    ) -> SnapshotResult {
+        let image = self
+            .render_viewport(viewport_id)
+            .map_err(|err| SnapshotError::RenderError { err })?;
+        try_image_snapshot_options(&image, name.into(), &self.default_snapshot_options)
+    }
+
+    /// Render an image of the given viewport and compare it to the snapshot.
+    ///
+    /// This is like [`Self::snapshot`], but for a viewport other than the root viewport.
+    /// See [`crate::HarnessBuilder::with_embed_viewports`].
+    ///
+    /// # Panics
+    /// Panics if the image does not match the snapshot, if there was an error reading or writing the
+    /// snapshot, if the viewport is not open or if the rendering fails.
+    #[track_caller]
+    pub fn snapshot_viewport(&mut self, viewport_id: egui::ViewportId, name: impl Into<String>) {
+        let result = self.try_snapshot_viewport(viewport_id, name);
+        self.snapshot_results.add(result);
+    }
+
     /// Render a snapshot, save it to a temp file and open it in the default image viewer.
     ///
     /// This method is marked as deprecated to trigger errors in CI (so that it's not accidentally
@@ -798,6 +1072,13 @@ impl Display for SnapshotResults {
             for error in &self.errors {
                 writeln!(f, "  {error}")?;
             }
+            if let Some(report_path) = snapshot_report_dir_from_env() {
+                let html_path = report_path.join("index.html");
+                if html_path.exists() {
+                    let html_path = std::path::absolute(&html_path).unwrap_or(html_path);
+                    writeln!(f, "Snapshot report: {}", html_path.display())?;
+                }
+            }
             Ok(())
         }
     }
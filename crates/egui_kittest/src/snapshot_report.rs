@@ -0,0 +1,428 @@
+//! An aggregated HTML report of all failing image snapshots.
+//!
+//! Enable it by setting the `SNAPSHOT_REPORT` env-var, e.g. `SNAPSHOT_REPORT=1 cargo test`.
+//! Every failing snapshot then writes a small entry file to the report directory,
+//! and the `index.html` there is regenerated from all entries.
+//! Since the entries are files, the report covers all test binaries (and processes) of a run.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{SnapshotError, SnapshotOptions};
+
+/// The name of the HTML file in the report directory.
+const INDEX_HTML: &str = "index.html";
+
+/// The report directory, as configured by the `SNAPSHOT_REPORT` env-var.
+///
+/// `SNAPSHOT_REPORT=1` uses `target/kittest_report` (respecting `CARGO_TARGET_DIR`),
+/// any other value (except `0`, `false`, …) is used as the path of the directory.
+pub fn snapshot_report_dir_from_env() -> Option<PathBuf> {
+    let value = std::env::var("SNAPSHOT_REPORT").ok()?;
+    match value.as_str() {
+        "" | "false" | "0" | "no" | "off" => None,
+        "true" | "1" | "yes" | "on" => Some(default_target_dir().join("kittest_report")),
+        path => Some(PathBuf::from(path)),
+    }
+}
+
+fn default_target_dir() -> PathBuf {
+    if let Some(target_dir) = std::env::var_os("CARGO_TARGET_DIR") {
+        return PathBuf::from(target_dir);
+    }
+
+    // The working directory of tests is the crate root, but the target dir is in the workspace root:
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    current_dir
+        .ancestors()
+        .find(|dir| dir.join("Cargo.lock").exists())
+        .unwrap_or(&current_dir)
+        .join("target")
+}
+
+/// A failing image snapshot in a [`SnapshotReport`].
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SnapshotReportEntry {
+    /// Name of the snapshot.
+    pub name: String,
+
+    /// The checked in snapshot. Doesn't exist for new snapshots.
+    pub snapshot_path: PathBuf,
+
+    /// The image from the failing test run.
+    pub new_path: PathBuf,
+
+    /// The diff image, if the sizes matched.
+    pub diff_path: Option<PathBuf>,
+
+    /// [`SnapshotOptions::threshold`] of the snapshot.
+    pub threshold: f32,
+
+    /// [`SnapshotOptions::failed_pixel_count_threshold`] of the snapshot.
+    pub failed_pixel_count_threshold: usize,
+
+    /// Count of pixels that were different, if the sizes matched.
+    pub num_wrong_pixels: Option<i32>,
+
+    /// The [`SnapshotError`] as text.
+    pub error: String,
+}
+
+/// All failing image snapshots in a report directory.
+///
+/// See [`SnapshotOptions::report_path`].
+#[derive(Clone, Debug)]
+pub struct SnapshotReport {
+    dir: PathBuf,
+
+    /// The failing snapshots, sorted by name.
+    pub entries: Vec<SnapshotReportEntry>,
+}
+
+impl SnapshotReport {
+    /// Load all entries from a report directory.
+    ///
+    /// Entries whose `.new.png` no longer exists (e.g. because it was accepted) are skipped.
+    ///
+    /// # Errors
+    /// If the directory can't be read.
+    /// Unreadable entries are skipped.
+    pub fn load(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        let mut entries = Vec::new();
+        match std::fs::read_dir(entries_dir(&dir)) {
+            Ok(read_dir) => {
+                for file in read_dir {
+                    let path = file?.path();
+                    if path.extension().is_some_and(|ext| ext == "toml")
+                        && let Ok(text) = std::fs::read_to_string(&path)
+                        && let Ok(entry) = toml::from_str::<SnapshotReportEntry>(&text)
+                        && entry.new_path.exists()
+                    {
+                        entries.push(entry);
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(Self { dir, entries })
+    }
+
+    /// The report directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The path of the HTML report.
+    pub fn html_path(&self) -> PathBuf {
+        self.dir.join(INDEX_HTML)
+    }
+
+    /// Write the HTML report to [`Self::html_path`].
+    ///
+    /// # Errors
+    /// If writing the file fails.
+    pub fn write_html(&self) -> io::Result<PathBuf> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.html_path();
+
+        // Many tests may write the report at the same time, so write it atomically:
+        let tmp_path = self.dir.join(format!(
+            "{INDEX_HTML}.{}.{:?}.tmp",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        std::fs::write(&tmp_path, self.to_html())?;
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(path)
+    }
+
+    /// Accept the new images of the snapshots with the given names,
+    /// replacing the checked in snapshots, and remove them from the report.
+    ///
+    /// This is what the command in the HTML report does.
+    ///
+    /// # Errors
+    /// If moving a file or rewriting the report fails.
+    pub fn accept(&mut self, names: &[&str]) -> io::Result<()> {
+        let mut result = Ok(());
+        self.entries.retain(|entry| {
+            if !names.contains(&entry.name.as_str()) {
+                return true;
+            }
+            if let Err(err) = std::fs::rename(&entry.new_path, &entry.snapshot_path) {
+                result = Err(err);
+                return true;
+            }
+            if let Some(diff_path) = &entry.diff_path {
+                std::fs::remove_file(diff_path).ok();
+            }
+            std::fs::remove_file(entry_path(&self.dir, &entry.snapshot_path)).ok();
+            false
+        });
+        result?;
+        self.write_html()?;
+        Ok(())
+    }
+
+    /// The report as a self-contained HTML page.
+    ///
+    /// The images are linked, not embedded.
+    pub fn to_html(&self) -> String {
+        use std::fmt::Write as _;
+
+        let mut html = String::new();
+        html.push_str(HTML_HEAD);
+
+        if self.entries.is_empty() {
+            html.push_str("<p>All snapshots passed.</p>\n");
+        } else {
+            writeln!(
+                html,
+                "<p>{} failing snapshot(s). Select the ones to accept, then run the command at the bottom.</p>",
+                self.entries.len()
+            )
+            .expect("Writing to a String can't fail");
+        }
+
+        for entry in &self.entries {
+            write_entry_html(&mut html, entry).expect("Writing to a String can't fail");
+        }
+
+        html.push_str(HTML_FOOTER);
+        html
+    }
+}
+
+/// Update the report in `dir` after comparing the snapshot `name`.
+///
+/// Removes the old entry of the snapshot, and adds a new one if the comparison failed.
+pub(crate) fn update_snapshot_report(
+    dir: &Path,
+    name: &str,
+    options: &SnapshotOptions,
+    result: &Result<(), SnapshotError>,
+) {
+    if let Err(err) = try_update_snapshot_report(dir, name, options, result) {
+        #[expect(clippy::print_stderr)]
+        {
+            eprintln!(
+                "Failed to update snapshot report in {}: {err}",
+                dir.display()
+            );
+        }
+    }
+}
+
+fn try_update_snapshot_report(
+    dir: &Path,
+    name: &str,
+    options: &SnapshotOptions,
+    result: &Result<(), SnapshotError>,
+) -> io::Result<()> {
+    let output_path = std::path::absolute(&options.output_path)?;
+    let snapshot_path = output_path.join(format!("{name}.png"));
+    let new_path = output_path.join(format!("{name}.new.png"));
+    let diff_path = output_path.join(format!("{name}.diff.png"));
+
+    let entry_path = entry_path(dir, &snapshot_path);
+    let had_entry = std::fs::remove_file(&entry_path).is_ok();
+
+    // Only report failures that left a new image behind, i.e. no write or render errors:
+    let entry = match result {
+        Err(err) if new_path.exists() => Some(SnapshotReportEntry {
+            name: name.to_owned(),
+            snapshot_path,
+            new_path,
+            diff_path: diff_path.exists().then_some(diff_path),
+            threshold: options.threshold,
+            failed_pixel_count_threshold: options.failed_pixel_count_threshold,
+            num_wrong_pixels: match err {
+                SnapshotError::Diff { diff, .. } => Some(*diff),
+                _ => None,
+            },
+            error: err.to_string(),
+        }),
+        _ => None,
+    };
+
+    if let Some(entry) = &entry {
+        let toml = toml::to_string(entry).map_err(io::Error::other)?;
+        std::fs::create_dir_all(entries_dir(dir))?;
+        std::fs::write(&entry_path, toml)?;
+    }
+
+    if had_entry || entry.is_some() {
+        SnapshotReport::load(dir)?.write_html()?;
+    }
+    Ok(())
+}
+
+fn entries_dir(dir: &Path) -> PathBuf {
+    dir.join("entries")
+}
+
+/// Each snapshot has one entry file, named after the hash of its (absolute) path.
+fn entry_path(dir: &Path, snapshot_path: &Path) -> PathBuf {
+    use std::hash::{Hash as _, Hasher as _};
+    let mut hasher = std::hash::DefaultHasher::new();
+    snapshot_path.hash(&mut hasher);
+    entries_dir(dir).join(format!("{:016x}.toml", hasher.finish()))
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn file_url(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    let mut url = String::from("file:///");
+    for c in path.trim_start_matches('/').chars() {
+        match c {
+            ' ' => url.push_str("%20"),
+            '#' => url.push_str("%23"),
+            '%' => url.push_str("%25"),
+            '?' => url.push_str("%3F"),
+            c => url.push(c),
+        }
+    }
+    url
+}
+
+fn write_entry_html(html: &mut String, entry: &SnapshotReportEntry) -> std::fmt::Result {
+    use std::fmt::Write as _;
+
+    let SnapshotReportEntry {
+        name,
+        snapshot_path,
+        new_path,
+        diff_path,
+        threshold,
+        failed_pixel_count_threshold,
+        num_wrong_pixels,
+        error,
+    } = entry;
+
+    let old = snapshot_path.exists().then(|| file_url(snapshot_path));
+    let new = file_url(new_path);
+    let diff = diff_path.as_deref().map(file_url);
+
+    let wrong_pixels = num_wrong_pixels.map_or_else(|| "-".to_owned(), |n| n.to_string());
+    write!(
+        html,
+        r#"<section>
+<h2><label><input type="checkbox" class="accept" data-new="{new_path}" data-snapshot="{snapshot_path}"> {name}</label></h2>
+<p class="stats">Failed pixels: <b>{wrong_pixels}</b> (allowed: {failed_pixel_count_threshold}), threshold: {threshold}</p>
+<pre>{error}</pre>
+<div class="images">
+"#,
+        new_path = escape_html(&new_path.to_string_lossy()),
+        snapshot_path = escape_html(&snapshot_path.to_string_lossy()),
+        name = escape_html(name),
+        error = escape_html(error),
+    )?;
+
+    for (caption, url) in [
+        ("Old", old.as_deref()),
+        ("New", Some(new.as_str())),
+        ("Diff", diff.as_deref()),
+    ] {
+        html.push_str("<figure>");
+        if let Some(url) = url {
+            write!(
+                html,
+                r#"<a href="{url}"><img src="{url}"></a>"#,
+                url = escape_html(url)
+            )?;
+        } else {
+            html.push_str(r#"<div class="missing">Missing</div>"#);
+        }
+        writeln!(html, "<figcaption>{caption}</figcaption></figure>")?;
+    }
+
+    if let Some(old) = &old {
+        write!(
+            html,
+            r#"<figure><div class="overlay"><img src="{old}"><img class="top" src="{new}"></div>
+<input type="range" min="0" max="100" value="50" class="slider"><figcaption>Old | New</figcaption></figure>
+"#,
+            old = escape_html(old),
+            new = escape_html(&new),
+        )?;
+    }
+
+    html.push_str("</div>\n</section>\n");
+    Ok(())
+}
+
+const HTML_HEAD: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Snapshot report</title>
+<style>
+body { font-family: sans-serif; background: #1b1b1b; color: #ddd; margin: 2em; }
+section { border-top: 1px solid #444; padding: 1em 0; }
+h2 { font-size: 1.1em; }
+pre { white-space: pre-wrap; color: #aaa; }
+.images { display: flex; flex-wrap: wrap; gap: 1em; align-items: flex-start; }
+figure { margin: 0; }
+figcaption { text-align: center; }
+img { max-width: 400px; image-rendering: pixelated; background: repeating-conic-gradient(#555 0 25%, #333 0 50%) 0 0 / 16px 16px; }
+.missing { width: 200px; height: 100px; display: flex; align-items: center; justify-content: center; border: 1px dashed #666; }
+.overlay { position: relative; }
+.overlay .top { position: absolute; left: 0; top: 0; clip-path: inset(0 0 0 50%); }
+.slider { width: 100%; }
+textarea { width: 100%; height: 8em; background: #111; color: #ddd; }
+</style>
+</head>
+<body>
+<h1>Snapshot report</h1>
+"#;
+
+const HTML_FOOTER: &str = r#"<h2>Accept selected snapshots</h2>
+<p><label><input type="checkbox" id="select_all"> Select all</label></p>
+<textarea id="command" readonly></textarea>
+<script>
+function quote(path) {
+    return "'" + path.replace(/'/g, "'\\''") + "'";
+}
+function updateCommand() {
+    const lines = [];
+    for (const checkbox of document.querySelectorAll(".accept:checked")) {
+        lines.push("mv -f " + quote(checkbox.dataset.new) + " " + quote(checkbox.dataset.snapshot));
+    }
+    document.getElementById("command").value = lines.join("\n");
+}
+for (const checkbox of document.querySelectorAll(".accept")) {
+    checkbox.addEventListener("change", updateCommand);
+}
+document.getElementById("select_all").addEventListener("change", (event) => {
+    for (const checkbox of document.querySelectorAll(".accept")) {
+        checkbox.checked = event.target.checked;
+    }
+    updateCommand();
+});
+for (const slider of document.querySelectorAll(".slider")) {
+    slider.addEventListener("input", () => {
+        const top = slider.parentElement.querySelector(".top");
+        top.style.clipPath = "inset(0 0 0 " + slider.value + "%)";
+    });
+}
+</script>
+</body>
+</html>
+"#;
@@ -0,0 +1,520 @@
+//! Simulating a backend with multiple native viewports.
+//!
+//! See [`crate::HarnessBuilder::with_embed_viewports`].
+
+use std::{cell::RefCell, collections::VecDeque, rc::Rc, sync::Arc, time::Duration};
+
+use egui::{
+    DeferredViewportUiCallback, FullOutput, ImmediateViewport, Modifiers, OrderedViewportIdMap,
+    Pos2, RawInput, Rect, Vec2, ViewportBuilder, ViewportCommand, ViewportEvent, ViewportId,
+    ViewportIdMap, ViewportIdPair, ViewportIdSet, ViewportInfo, ViewportOutput,
+};
+
+use crate::{EventQueue, EventType, Harness, Node};
+
+/// The size of a new viewport, if its [`ViewportBuilder`] doesn't specify one.
+const DEFAULT_VIEWPORT_SIZE: Vec2 = Vec2::new(800.0, 600.0);
+
+/// A viewport other than the root viewport.
+pub(crate) struct ChildViewport {
+    ids: ViewportIdPair,
+    builder: ViewportBuilder,
+
+    /// Only set for deferred viewports.
+    viewport_ui_cb: Option<Arc<DeferredViewportUiCallback>>,
+
+    /// The simulated state of the native window.
+    info: ViewportInfo,
+
+    kittest: kittest::State,
+    output: FullOutput,
+
+    /// Input for the next pass.
+    modifiers: Modifiers,
+    events: Vec<egui::Event>,
+
+    queued_events: EventQueue,
+
+    /// Events taken from `queued_events` by [`Harness::step`]. One is processed per pass.
+    pending_events: VecDeque<EventType>,
+}
+
+impl ChildViewport {
+    fn new(
+        ids: ViewportIdPair,
+        builder: ViewportBuilder,
+        viewport_ui_cb: Option<Arc<DeferredViewportUiCallback>>,
+        info: ViewportInfo,
+        mut output: FullOutput,
+    ) -> Self {
+        Self {
+            ids,
+            builder,
+            viewport_ui_cb,
+            info,
+            kittest: kittest::State::new(
+                output
+                    .platform_output
+                    .accesskit_update
+                    .take()
+                    .expect("AccessKit was disabled"),
+            ),
+            output,
+            modifiers: Modifiers::default(),
+            events: Vec::new(),
+            queued_events: EventQueue::default(),
+            pending_events: VecDeque::new(),
+        }
+    }
+
+    fn update(&mut self, mut output: FullOutput) {
+        self.kittest.update(
+            output
+                .platform_output
+                .accesskit_update
+                .take()
+                .expect("AccessKit was disabled"),
+        );
+        self.output = output;
+    }
+
+    /// The input for the next pass of this viewport.
+    fn take_input(&mut self, template: &RawInput) -> RawInput {
+        if let Some(event) = self.pending_events.pop_front() {
+            match event {
+                EventType::Event(event) => self.events.push(event),
+                EventType::Modifiers(modifiers) => self.modifiers = modifiers,
+            }
+        }
+
+        let mut input = viewport_input(self.ids.this, &mut self.info, template);
+        input.modifiers = self.modifiers;
+        input.events = std::mem::take(&mut self.events);
+        input
+    }
+
+    fn repaint_delay(&self) -> Option<Duration> {
+        self.output
+            .viewport_output
+            .get(&self.ids.this)
+            .map(|output| output.repaint_delay)
+    }
+}
+
+/// The state shared between the [`Harness`] and the immediate viewport renderer
+/// (see [`egui::Context::set_immediate_viewport_renderer`]).
+#[derive(Default)]
+pub(crate) struct ImmediateViewports {
+    /// The input used by the immediate viewports that are shown for the first time.
+    template: RawInput,
+
+    /// The input for the next pass of each known immediate viewport.
+    inputs: ViewportIdMap<RawInput>,
+
+    /// The immediate viewports that were shown during the last pass.
+    outputs: Vec<(ViewportIdPair, ViewportBuilder, FullOutput)>,
+}
+
+impl ImmediateViewports {
+    /// Make the immediate viewports shown on this thread run in this harness.
+    pub(crate) fn install(this: &Rc<RefCell<Self>>) {
+        let this = Rc::clone(this);
+        egui::Context::set_immediate_viewport_renderer(move |ctx, viewport| {
+            let ImmediateViewport {
+                ids,
+                builder,
+                mut viewport_ui_cb,
+            } = viewport;
+
+            let input = {
+                let mut this = this.borrow_mut();
+                this.inputs.remove(&ids.this).unwrap_or_else(|| {
+                    let native_pixels_per_point = this
+                        .template
+                        .viewports
+                        .get(&ViewportId::ROOT)
+                        .and_then(|info| info.native_pixels_per_point);
+                    let mut info = initial_info(ids, &builder, native_pixels_per_point);
+                    viewport_input(ids.this, &mut info, &this.template)
+                })
+            };
+
+            let output = ctx.run_ui(input, |ui| viewport_ui_cb(ui));
+            this.borrow_mut().outputs.push((ids, builder, output));
+        });
+    }
+}
+
+/// The state of a new native window, created from a [`ViewportBuilder`].
+fn initial_info(
+    ids: ViewportIdPair,
+    builder: &ViewportBuilder,
+    native_pixels_per_point: Option<f32>,
+) -> ViewportInfo {
+    let rect = Rect::from_min_size(
+        builder.position.unwrap_or(Pos2::ZERO),
+        builder.inner_size.unwrap_or(DEFAULT_VIEWPORT_SIZE),
+    );
+    ViewportInfo {
+        parent: Some(ids.parent),
+        title: builder.title.clone(),
+        native_pixels_per_point,
+        inner_rect: Some(rect),
+        outer_rect: Some(rect),
+        minimized: Some(false),
+        maximized: Some(builder.maximized.unwrap_or(false)),
+        fullscreen: Some(builder.fullscreen.unwrap_or(false)),
+        ..Default::default()
+    }
+}
+
+/// The input for the next pass of a viewport other than the root viewport.
+///
+/// The events of the [`ViewportInfo`] are moved to the input.
+fn viewport_input(id: ViewportId, info: &mut ViewportInfo, template: &RawInput) -> RawInput {
+    let size = info
+        .inner_rect
+        .map_or(DEFAULT_VIEWPORT_SIZE, |rect| rect.size());
+    let focused = info.focused.unwrap_or(true);
+
+    let mut viewports = template.viewports.clone();
+    viewports.insert(id, info.take());
+
+    RawInput {
+        viewport_id: id,
+        viewports,
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, size)),
+        focused,
+        ..template.clone()
+    }
+}
+
+/// Simulate what a native window does when it gets a [`ViewportCommand`].
+///
+/// [`ViewportCommand::Focus`] is handled by the [`Harness`], since it affects all viewports.
+fn apply_command(info: &mut ViewportInfo, command: &ViewportCommand) {
+    match command {
+        ViewportCommand::Close => info.events.push(ViewportEvent::Close),
+        ViewportCommand::CancelClose => {
+            info.events.retain(|event| *event != ViewportEvent::Close);
+        }
+        ViewportCommand::Title(title) => info.title = Some(title.clone()),
+        ViewportCommand::InnerSize(size) => {
+            let min = info.inner_rect.map_or(Pos2::ZERO, |rect| rect.min);
+            info.inner_rect = Some(Rect::from_min_size(min, *size));
+            info.outer_rect = info.inner_rect;
+        }
+        ViewportCommand::OuterPosition(pos) => {
+            let size = info
+                .inner_rect
+                .map_or(DEFAULT_VIEWPORT_SIZE, |rect| rect.size());
+            info.inner_rect = Some(Rect::from_min_size(*pos, size));
+            info.outer_rect = info.inner_rect;
+        }
+        ViewportCommand::Minimized(minimized) => info.minimized = Some(*minimized),
+        ViewportCommand::Maximized(maximized) => info.maximized = Some(*maximized),
+        ViewportCommand::Fullscreen(fullscreen) => info.fullscreen = Some(*fullscreen),
+        _ => {}
+    }
+}
+
+/// Running the viewports when they are not embedded.
+impl<State> Harness<'_, State> {
+    fn root_info_mut(&mut self) -> &mut ViewportInfo {
+        self.input.viewports.entry(ViewportId::ROOT).or_default()
+    }
+
+    fn viewport_info_mut(&mut self, viewport_id: ViewportId) -> Option<&mut ViewportInfo> {
+        if viewport_id == ViewportId::ROOT {
+            Some(self.root_info_mut())
+        } else {
+            self.viewports.get_mut(&viewport_id).map(|vp| &mut vp.info)
+        }
+    }
+
+    /// The parts of the input that are the same for all viewports.
+    fn input_template(&self) -> RawInput {
+        RawInput {
+            viewports: self.input.viewports.clone(),
+            time: self.input.time,
+            predicted_dt: self.input.predicted_dt,
+            max_texture_side: self.input.max_texture_side,
+            system_theme: self.input.system_theme,
+            ..Default::default()
+        }
+    }
+
+    /// Move the queued events of each viewport to its pending events.
+    ///
+    /// Returns the number of passes needed to process them.
+    pub(crate) fn take_viewport_events(&mut self) -> usize {
+        self.viewports
+            .values_mut()
+            .map(|vp| {
+                let events = std::mem::take(&mut *vp.queued_events.lock());
+                vp.pending_events.extend(events);
+                vp.pending_events.len()
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Prepare the input of all viewports, before the root viewport runs.
+    pub(crate) fn begin_viewports_pass(&mut self) {
+        // Pick up changes from `Harness::set_size`:
+        if let Some(screen_rect) = self.input.screen_rect {
+            let info = self.root_info_mut();
+            let min = info.inner_rect.map_or(Pos2::ZERO, |rect| rect.min);
+            info.inner_rect = Some(Rect::from_min_size(min, screen_rect.size()));
+        }
+        self.input.focused = self.root_info_mut().focused.unwrap_or(true);
+        self.had_viewport_events = !self.root_info_mut().events.is_empty();
+
+        self.input.viewports.retain(|id, _| *id == ViewportId::ROOT);
+        for (id, vp) in &self.viewports {
+            self.input.viewports.insert(*id, vp.info.clone());
+        }
+
+        let template = self.input_template();
+        let inputs = self
+            .viewports
+            .iter_mut()
+            .filter(|(_, vp)| vp.viewport_ui_cb.is_none())
+            .map(|(id, vp)| (*id, vp.take_input(&template)))
+            .collect::<ViewportIdMap<_>>();
+        self.had_viewport_events |= inputs
+            .values()
+            .any(|input| !input.viewport().events.is_empty());
+
+        *self.immediate_viewports.borrow_mut() = ImmediateViewports {
+            template,
+            inputs,
+            outputs: Vec::new(),
+        };
+        ImmediateViewports::install(&self.immediate_viewports);
+    }
+
+    /// Handle the output of the immediate viewports that were shown during the last pass.
+    pub(crate) fn collect_immediate_viewports(&mut self) {
+        let outputs = std::mem::take(&mut self.immediate_viewports.borrow_mut().outputs);
+        for (ids, builder, output) in outputs {
+            self.renderer.handle_delta(&output.textures_delta);
+            if let Some(vp) = self.viewports.get_mut(&ids.this) {
+                vp.update(output);
+            } else {
+                let native_pixels_per_point = self.root_info_mut().native_pixels_per_point;
+                let info = initial_info(ids, &builder, native_pixels_per_point);
+                let vp = ChildViewport::new(ids, builder, None, info, output);
+                self.viewports.insert(ids.this, vp);
+            }
+        }
+    }
+
+    /// Apply the viewport commands and run the deferred viewports, after the root viewport ran.
+    pub(crate) fn end_viewports_pass(&mut self) {
+        let mut ran = ViewportIdSet::default();
+        self.handle_viewport_output(self.output.viewport_output.clone(), &mut ran);
+
+        let deferred: Vec<ViewportId> = self
+            .viewports
+            .iter()
+            .filter(|(_, vp)| vp.viewport_ui_cb.is_some())
+            .map(|(id, _)| *id)
+            .collect();
+        for viewport_id in deferred {
+            if ran.insert(viewport_id)
+                && let Some(vp) = self.viewports.get(&viewport_id)
+                && let Some(viewport_ui_cb) = vp.viewport_ui_cb.clone()
+            {
+                let input = self.viewport_input(viewport_id);
+                let output = self.run_deferred_viewport(input, viewport_ui_cb.as_ref());
+                let viewport_output = output.viewport_output.clone();
+                if let Some(vp) = self.viewports.get_mut(&viewport_id) {
+                    vp.update(output);
+                }
+                self.handle_viewport_output(viewport_output, &mut ran);
+            }
+        }
+    }
+
+    fn viewport_input(&mut self, viewport_id: ViewportId) -> RawInput {
+        let template = self.input_template();
+        let input = self
+            .viewports
+            .get_mut(&viewport_id)
+            .map(|vp| vp.take_input(&template))
+            .unwrap_or_default();
+        self.had_viewport_events |= !input.viewport().events.is_empty();
+        input
+    }
+
+    fn run_deferred_viewport(
+        &mut self,
+        input: RawInput,
+        viewport_ui_cb: &DeferredViewportUiCallback,
+    ) -> FullOutput {
+        let output = self.ctx.run_ui(input, |ui| viewport_ui_cb(ui));
+        self.collect_immediate_viewports();
+        self.renderer.handle_delta(&output.textures_delta);
+        output
+    }
+
+    /// Create and remove viewports, and apply the commands sent to them.
+    ///
+    /// New deferred viewports run right away.
+    fn handle_viewport_output(
+        &mut self,
+        viewport_output: OrderedViewportIdMap<ViewportOutput>,
+        ran: &mut ViewportIdSet,
+    ) {
+        self.viewports
+            .retain(|id, _| viewport_output.contains_key(id));
+
+        for (viewport_id, output) in viewport_output {
+            let ViewportOutput {
+                parent,
+                class: _,
+                builder,
+                viewport_ui_cb,
+                commands,
+                repaint_delay: _,
+            } = output;
+
+            let mut commands = commands;
+            if let Some(vp) = self.viewports.get_mut(&viewport_id) {
+                let (patch, _recreate) = vp.builder.patch(builder);
+                vp.viewport_ui_cb = viewport_ui_cb;
+                commands.splice(0..0, patch);
+            } else if viewport_id != ViewportId::ROOT
+                && let Some(viewport_ui_cb) = viewport_ui_cb
+            {
+                ran.insert(viewport_id);
+                let ids = ViewportIdPair {
+                    this: viewport_id,
+                    parent,
+                };
+                let native_pixels_per_point = self.root_info_mut().native_pixels_per_point;
+                let mut info = initial_info(ids, &builder, native_pixels_per_point);
+                let input = viewport_input(viewport_id, &mut info, &self.input_template());
+                let output = self.run_deferred_viewport(input, viewport_ui_cb.as_ref());
+                let nested_output = output.viewport_output.clone();
+                let vp = ChildViewport::new(ids, builder, Some(viewport_ui_cb), info, output);
+                self.viewports.insert(viewport_id, vp);
+                self.handle_viewport_output(nested_output, ran);
+            }
+
+            for command in &commands {
+                self.apply_viewport_command(viewport_id, command);
+            }
+        }
+    }
+
+    fn apply_viewport_command(&mut self, viewport_id: ViewportId, command: &ViewportCommand) {
+        if *command == ViewportCommand::Focus {
+            self.root_info_mut().focused = Some(viewport_id == ViewportId::ROOT);
+            for (id, vp) in &mut self.viewports {
+                vp.info.focused = Some(*id == viewport_id);
+            }
+            return;
+        }
+
+        let Some(info) = self.viewport_info_mut(viewport_id) else {
+            return;
+        };
+        apply_command(info, command);
+
+        if viewport_id == ViewportId::ROOT
+            && let ViewportCommand::InnerSize(size) = command
+        {
+            self.set_size(*size);
+        }
+    }
+
+    /// The repaint delays of the viewports other than the root viewport.
+    pub(crate) fn viewport_repaint_delays(&self) -> impl Iterator<Item = Duration> + '_ {
+        self.viewports
+            .values()
+            .filter_map(ChildViewport::repaint_delay)
+    }
+
+    /// Does any viewport other than the root viewport want to be repainted right away?
+    pub(crate) fn viewports_need_repaint(&self) -> bool {
+        self.had_viewport_events
+            || self
+                .viewport_repaint_delays()
+                .any(|delay| delay == Duration::ZERO)
+    }
+
+    /// The ids of the viewports other than the root viewport that are currently open.
+    ///
+    /// This is always empty unless the viewports are not embedded,
+    /// see [`crate::HarnessBuilder::with_embed_viewports`].
+    pub fn viewport_ids(&self) -> impl Iterator<Item = ViewportId> + '_ {
+        self.viewports.keys().copied()
+    }
+
+    /// Is this viewport currently open?
+    pub fn has_viewport(&self, viewport_id: ViewportId) -> bool {
+        viewport_id == ViewportId::ROOT || self.viewports.contains_key(&viewport_id)
+    }
+
+    /// The root node of the given viewport.
+    ///
+    /// Use it to query the widgets in that viewport, and to interact with them.
+    ///
+    /// # Panics
+    /// Panics if the viewport is not open.
+    #[track_caller]
+    pub fn viewport(&self, viewport_id: ViewportId) -> Node<'_> {
+        if viewport_id == ViewportId::ROOT {
+            return self.root();
+        }
+        let vp = self.viewports.get(&viewport_id).unwrap_or_else(|| {
+            panic!(
+                "The viewport {viewport_id:?} is not open. \
+                Did you call HarnessBuilder::with_embed_viewports(false)?"
+            )
+        });
+        Node {
+            accesskit_node: vp.kittest.root(),
+            queue: &vp.queued_events,
+        }
+    }
+
+    /// The simulated state of the native window of the given viewport.
+    pub fn viewport_info(&self, viewport_id: ViewportId) -> Option<&ViewportInfo> {
+        if viewport_id == ViewportId::ROOT {
+            self.input.viewports.get(&viewport_id)
+        } else {
+            self.viewports.get(&viewport_id).map(|vp| &vp.info)
+        }
+    }
+
+    /// Access the [`egui::FullOutput`] of the last pass of the given viewport.
+    pub fn viewport_output(&self, viewport_id: ViewportId) -> Option<&FullOutput> {
+        if viewport_id == ViewportId::ROOT {
+            Some(&self.output)
+        } else {
+            self.viewports.get(&viewport_id).map(|vp| &vp.output)
+        }
+    }
+
+    /// Queue an event to be processed by the given viewport in the next frame.
+    pub fn viewport_event(&self, viewport_id: ViewportId, event: egui::Event) {
+        if viewport_id == ViewportId::ROOT {
+            self.event(event);
+        } else if let Some(vp) = self.viewports.get(&viewport_id) {
+            vp.queued_events.lock().push(EventType::Event(event));
+        }
+    }
+
+    /// Simulate the user closing the native window of the given viewport,
+    /// e.g. by clicking its close button.
+    ///
+    /// The viewport will see [`ViewportInfo::close_requested`] in the next frame.
+    pub fn close_viewport(&mut self, viewport_id: ViewportId) {
+        if let Some(info) = self.viewport_info_mut(viewport_id) {
+            info.events.push(ViewportEvent::Close);
+        }
+    }
+}
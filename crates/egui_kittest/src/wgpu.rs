@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use std::{iter::once, time::Duration};
 
-use egui::TexturesDelta;
+use egui::{TexturesDelta, emath::GuiRounding as _};
 use egui_wgpu::{RenderState, ScreenDescriptor, WgpuSetup, wgpu};
 use image::RgbaImage;
 
@@ -154,6 +154,15 @@ impl crate::TestRenderer for WgpuTestRenderer {
         &mut self,
         ctx: &egui::Context,
         output: &egui::FullOutput,
+    ) -> Result<RgbaImage, String> {
+        self.render_viewport(ctx, egui::ViewportId::ROOT, output)
+    }
+
+    fn render_viewport(
+        &mut self,
+        ctx: &egui::Context,
+        viewport_id: egui::ViewportId,
+        output: &egui::FullOutput,
     ) -> Result<RgbaImage, String> {
         let mut renderer = self.render_state.renderer.write();
 
@@ -164,13 +173,16 @@ impl crate::TestRenderer for WgpuTestRenderer {
                     label: Some("Egui Command Encoder"),
                 });
 
-        let size = ctx.content_rect().size() * ctx.pixels_per_point();
+        let (content_rect, pixels_per_point) = ctx.input_for(viewport_id, |i| {
+            (i.content_rect().round_ui(), i.pixels_per_point())
+        });
+        let size = content_rect.size() * pixels_per_point;
         let screen = ScreenDescriptor {
-            pixels_per_point: ctx.pixels_per_point(),
+            pixels_per_point,
             size_in_pixels: [size.x.round() as u32, size.y.round() as u32],
         };
 
-        let tessellated = ctx.tessellate(output.shapes.clone(), ctx.pixels_per_point());
+        let tessellated = ctx.tessellate(output.shapes.clone(), pixels_per_point);
 
         let user_buffers = renderer.update_buffers(
             &self.render_state.device,
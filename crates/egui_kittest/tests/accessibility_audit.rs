@@ -0,0 +1,147 @@
+use egui::{
+    Button, Color32, FocusScope, Id, Sense, Vec2,
+    accessibility_audit::{AuditIssueKind, AuditOptions, contrast_ratio},
+};
+use egui_kittest::Harness;
+
+fn kinds<State>(harness: &Harness<'_, State>, id: Id) -> Vec<AuditIssueKind> {
+    harness
+        .accessibility_audit()
+        .issues_for(id)
+        .map(|issue| issue.kind.clone())
+        .collect()
+}
+
+#[test]
+fn large_labelled_widgets_pass() {
+    let harness = Harness::new_ui(|ui| {
+        ui.spacing_mut().interact_size.y = 24.0;
+        ui.label("Some text");
+        _ = ui.button("Save");
+        ui.checkbox(&mut true, "Enabled");
+    });
+
+    harness.assert_accessible(&AuditOptions::default());
+}
+
+#[test]
+fn reports_missing_label_and_small_target() {
+    let harness = Harness::new_ui_state(
+        |ui, id| {
+            *id = ui.add(Button::new("")).id;
+        },
+        Id::NULL,
+    );
+
+    let kinds = kinds(&harness, *harness.state());
+    assert!(kinds.contains(&AuditIssueKind::MissingLabel), "{kinds:?}");
+    assert!(
+        kinds
+            .iter()
+            .any(|kind| matches!(kind, AuditIssueKind::SmallTarget { .. })),
+        "{kinds:?}"
+    );
+}
+
+#[test]
+fn reports_not_focusable() {
+    let harness = Harness::new_ui_state(
+        |ui, id| {
+            let (rect, response) = ui.allocate_exact_size(Vec2::splat(32.0), Sense::CLICK);
+            response.widget_info(|| {
+                egui::WidgetInfo::labeled(egui::WidgetType::Button, true, "Custom")
+            });
+            ui.painter().rect_filled(rect, 0.0, Color32::GRAY);
+            *id = response.id;
+        },
+        Id::NULL,
+    );
+
+    assert_eq!(
+        kinds(&harness, *harness.state()),
+        vec![AuditIssueKind::NotFocusable]
+    );
+}
+
+#[test]
+fn reports_widgets_skipped_by_tab() {
+    let harness = Harness::new_ui_state(
+        |ui, ids| {
+            ui.spacing_mut().interact_size.y = 24.0;
+            let negative = ui.button("Negative tab index").with_tab_index(-1);
+            let skipped = ui
+                .focus_scope(FocusScope::new(Id::new("skip")).skip(true), |ui| {
+                    ui.button("In skipped scope")
+                })
+                .inner;
+            let roving = ui
+                .focus_scope(FocusScope::new(Id::new("roving")).roving(true), |ui| {
+                    _ = ui.button("Tab stop");
+                    ui.button("Reached with arrows")
+                })
+                .inner;
+            *ids = [negative.id, skipped.id, roving.id];
+        },
+        [Id::NULL; 3],
+    );
+    let [negative, skipped, roving] = *harness.state();
+
+    assert_eq!(
+        kinds(&harness, negative),
+        vec![AuditIssueKind::NotFocusable]
+    );
+    assert_eq!(kinds(&harness, skipped), vec![AuditIssueKind::NotFocusable]);
+    assert_eq!(kinds(&harness, roving), vec![]);
+}
+
+#[test]
+fn reports_overlap() {
+    let harness = Harness::new_ui_state(
+        |ui, ids| {
+            let first = ui.put(
+                egui::Rect::from_min_size(egui::pos2(10.0, 10.0), Vec2::splat(40.0)),
+                Button::new("First"),
+            );
+            let second = ui.put(
+                egui::Rect::from_min_size(egui::pos2(30.0, 30.0), Vec2::splat(40.0)),
+                Button::new("Second"),
+            );
+            *ids = [first.id, second.id];
+        },
+        [Id::NULL; 2],
+    );
+    let ids = *harness.state();
+
+    assert_eq!(kinds(&harness, ids[0]), vec![]);
+    assert_eq!(
+        kinds(&harness, ids[1]),
+        vec![AuditIssueKind::Overlapping { other: ids[0] }]
+    );
+}
+
+#[test]
+fn reports_low_contrast() {
+    let harness = Harness::new_ui(|ui| {
+        ui.ctx().global_style_mut(|style| {
+            style.visuals.override_text_color = Some(Color32::from_gray(40));
+            style.visuals.panel_fill = Color32::from_gray(30);
+        });
+        ui.label("Hard to read");
+    });
+
+    let report = harness.accessibility_audit();
+    assert!(
+        report
+            .issues
+            .iter()
+            .any(|issue| matches!(issue.kind, AuditIssueKind::LowContrast { .. })),
+        "{report}"
+    );
+}
+
+#[test]
+fn contrast() {
+    assert!((contrast_ratio(Color32::BLACK, Color32::WHITE) - 21.0).abs() < 0.01);
+    assert!((contrast_ratio(Color32::WHITE, Color32::WHITE) - 1.0).abs() < 0.01);
+    assert!((contrast_ratio(Color32::from_gray(118), Color32::WHITE) - 4.5).abs() < 0.1);
+}
@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use egui::Id;
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[test]
+fn tooltip_after_hover_delay() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.label("Hover me").on_hover_text("Tooltip");
+    });
+
+    harness.get_by_label("Hover me").hover();
+    let start = harness.time();
+    harness.run_until(|harness| harness.query_by_label("Tooltip").is_some());
+
+    let tooltip_delay = harness.ctx.global_style().interaction.tooltip_delay;
+    assert!(f64::from(tooltip_delay) <= harness.time() - start);
+}
+
+#[test]
+fn fast_forward_animation() {
+    let mut harness = Harness::new_ui_state(
+        |ui, open| {
+            let openness = ui.ctx().animate_bool_with_time(Id::new("anim"), *open, 2.0);
+            ui.label(format!("{openness:.1}"));
+        },
+        false,
+    );
+    harness.assert_no_pending_animations();
+
+    *harness.state_mut() = true;
+    harness.step();
+    assert!(harness.is_animating());
+
+    // 0.25s of the first step, plus another second:
+    harness.advance_time(Duration::from_secs(1));
+    harness.get_by_label("0.6");
+
+    harness.advance_time(Duration::from_secs(5));
+    harness.get_by_label("1.0");
+    harness.step();
+    harness.assert_no_pending_animations();
+}
+
+#[test]
+fn skip_to_scheduled_repaint() {
+    let mut harness = Harness::new_ui_state(
+        |ui, frames| {
+            *frames += 1;
+            ui.ctx().request_repaint_after(Duration::from_secs(10));
+        },
+        0,
+    );
+
+    let frames = *harness.state();
+    let time = harness.time();
+    let delay = harness.next_repaint().unwrap();
+    assert!(Duration::from_secs(9) < delay && delay <= Duration::from_secs(10));
+    assert_eq!(harness.skip_to_next_repaint(), Some(delay));
+    assert_eq!(*harness.state(), frames + 1);
+    assert_eq!(harness.time(), time + delay.as_secs_f64());
+}
@@ -0,0 +1,144 @@
+use egui::{FocusScope, Id, Key, Modifiers, Popup};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+fn assert_focused(harness: &Harness<'_>, label: &str) {
+    assert!(
+        harness.get_by_label(label).is_focused(),
+        "Expected {label:?} to be focused"
+    );
+}
+
+fn tab(harness: &mut Harness<'_>) {
+    harness.key_press(Key::Tab);
+    harness.run();
+}
+
+#[test]
+fn tab_index_changes_order() {
+    let mut harness = Harness::new_ui(|ui| {
+        _ = ui.button("A");
+        _ = ui.button("B").with_tab_index(2);
+        _ = ui.button("C").with_tab_index(1);
+        _ = ui.button("Skipped").with_tab_index(-1);
+    });
+
+    tab(&mut harness);
+    assert_focused(&harness, "C");
+    tab(&mut harness);
+    assert_focused(&harness, "B");
+    tab(&mut harness);
+    assert_focused(&harness, "A");
+    tab(&mut harness);
+    assert_focused(&harness, "C");
+
+    harness.key_press_modifiers(Modifiers::SHIFT, Key::Tab);
+    harness.run();
+    assert_focused(&harness, "A");
+}
+
+#[test]
+fn tab_moves_focus_in_the_next_pass() {
+    let mut harness = Harness::new_ui(|ui| {
+        _ = ui.button("A");
+        _ = ui.button("B");
+    });
+    tab(&mut harness);
+    assert_focused(&harness, "A");
+
+    // The tab order is only known at the end of the pass that handles the key press:
+    harness.key_down(Key::Tab);
+    harness.step();
+    assert_focused(&harness, "A");
+    assert!(
+        harness.ctx.has_requested_repaint(),
+        "egui should ask for the pass that moves the focus"
+    );
+    harness.step();
+    assert_focused(&harness, "B");
+    harness.key_up(Key::Tab);
+    harness.run();
+}
+
+#[test]
+fn roving_scope_is_one_tab_stop() {
+    let mut harness = Harness::new_ui(|ui| {
+        _ = ui.button("Before");
+        ui.focus_scope(FocusScope::new(Id::new("toolbar")).roving(true), |ui| {
+            ui.horizontal(|ui| {
+                _ = ui.button("Cut");
+                _ = ui.button("Copy");
+                _ = ui.button("Paste");
+            });
+        });
+        _ = ui.button("After");
+    });
+
+    tab(&mut harness);
+    tab(&mut harness);
+    assert_focused(&harness, "Cut");
+
+    harness.key_press(Key::ArrowRight);
+    harness.run();
+    assert_focused(&harness, "Copy");
+
+    tab(&mut harness);
+    assert_focused(&harness, "After");
+
+    // Tabbing back in returns to the last focused widget of the scope:
+    harness.key_press_modifiers(Modifiers::SHIFT, Key::Tab);
+    harness.run();
+    assert_focused(&harness, "Copy");
+
+    // Arrow keys wrap around within the scope:
+    harness.key_press(Key::ArrowLeft);
+    harness.run();
+    harness.key_press(Key::ArrowLeft);
+    harness.run();
+    assert_focused(&harness, "Paste");
+}
+
+#[test]
+fn trap_and_skip_scopes() {
+    let mut harness = Harness::new_ui(|ui| {
+        _ = ui.button("Outside");
+        ui.focus_scope(FocusScope::new(Id::new("skipped")).skip(true), |ui| {
+            _ = ui.button("Skipped");
+        });
+        ui.focus_scope(FocusScope::new(Id::new("dialog")).trap(true), |ui| {
+            _ = ui.button("Ok");
+            _ = ui.button("Cancel");
+        });
+    });
+
+    tab(&mut harness);
+    assert_focused(&harness, "Outside");
+    tab(&mut harness);
+    assert_focused(&harness, "Ok");
+    tab(&mut harness);
+    assert_focused(&harness, "Cancel");
+    tab(&mut harness);
+    assert_focused(&harness, "Ok");
+}
+
+#[test]
+fn focus_returns_when_popup_closes() {
+    let mut harness = Harness::new_ui(|ui| {
+        let response = ui.button("Open");
+        Popup::from_toggle_button_response(&response).show(|ui| {
+            _ = ui.button("Inside");
+        });
+    });
+
+    tab(&mut harness);
+    assert_focused(&harness, "Open");
+
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert!(harness.query_by_label("Inside").is_some());
+
+    // Escape clears the focus and closes the popup:
+    harness.key_press(Key::Escape);
+    harness.run();
+    assert!(harness.query_by_label("Inside").is_none());
+    assert_focused(&harness, "Open");
+}
@@ -0,0 +1,98 @@
+use egui_kittest::{Harness, Monkey, MonkeyAction, MonkeyFailureKind, kittest::Queryable as _};
+
+#[test]
+fn robust_ui_survives() {
+    Monkey::new(1).with_steps(50).run(|| {
+        Harness::new_ui_state(
+            |ui, (checked, text)| {
+                _ = ui.button("Button");
+                ui.checkbox(checked, "Check");
+                ui.text_edit_singleline(text);
+                ui.collapsing("Collapsing", |ui| {
+                    ui.label("Inside");
+                });
+            },
+            (false, String::new()),
+        )
+    });
+}
+
+#[test]
+fn finds_and_minimizes_panic() {
+    let make_harness = || {
+        Harness::new_ui_state(
+            |ui, clicks| {
+                ui.label("Some text");
+                ui.text_edit_singleline(&mut String::new());
+                if ui.button("Boom").clicked() {
+                    *clicks += 1;
+                    assert!(*clicks < 2, "Clicked twice");
+                }
+            },
+            0,
+        )
+    };
+
+    let failure = Monkey::new(7)
+        .with_steps(200)
+        .try_run(make_harness)
+        .expect_err("Should find the panic");
+    assert_eq!(
+        failure.kind,
+        MonkeyFailureKind::Panic("Clicked twice".to_owned())
+    );
+    assert!(failure.actions.len() <= failure.num_actions_run);
+    // Two clicks (or short drags) on the button:
+    assert_eq!(failure.actions.len(), 2, "{failure}");
+    let failure = failure.to_string();
+    assert!(failure.contains("Monkey::new(7).replay("), "{failure}");
+    assert!(failure.ends_with(".unwrap();"), "{failure}");
+}
+
+#[test]
+fn finds_repaint_loop() {
+    let failure = Monkey::new(3)
+        .with_steps(200)
+        .try_run(|| {
+            Harness::new_ui_state(
+                |ui, checked| {
+                    ui.checkbox(checked, "Spin");
+                    if *checked {
+                        ui.ctx().request_repaint();
+                    }
+                },
+                false,
+            )
+        })
+        .expect_err("Should find the repaint loop");
+    assert!(
+        matches!(failure.kind, MonkeyFailureKind::RepaintLoop { .. }),
+        "{failure}"
+    );
+    assert_eq!(failure.actions.len(), 1, "{failure}");
+}
+
+#[test]
+fn replay_is_deterministic() {
+    let mut harness = Harness::new_ui_state(
+        |ui, checked| {
+            ui.checkbox(checked, "Check");
+        },
+        false,
+    );
+    let pos = harness.get_by_label("Check").rect().center();
+
+    Monkey::new(0)
+        .replay(
+            &mut harness,
+            &[
+                MonkeyAction::Click {
+                    pos,
+                    button: egui::PointerButton::Primary,
+                },
+                MonkeyAction::Wait { seconds: 1.0 },
+            ],
+        )
+        .unwrap();
+    assert!(harness.state());
+}
@@ -0,0 +1,98 @@
+use egui::input_recording::{InputRecorder, InputRecording};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+fn app(ui: &mut egui::Ui, clicks: &mut usize) {
+    if ui.button("Click me").clicked() {
+        *clicks += 1;
+        ui.ctx()
+            .with_plugin(|recorder: &mut InputRecorder| recorder.mark(format!("click_{clicks}")));
+    }
+    ui.label(format!("Clicks: {clicks}"));
+}
+
+fn record() -> InputRecording {
+    let mut harness = Harness::new_ui_state(app, 0);
+    harness.ctx.add_plugin(InputRecorder::default());
+
+    harness.get_by_label("Click me").click();
+    harness.run();
+    harness.get_by_label("Click me").click();
+    harness.run();
+    assert_eq!(*harness.state(), 2, "Both clicks should be recorded");
+
+    harness
+        .ctx
+        .plugin::<InputRecorder>()
+        .lock()
+        .take_recording()
+}
+
+#[test]
+fn replay_reproduces_state() {
+    let recording = record();
+    assert!(!recording.is_empty());
+
+    let mut harness = Harness::new_ui_state(app, 0);
+    let mut markers = Vec::new();
+    harness.replay_with(&recording, |harness, marker| {
+        markers.push((marker.to_owned(), *harness.state()));
+    });
+
+    assert_eq!(*harness.state(), 2);
+    assert_eq!(
+        markers,
+        vec![("click_1".to_owned(), 1), ("click_2".to_owned(), 2)]
+    );
+    harness.get_by_label("Clicks: 2");
+}
+
+#[test]
+fn unchanged_viewports_are_not_repeated() {
+    let recording = record();
+
+    let stored = recording
+        .frames
+        .iter()
+        .filter(|frame| !frame.input.viewports.is_empty())
+        .count();
+    assert!(stored < recording.len());
+    assert!(
+        recording
+            .inputs()
+            .all(|(input, _)| !input.viewports.is_empty())
+    );
+}
+
+#[test]
+fn replay_follows_recorded_timestamps() {
+    let recording = record();
+    let first_time = recording
+        .inputs()
+        .find_map(|(input, _)| input.time)
+        .expect("Should have timestamps");
+    let marked_times: Vec<f64> = recording
+        .inputs()
+        .filter(|(_, markers)| !markers.is_empty())
+        .filter_map(|(input, _)| input.time)
+        .collect();
+
+    let mut harness = Harness::builder().with_step_dt(0.25).build_ui_state(app, 0);
+    let start = harness.time();
+    let mut replayed_times = Vec::new();
+    harness.replay_with(&recording, |harness, _| {
+        replayed_times.push(harness.time());
+    });
+
+    // The first frame is one step after the last one, then the recorded spacing is kept:
+    let expected: Vec<f64> = marked_times
+        .iter()
+        .map(|time| start + 0.25 + (time - first_time))
+        .collect();
+    assert_eq!(replayed_times.len(), expected.len());
+    for (replayed, expected) in replayed_times.iter().zip(&expected) {
+        assert!(
+            (replayed - expected).abs() < 1e-9,
+            "Replayed at {replayed}, expected {expected}"
+        );
+    }
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "snapshot")]
+
+use egui_kittest::{SnapshotError, SnapshotOptions, SnapshotReport, try_image_snapshot_options};
+use image::{Rgba, RgbaImage};
+
+#[test]
+fn report_and_accept() {
+    let dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("snapshot_report");
+    std::fs::remove_dir_all(&dir).ok();
+    let report_dir = dir.join("report");
+    let options = SnapshotOptions::new()
+        .output_path(dir.join("snapshots"))
+        .report_path(&report_dir)
+        .threshold(0.0);
+
+    let red = RgbaImage::from_pixel(4, 4, Rgba([255, 0, 0, 255]));
+    let blue = RgbaImage::from_pixel(4, 4, Rgba([0, 0, 255, 255]));
+    std::fs::create_dir_all(dir.join("snapshots")).unwrap();
+    red.save(dir.join("snapshots/square.png")).unwrap();
+
+    assert!(try_image_snapshot_options(&red, "square", &options).is_ok());
+    assert!(
+        !report_dir.join("index.html").exists(),
+        "Nothing failed yet"
+    );
+
+    let result = try_image_snapshot_options(&blue, "square", &options);
+    assert!(matches!(result, Err(SnapshotError::Diff { diff: 16, .. })));
+    assert!(try_image_snapshot_options(&blue, "missing", &options).is_err());
+
+    let mut report = SnapshotReport::load(&report_dir).unwrap();
+    assert_eq!(report.entries.len(), 2);
+    let square = &report.entries[1];
+    assert_eq!(square.name, "square");
+    assert_eq!(square.num_wrong_pixels, Some(16));
+    assert_eq!(square.threshold, 0.0);
+    assert!(square.diff_path.is_some());
+    assert_eq!(report.entries[0].num_wrong_pixels, None);
+
+    let html = std::fs::read_to_string(report.html_path()).unwrap();
+    assert!(html.contains("square.diff.png"));
+    assert!(html.contains("2 failing snapshot(s)"));
+
+    report.accept(&["square"]).unwrap();
+    assert_eq!(report.entries.len(), 1);
+    assert!(try_image_snapshot_options(&blue, "square", &options).is_ok());
+    assert_eq!(SnapshotReport::load(&report_dir).unwrap().entries.len(), 1);
+
+    // Fixing a snapshot removes it from the report:
+    blue.save(dir.join("snapshots/missing.png")).unwrap();
+    assert!(try_image_snapshot_options(&blue, "missing", &options).is_ok());
+    let report = SnapshotReport::load(&report_dir).unwrap();
+    assert!(report.entries.is_empty());
+    assert!(
+        std::fs::read_to_string(report.html_path())
+            .unwrap()
+            .contains("All snapshots passed")
+    );
+}
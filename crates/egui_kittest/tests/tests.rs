@@ -214,3 +214,22 @@ fn test_remove_cursor() {
         "The button appearance should change"
     );
 }
+
+#[cfg(feature = "snapshot")]
+#[test]
+fn test_accesskit_snapshot() {
+    let mut harness = Harness::new_ui_state(
+        |ui, checked| {
+            ui.label("Settings");
+            ui.checkbox(checked, "Enable");
+            ui.add_enabled(*checked, egui::Button::new("Apply"));
+        },
+        false,
+    );
+
+    harness.accesskit_snapshot("test_accesskit_snapshot_disabled");
+
+    harness.get_by_label("Enable").click();
+    harness.run();
+    harness.accesskit_snapshot("test_accesskit_snapshot_enabled");
+}
@@ -0,0 +1,133 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use egui::{Vec2, ViewportBuilder, ViewportCommand, ViewportId};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[test]
+fn deferred_viewport() {
+    let clicks = Arc::new(AtomicUsize::new(0));
+    let viewport_id = ViewportId::from_hash_of("deferred");
+
+    let mut harness = Harness::builder()
+        .with_embed_viewports(false)
+        .build_ui(|ui| {
+            ui.label("Root");
+            let clicks = Arc::clone(&clicks);
+            ui.ctx().show_viewport_deferred(
+                viewport_id,
+                ViewportBuilder::default()
+                    .with_title("Deferred")
+                    .with_inner_size([200.0, 100.0]),
+                move |ui, _class| {
+                    if ui.button("Click me").clicked() {
+                        clicks.fetch_add(1, Ordering::Relaxed);
+                    }
+                },
+            );
+        });
+
+    assert_eq!(
+        harness.viewport_ids().collect::<Vec<_>>(),
+        vec![viewport_id]
+    );
+
+    // Each viewport has its own tree:
+    assert!(harness.query_by_label("Click me").is_none());
+    harness
+        .viewport(viewport_id)
+        .get_by_label("Click me")
+        .click();
+    harness.run();
+    assert_eq!(clicks.load(Ordering::Relaxed), 1);
+
+    let info = harness.viewport_info(viewport_id).unwrap();
+    assert_eq!(info.title.as_deref(), Some("Deferred"));
+    assert_eq!(info.inner_rect.unwrap().size(), Vec2::new(200.0, 100.0));
+}
+
+#[test]
+fn immediate_viewport_commands_and_close() {
+    let viewport_id = ViewportId::from_hash_of("immediate");
+
+    let mut harness = Harness::builder()
+        .with_embed_viewports(false)
+        .build_ui_state(
+            |ui, open| {
+                if !*open {
+                    return;
+                }
+                ui.ctx().show_viewport_immediate(
+                    viewport_id,
+                    ViewportBuilder::default(),
+                    |ui, _| {
+                        if ui.button("Grow").clicked() {
+                            ui.send_viewport_cmd(ViewportCommand::InnerSize(Vec2::new(
+                                300.0, 200.0,
+                            )));
+                            ui.send_viewport_cmd(ViewportCommand::Title("Grown".to_owned()));
+                        }
+                        if ui.input(|i| i.viewport().close_requested()) {
+                            *open = false;
+                        }
+                    },
+                );
+            },
+            true,
+        );
+
+    harness.viewport(viewport_id).get_by_label("Grow").click();
+    harness.run();
+
+    let info = harness.viewport_info(viewport_id).unwrap();
+    assert_eq!(info.title.as_deref(), Some("Grown"));
+    assert_eq!(info.inner_rect.unwrap().size(), Vec2::new(300.0, 200.0));
+    let content_rect = harness.ctx.input_for(viewport_id, |i| i.content_rect());
+    assert_eq!(content_rect.size(), Vec2::new(300.0, 200.0));
+
+    harness.close_viewport(viewport_id);
+    harness.run();
+    assert!(!harness.state());
+    assert!(!harness.has_viewport(viewport_id));
+}
+
+#[test]
+fn embedded_by_default() {
+    let harness = Harness::new_ui(|ui| {
+        ui.ctx().show_viewport_immediate(
+            ViewportId::from_hash_of("embedded"),
+            ViewportBuilder::default().with_title("Embedded"),
+            |ui, _| {
+                ui.label("Inside");
+            },
+        );
+    });
+
+    assert_eq!(harness.viewport_ids().count(), 0);
+    harness.get_by_label("Inside");
+}
+
+#[test]
+fn render_viewport() {
+    let viewport_id = ViewportId::from_hash_of("rendered");
+
+    let mut harness = Harness::builder()
+        .with_size([400.0, 300.0])
+        .with_embed_viewports(false)
+        .build_ui(|ui| {
+            ui.ctx().show_viewport_immediate(
+                viewport_id,
+                ViewportBuilder::default().with_inner_size([120.0, 80.0]),
+                |ui, _| {
+                    ui.label("Hello");
+                },
+            );
+        });
+
+    let image = harness.render_viewport(viewport_id).unwrap();
+    assert_eq!(image.dimensions(), (120, 80));
+    let image = harness.render().unwrap();
+    assert_eq!(image.dimensions(), (400, 300));
+}
@@ -0,0 +1,51 @@
+use egui::{Color32, Event, OutputCommand, RichText, vec2};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[test]
+fn copying_labels_includes_html() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(300.0, 200.0))
+        .build_ui(|ui| {
+            ui.label(RichText::new("Hello").color(Color32::RED).italics());
+            ui.label("Fish & chips");
+        });
+    harness.run();
+
+    // Select both labels:
+    let start = harness.get_by_label("Hello").rect().left_center() + vec2(1.0, 0.0);
+    let end = harness.get_by_label("Fish & chips").rect().right_center();
+    harness.drag_at(start);
+    harness.step();
+    harness.hover_at(end);
+    harness.step();
+    harness.drop_at(end);
+    harness.step();
+
+    harness.event(Event::Copy);
+    harness.step();
+
+    let commands = &harness.output().platform_output.commands;
+    let [OutputCommand::CopyRich { text, html }] = &commands[..] else {
+        panic!("Should copy text together with html: {commands:?}");
+    };
+    assert_eq!(text, "Hello\nFish & chips");
+
+    assert!(
+        html.contains("color:#ff0000ff") && html.contains("font-style:italic"),
+        "Should keep the style: {html}"
+    );
+    assert!(
+        html.contains(">Hello</span><br>\n<span"),
+        "Should keep the line break: {html}"
+    );
+    assert!(html.contains("Fish &amp; chips"), "Should escape: {html}");
+
+    let default_colored = html
+        .split("<span")
+        .find(|span| span.contains("Fish"))
+        .expect("Should have a span for the second label");
+    assert!(
+        !default_colored.contains("color:"),
+        "The default text color should be left to the app we paste into: {html}"
+    );
+}
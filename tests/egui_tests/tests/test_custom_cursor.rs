@@ -0,0 +1,48 @@
+use egui::{Color32, ColorImage, CursorIcon, CursorImage, Id, vec2};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+fn cursor_image() -> CursorImage {
+    CursorImage::new(ColorImage::filled([16, 16], Color32::RED), [8, 8])
+        .with_fallback(CursorIcon::Crosshair)
+}
+
+#[test]
+fn custom_cursor_is_sent_to_the_integration() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(200.0, 100.0))
+        .build_ui(|ui| {
+            let cursor = ui.ctx().custom_cursor(Id::new("red"), cursor_image());
+            _ = ui.button("Custom").on_hover_cursor(cursor);
+            _ = ui.button("Normal");
+        });
+    harness.run();
+
+    let output = harness.output();
+    assert_eq!(output.platform_output.cursor_icon, CursorIcon::Default);
+    assert!(
+        output.platform_output.cursor_image.is_none(),
+        "No custom cursor when not hovered"
+    );
+
+    harness.get_by_label("Custom").hover();
+    harness.run();
+
+    let output = harness.output();
+    assert_eq!(
+        output.platform_output.cursor_icon,
+        CursorIcon::Custom(Id::new("red"))
+    );
+    let image = output
+        .platform_output
+        .cursor_image
+        .clone()
+        .expect("Should send the cursor image");
+    assert_eq!(*image, cursor_image());
+
+    harness.get_by_label("Normal").hover();
+    harness.run();
+    assert!(
+        harness.output().platform_output.cursor_image.is_none(),
+        "Should stop sending the cursor image"
+    );
+}
@@ -0,0 +1,147 @@
+use egui::{DroppedFile, Event, FileDrop, FileFilter, Frame, HoveredFile, Pos2, vec2};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[derive(Default)]
+struct Zones {
+    images: Option<FileDrop>,
+    dropped_anything: Vec<DroppedFile>,
+}
+
+fn harness() -> Harness<'static, Zones> {
+    Harness::builder()
+        .with_size(vec2(400.0, 300.0))
+        .build_ui_state(
+            |ui, zones: &mut Zones| {
+                let response = ui.button("Images");
+                zones.images = response.file_drop_target(&FileFilter::any().mime_type("image/*"));
+
+                let (_, dropped) = ui.file_drop_zone(Frame::new(), &FileFilter::any(), |ui| {
+                    ui.label("Anything");
+                });
+                zones.dropped_anything.extend(dropped);
+            },
+            Zones::default(),
+        )
+}
+
+fn hover_files(harness: &mut Harness<'_, Zones>, pos: Pos2, files: &[(&str, &str)]) {
+    harness.hover_at(pos);
+    harness.input_mut().hovered_files = files
+        .iter()
+        .map(|(name, mime)| HoveredFile {
+            path: Some(name.into()),
+            mime: (*mime).to_owned(),
+        })
+        .collect();
+    // Hovered files keep egui repainting:
+    harness.run_steps(2);
+}
+
+fn drop_files(harness: &mut Harness<'_, Zones>, files: &[(&str, &str)]) {
+    harness.input_mut().hovered_files.clear();
+    harness.input_mut().dropped_files = files
+        .iter()
+        .map(|(name, mime)| DroppedFile {
+            name: (*name).to_owned(),
+            mime: (*mime).to_owned(),
+            ..Default::default()
+        })
+        .collect();
+    harness.step();
+}
+
+#[test]
+fn files_go_to_the_widget_under_the_pointer() {
+    let mut harness = harness();
+    harness.run();
+    let images = harness.get_by_label("Images").rect().center();
+
+    hover_files(
+        &mut harness,
+        images,
+        &[("cat.png", "image/png"), ("notes.txt", "text/plain")],
+    );
+    let drop = harness.state().images.clone().expect("Should be hovered");
+    assert!(drop.can_accept(), "Should accept the image");
+    assert_eq!(drop.hovered.len(), 1);
+    assert_eq!(drop.rejected, 1);
+
+    drop_files(
+        &mut harness,
+        &[("cat.png", "image/png"), ("notes.txt", "text/plain")],
+    );
+    let drop = harness
+        .state()
+        .images
+        .clone()
+        .expect("Should be dropped on");
+    assert_eq!(drop.dropped.len(), 1);
+    assert_eq!(drop.dropped[0].name, "cat.png");
+    assert!(
+        harness.state().dropped_anything.is_empty(),
+        "The other zone is not under the pointer"
+    );
+}
+
+#[test]
+fn rejected_files() {
+    let mut harness = harness();
+    harness.run();
+    let images = harness.get_by_label("Images").rect().center();
+
+    hover_files(&mut harness, images, &[("notes.txt", "text/plain")]);
+    let drop = harness.state().images.clone().expect("Should be hovered");
+    assert!(drop.is_rejected(), "Should reject the text file");
+
+    drop_files(&mut harness, &[("notes.txt", "text/plain")]);
+    let drop = harness
+        .state()
+        .images
+        .clone()
+        .expect("Should be dropped on");
+    assert!(drop.dropped.is_empty(), "Should not receive the text file");
+}
+
+#[test]
+fn drop_zone_receives_files() {
+    let mut harness = harness();
+    harness.run();
+    let anything = harness.get_by_label("Anything").rect().center();
+
+    hover_files(&mut harness, anything, &[("notes.txt", "")]);
+    assert_eq!(harness.state().images, None, "Not over the images");
+
+    drop_files(&mut harness, &[("notes.txt", "")]);
+    assert_eq!(harness.state().dropped_anything.len(), 1);
+}
+
+#[test]
+fn files_go_to_the_last_known_pointer_position() {
+    let mut harness = harness();
+    harness.run();
+    let images = harness.get_by_label("Images").rect().center();
+
+    // winit doesn't send `CursorMoved` while files are dragged over the window,
+    // and may report the pointer as gone:
+    hover_files(&mut harness, images, &[("cat.png", "image/png")]);
+    harness.event(Event::PointerGone);
+    harness.run_steps(2);
+    let drop = harness
+        .state()
+        .images
+        .clone()
+        .expect("Should still be hovered");
+    assert!(drop.can_accept(), "Should accept the image");
+
+    drop_files(&mut harness, &[("cat.png", "image/png")]);
+    let drop = harness
+        .state()
+        .images
+        .clone()
+        .expect("Should be dropped on");
+    assert_eq!(drop.dropped.len(), 1);
+    assert!(
+        harness.state().dropped_anything.is_empty(),
+        "The other zone was not under the pointer"
+    );
+}
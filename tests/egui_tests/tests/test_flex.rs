@@ -0,0 +1,216 @@
+use egui::{Button, Flex, FlexAlign, FlexItem, FlexJustify, Rect};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+fn button_rect<State>(harness: &Harness<'_, State>, label: &str) -> Rect {
+    harness.get_by_label(label).rect()
+}
+
+#[test]
+fn grow_fills_the_line() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(400.0, 100.0))
+        .build_ui(|ui| {
+            Flex::horizontal().show(ui, |flex| {
+                flex.add(FlexItem::new(), Button::new("Left"));
+                flex.add(FlexItem::new().grow(1.0), Button::new("Middle"));
+                flex.add(FlexItem::new(), Button::new("Right"));
+            });
+        });
+    harness.run();
+
+    let left = button_rect(&harness, "Left");
+    let middle = button_rect(&harness, "Middle");
+    let right = button_rect(&harness, "Right");
+    let available = harness.ctx.content_rect().shrink(8.0);
+    assert!(
+        (right.right() - available.right()).abs() < 1.0,
+        "Right should be at the edge: {right:?}"
+    );
+    assert!(left.right() < middle.left(), "Items should not overlap");
+    assert!(middle.right() < right.left(), "Items should not overlap");
+    assert!(
+        left.width() < middle.width(),
+        "The middle button should grow: {middle:?}"
+    );
+}
+
+#[test]
+fn items_wrap_to_new_lines() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(200.0, 300.0))
+        .build_ui(|ui| {
+            Flex::horizontal().wrap(true).show(ui, |flex| {
+                for i in 0..10 {
+                    flex.add(FlexItem::new(), Button::new(format!("Button {i}")));
+                }
+            });
+        });
+    harness.run();
+
+    let first = button_rect(&harness, "Button 0");
+    let last = button_rect(&harness, "Button 9");
+    assert!(first.bottom() < last.top(), "Items should wrap");
+    for i in 0..10 {
+        let rect = button_rect(&harness, &format!("Button {i}"));
+        assert!(rect.right() <= 192.5, "Button {i} should fit: {rect:?}");
+    }
+}
+
+#[test]
+fn justify_space_between() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(400.0, 100.0))
+        .build_ui(|ui| {
+            Flex::horizontal()
+                .justify(FlexJustify::SpaceBetween)
+                .show(ui, |flex| {
+                    flex.add(FlexItem::new(), Button::new("A"));
+                    flex.add(FlexItem::new(), Button::new("B"));
+                    flex.add(FlexItem::new(), Button::new("C"));
+                });
+        });
+    harness.run();
+
+    let a = button_rect(&harness, "A");
+    let b = button_rect(&harness, "B");
+    let c = button_rect(&harness, "C");
+    assert!((a.left() - 8.0).abs() < 1.0, "A should be at the start");
+    assert!((c.right() - 392.0).abs() < 1.0, "C should be at the end");
+    let gap_ab = b.left() - a.right();
+    let gap_bc = c.left() - b.right();
+    assert!((gap_ab - gap_bc).abs() < 1.0, "Gaps should be equal");
+}
+
+#[test]
+fn first_frame_is_already_laid_out() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(400.0, 100.0))
+        .build_ui(|ui| {
+            Flex::horizontal()
+                .justify(FlexJustify::End)
+                .show(ui, |flex| {
+                    flex.add(FlexItem::new(), Button::new("Ok"));
+                });
+        });
+    harness.step();
+    let first = button_rect(&harness, "Ok");
+    harness.step();
+    let second = button_rect(&harness, "Ok");
+    assert_eq!(first, second, "The layout should not jitter");
+    assert!(
+        (first.right() - 392.0).abs() < 1.0,
+        "Ok should be at the end"
+    );
+}
+
+#[test]
+fn items_shrink_in_proportion() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(300.0, 100.0))
+        .build_ui_state(
+            |ui, widths: &mut [f32; 2]| {
+                Flex::horizontal()
+                    .gap(egui::vec2(8.0, 8.0))
+                    .show(ui, |flex| {
+                        for (width, shrink) in widths.iter_mut().zip([1.0, 3.0]) {
+                            let item = FlexItem::new().basis(200.0).shrink(shrink);
+                            *width = flex.add_ui(item, |ui| ui.max_rect().width()).inner;
+                        }
+                    });
+            },
+            [0.0; 2],
+        );
+    harness.run();
+
+    // 284 points of space, 400 points of basis and 8 points of gap:
+    let overflow = 400.0 + 8.0 - 284.0;
+    let [first, second] = *harness.state();
+    assert!(
+        (first - (200.0 - overflow / 4.0)).abs() < 1.0,
+        "The first item should shrink by a quarter: {first}"
+    );
+    assert!(
+        (second - (200.0 - overflow * 3.0 / 4.0)).abs() < 1.0,
+        "The second item should shrink by three quarters: {second}"
+    );
+}
+
+#[test]
+fn align_items_across_the_line() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(400.0, 200.0))
+        .build_ui(|ui| {
+            Flex::horizontal()
+                .align_items(FlexAlign::End)
+                .show(ui, |flex| {
+                    flex.add_ui(FlexItem::new(), |ui| {
+                        ui.allocate_space(egui::vec2(50.0, 100.0));
+                    });
+                    flex.add(FlexItem::new(), Button::new("End"));
+                    flex.add(
+                        FlexItem::new().align_self(FlexAlign::Start),
+                        Button::new("Start"),
+                    );
+                    flex.add(
+                        FlexItem::new().align_self(FlexAlign::Stretch),
+                        Button::new("Stretch"),
+                    );
+                });
+        });
+    harness.run();
+
+    let top = 8.0;
+    let bottom = top + 100.0;
+    let end = button_rect(&harness, "End");
+    let start = button_rect(&harness, "Start");
+    let stretch = button_rect(&harness, "Stretch");
+    assert!(
+        (end.bottom() - bottom).abs() < 1.0,
+        "End should be at the bottom of the line: {end:?}"
+    );
+    assert!(
+        (start.top() - top).abs() < 1.0,
+        "Start should be at the top of the line: {start:?}"
+    );
+    assert!(
+        (stretch.top() - top).abs() < 1.0 && (stretch.bottom() - bottom).abs() < 1.0,
+        "Stretch should fill the line: {stretch:?}"
+    );
+}
+
+#[test]
+fn growing_item_is_measured_again_when_its_content_changes() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(300.0, 200.0))
+        .build_ui_state(
+            |ui, text: &mut String| {
+                Flex::horizontal().wrap(true).show(ui, |flex| {
+                    flex.add(FlexItem::new().grow(1.0), Button::new(text.as_str()));
+                    flex.add(FlexItem::new(), Button::new("Next"));
+                });
+            },
+            "A button that is too wide to share the line".to_owned(),
+        );
+    harness.run();
+
+    let text = harness.state().clone();
+    let next = button_rect(&harness, "Next");
+    assert!(
+        button_rect(&harness, &text).bottom() < next.top(),
+        "Next should wrap to the second line"
+    );
+
+    *harness.state_mut() = "Short".to_owned();
+    harness.run();
+
+    let short = button_rect(&harness, "Short");
+    let next = button_rect(&harness, "Next");
+    assert!(
+        (short.top() - next.top()).abs() < 1.0,
+        "Next should be back on the first line: {short:?} {next:?}"
+    );
+    assert!(
+        (next.right() - 292.0).abs() < 1.0,
+        "Short should grow to fill the line: {short:?} {next:?}"
+    );
+}
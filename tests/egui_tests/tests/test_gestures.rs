@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use egui::{Direction, Pos2, Sense, Vec2, pos2, vec2};
+use egui_kittest::Harness;
+
+#[derive(Default)]
+struct Gestures {
+    clicked: bool,
+    long_pressed: bool,
+    double_tap_dragged: bool,
+    swipe: Option<Direction>,
+    fling: Option<Vec2>,
+}
+
+fn harness() -> Harness<'static, Gestures> {
+    Harness::builder()
+        .with_size(vec2(400.0, 300.0))
+        .with_step_dt(1.0 / 60.0)
+        .build_ui_state(
+            |ui, gestures: &mut Gestures| {
+                let response = ui.allocate_response(ui.available_size(), Sense::click_and_drag());
+                gestures.clicked |= response.clicked();
+                gestures.long_pressed |= response.long_pressed();
+                gestures.double_tap_dragged |= response.double_tap_dragged();
+                if let Some(direction) = response.swipe_direction() {
+                    gestures.swipe = Some(direction);
+                }
+                if let Some(velocity) = response.fling_velocity() {
+                    gestures.fling = Some(velocity);
+                }
+            },
+            Gestures::default(),
+        )
+}
+
+fn drag_through(harness: &mut Harness<'_, Gestures>, points: impl IntoIterator<Item = Pos2>) {
+    for pos in points {
+        harness.hover_at(pos);
+        harness.step();
+    }
+}
+
+fn long_press_then_release(harness: &mut Harness<'_, Gestures>) {
+    harness.drag_at(pos2(100.0, 100.0));
+    harness.run_steps(3);
+
+    let delay = harness
+        .skip_to_next_repaint()
+        .expect("Should wake up for the long-press");
+    assert!(
+        delay <= Duration::from_secs_f32(0.5),
+        "Woke up too late: {delay:?}"
+    );
+    harness.step();
+    assert!(harness.state().long_pressed, "Should be long-pressed");
+
+    harness.drop_at(pos2(100.0, 100.0));
+    harness.step();
+}
+
+#[test]
+fn long_press_still_clicks() {
+    let mut harness = harness();
+    long_press_then_release(&mut harness);
+    assert!(
+        harness.state().clicked,
+        "Holding a button for a while should still click it"
+    );
+}
+
+#[test]
+fn long_press_cancels_click_when_enabled() {
+    let mut harness = harness();
+    harness.ctx.all_styles_mut(|style| {
+        style.interaction.long_press_cancels_click = true;
+    });
+    long_press_then_release(&mut harness);
+    assert!(
+        !harness.state().clicked,
+        "A long-press should not also click"
+    );
+}
+
+#[test]
+fn quick_release_is_a_click_not_a_long_press() {
+    let mut harness = harness();
+    harness.drag_at(pos2(100.0, 100.0));
+    harness.drop_at(pos2(100.0, 100.0));
+    harness.step();
+    harness.advance_time(Duration::from_secs(1));
+
+    assert!(harness.state().clicked, "Should be clicked");
+    assert!(!harness.state().long_pressed, "Should not be long-pressed");
+}
+
+#[test]
+fn fast_drag_is_a_swipe_and_fling() {
+    let mut harness = harness();
+    harness.drag_at(pos2(50.0, 100.0));
+    harness.step();
+    drag_through(
+        &mut harness,
+        (1..=10).map(|i| pos2(50.0 + 20.0 * i as f32, 105.0)),
+    );
+    harness.drop_at(pos2(250.0, 105.0));
+    harness.step();
+
+    assert_eq!(harness.state().swipe, Some(Direction::LeftToRight));
+    let fling = harness.state().fling.expect("Should be flung");
+    assert!(0.0 < fling.x, "Should fling to the right: {fling:?}");
+}
+
+#[test]
+fn slow_drag_is_not_a_swipe() {
+    let mut harness = harness();
+    harness.drag_at(pos2(50.0, 100.0));
+    harness.step();
+    drag_through(
+        &mut harness,
+        (1..=10).map(|i| pos2(50.0 + 20.0 * i as f32, 100.0)),
+    );
+    // Stop and wait before letting go:
+    harness.advance_time(Duration::from_secs(1));
+    harness.drop_at(pos2(250.0, 100.0));
+    harness.step();
+
+    assert_eq!(harness.state().swipe, None, "A slow release is not a swipe");
+    assert_eq!(harness.state().fling, None, "A slow release is not a fling");
+}
+
+#[test]
+fn double_tap_and_drag() {
+    let mut harness = harness();
+    harness.drag_at(pos2(100.0, 100.0));
+    harness.drop_at(pos2(100.0, 100.0));
+    harness.step();
+    assert!(harness.state().clicked, "The first tap should click");
+
+    harness.drag_at(pos2(100.0, 100.0));
+    harness.step();
+    drag_through(
+        &mut harness,
+        (1..=5).map(|i| pos2(100.0, 100.0 + 10.0 * i as f32)),
+    );
+    assert!(
+        harness.state().double_tap_dragged,
+        "Should be double-tap-dragged"
+    );
+}
+
+#[test]
+fn single_tap_and_drag_is_a_normal_drag() {
+    let mut harness = harness();
+    harness.drag_at(pos2(100.0, 100.0));
+    harness.step();
+    drag_through(
+        &mut harness,
+        (1..=5).map(|i| pos2(100.0, 100.0 + 10.0 * i as f32)),
+    );
+    assert!(
+        !harness.state().double_tap_dragged,
+        "Should not be double-tap-dragged"
+    );
+}
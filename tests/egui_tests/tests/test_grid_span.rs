@@ -0,0 +1,130 @@
+use egui::{Grid, Rect, Shape};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+fn label_rect(harness: &Harness<'_>, label: &str) -> Rect {
+    harness
+        .get_all_by_value(label)
+        .next()
+        .expect("Label should be shown")
+        .rect()
+}
+
+#[test]
+fn col_span_covers_columns() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(600.0, 200.0))
+        .build_ui(|ui| {
+            Grid::new("grid").show(ui, |ui| {
+                ui.label("a");
+                ui.label("b");
+                ui.label("c");
+                ui.end_row();
+
+                ui.grid_span(2, 1);
+                ui.label("wide");
+                ui.label("after");
+                ui.end_row();
+            });
+        });
+    harness.run();
+
+    let c = label_rect(&harness, "c");
+    let after = label_rect(&harness, "after");
+    assert!(
+        (c.left() - after.left()).abs() < 1.0,
+        "The cell after the span should be in the third column: {c:?} {after:?}"
+    );
+}
+
+#[test]
+fn wide_span_grows_columns_evenly() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(800.0, 200.0))
+        .build_ui(|ui| {
+            Grid::new("grid").show(ui, |ui| {
+                ui.grid_span(2, 1);
+                ui.label("A very long section header that is wider than both columns");
+                ui.end_row();
+
+                ui.label("a");
+                ui.label("b");
+                ui.end_row();
+            });
+        });
+    harness.run();
+
+    let header = label_rect(
+        &harness,
+        "A very long section header that is wider than both columns",
+    );
+    let a = label_rect(&harness, "a");
+    let b = label_rect(&harness, "b");
+    let spacing = harness.ctx.global_style().spacing.item_spacing.x;
+    let first_col_width = b.left() - a.left() - spacing;
+    assert!(
+        (2.0 * first_col_width + spacing - header.width()).abs() < 2.0,
+        "The header should be split evenly over the columns: {first_col_width} {header:?}"
+    );
+}
+
+#[test]
+fn row_span_is_skipped_in_later_rows() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(600.0, 300.0))
+        .build_ui(|ui| {
+            Grid::new("grid").striped(true).show(ui, |ui| {
+                ui.grid_span(1, 2);
+                ui.label("tall\ntall\ntall\ntall\ntall");
+                ui.label("a");
+                ui.end_row();
+
+                ui.label("b");
+                ui.end_row();
+
+                ui.label("c");
+                ui.label("d");
+                ui.end_row();
+            });
+        });
+    harness.run();
+
+    let tall = label_rect(&harness, "tall\ntall\ntall\ntall\ntall");
+    let a = label_rect(&harness, "a");
+    let b = label_rect(&harness, "b");
+    let c = label_rect(&harness, "c");
+    assert!(
+        (a.left() - b.left()).abs() < 1.0,
+        "b should be in the second column: {a:?} {b:?}"
+    );
+    assert!(
+        tall.bottom() <= c.top(),
+        "The next row should be below the tall cell: {tall:?} {c:?}"
+    );
+
+    // Only the second row is striped, and not behind the tall cell that covers it:
+    let stripe_color = harness.ctx.global_style().visuals.faint_bg_color;
+    let stripes: Vec<Rect> = harness
+        .output()
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            Shape::Rect(rect) if rect.fill == stripe_color => Some(rect.rect),
+            _ => None,
+        })
+        .collect();
+    let [stripe] = stripes[..] else {
+        panic!("Expected a single stripe: {stripes:?}");
+    };
+    assert!(
+        tall.right() < stripe.left(),
+        "The stripe should not cover the tall cell: {stripe:?} {tall:?}"
+    );
+    assert!(
+        stripe.contains_rect(b),
+        "The stripe should be behind b: {stripe:?} {b:?}"
+    );
+    assert!(
+        !stripe.intersects(a) && !stripe.intersects(c),
+        "The other rows should not be striped: {stripe:?}"
+    );
+}
@@ -0,0 +1,113 @@
+use egui::{
+    DragValue, PlatformOutput, WidgetInfo, WidgetType,
+    i18n::{MessageArgs, MessageBundle},
+    output::OutputEvent,
+    style::NumberFormatter,
+};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+fn german() -> MessageBundle {
+    MessageBundle::parse(
+        "de",
+        r"
+egui-widget-button = Schaltfläche
+egui-widget-disabled = deaktiviert
+files = { $count ->
+    [one] Eine Datei
+   *[other] { $count } Dateien
+}
+",
+    )
+    .expect("Should parse")
+}
+
+fn english() -> MessageBundle {
+    MessageBundle::parse(
+        "en",
+        r"
+files = { $count ->
+    [one] One file
+   *[other] { $count } files
+}
+",
+    )
+    .expect("Should parse")
+}
+
+#[test]
+fn set_language_translates_and_formats_numbers() {
+    let mut harness = Harness::new_ui_state(
+        |ui, value: &mut f64| {
+            ui.label(
+                ui.ctx()
+                    .tr_args("files", &MessageArgs::new().with("count", 2000)),
+            );
+            ui.add(DragValue::new(value).max_decimals(1));
+        },
+        1234.5,
+    );
+    harness.ctx.add_messages(english());
+    harness.ctx.add_messages(german());
+    harness.run();
+
+    harness.get_by_label("2,000 files");
+    harness.get_by_value("1234.5");
+
+    harness.ctx.set_language("de-AT");
+    harness.run();
+
+    assert_eq!(harness.ctx.language(), "de-AT");
+    harness.get_by_label("2.000 Dateien");
+    harness.get_by_value("1.234,5");
+
+    let description = harness.ctx.localization(|l| {
+        WidgetInfo::labeled(WidgetType::Button, false, "OK").localized_description(l)
+    });
+    assert_eq!(description, "OK: Schaltfläche: deaktiviert");
+    let output = PlatformOutput {
+        events: vec![OutputEvent::Clicked(WidgetInfo::labeled(
+            WidgetType::Button,
+            true,
+            "OK",
+        ))],
+        ..Default::default()
+    };
+    assert_eq!(
+        output.events_description(&harness.ctx),
+        "OK: Schaltfläche",
+        "Should use the language of the context"
+    );
+    assert_eq!(
+        harness.ctx.tr("egui-window-close"),
+        "Close window",
+        "Should fall back to English"
+    );
+
+    harness.ctx.set_language("en");
+    harness.run();
+    harness.get_by_value("1234.5");
+    assert_eq!(
+        harness.ctx.global_style().number_formatter,
+        NumberFormatter::default(),
+        "Switching back to English should restore the default formatter"
+    );
+}
+
+#[test]
+fn set_language_keeps_custom_number_formatter() {
+    let mut harness = Harness::new_ui_state(
+        |ui, value: &mut f64| {
+            ui.add(DragValue::new(value));
+        },
+        1234.0,
+    );
+    let custom = NumberFormatter::new(|value, _| format!("#{value}"));
+    harness.ctx.all_styles_mut(|style| {
+        style.number_formatter = custom.clone();
+    });
+    harness.ctx.set_language("de");
+    harness.run();
+
+    harness.get_by_value("#1234");
+    assert_eq!(harness.ctx.global_style().number_formatter, custom);
+}
@@ -0,0 +1,199 @@
+use egui::{Event, PenInfo, PointerButton, PointerKind, Pos2, pos2};
+use egui_kittest::Harness;
+
+#[derive(Default)]
+struct Pointer {
+    kind: PointerKind,
+    pen: Option<PenInfo>,
+    pressure: f32,
+}
+
+fn harness() -> Harness<'static, Pointer> {
+    Harness::builder().build_ui_state(
+        |ui, pointer: &mut Pointer| {
+            ui.input(|i| {
+                pointer.kind = i.pointer.kind();
+                pointer.pen = i.pointer.pen();
+                pointer.pressure = i.pointer.pressure();
+            });
+        },
+        Pointer::default(),
+    )
+}
+
+fn pen(pressure: f32) -> PenInfo {
+    PenInfo {
+        pressure,
+        altitude_angle: Some(std::f32::consts::FRAC_PI_4),
+        azimuth_angle: Some(0.0),
+        eraser: true,
+        ..Default::default()
+    }
+}
+
+/// Send the events of one frame.
+fn frame(harness: &mut Harness<'_, Pointer>, events: impl IntoIterator<Item = Event>) {
+    harness.input_mut().events.extend(events);
+    harness.step();
+}
+
+fn press(pos: Pos2, pressed: bool) -> Event {
+    Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    }
+}
+
+#[test]
+fn pen_events_are_exposed() {
+    let mut harness = harness();
+    let pos = pos2(50.0, 50.0);
+
+    // Hovering:
+    frame(
+        &mut harness,
+        [
+            Event::Pen {
+                pos,
+                info: pen(0.0),
+            },
+            Event::PointerMoved(pos),
+        ],
+    );
+    assert_eq!(harness.state().kind, PointerKind::Pen);
+    assert_eq!(
+        harness.state().pressure,
+        0.0,
+        "A hovering pen has no pressure"
+    );
+
+    // Touching:
+    frame(
+        &mut harness,
+        [
+            Event::Pen {
+                pos,
+                info: pen(0.7),
+            },
+            press(pos, true),
+        ],
+    );
+    let info = harness.state().pen.expect("Should have pen info");
+    assert_eq!(info.pressure, 0.7);
+    assert!(info.eraser, "Should be the eraser end");
+    assert_eq!(harness.state().pressure, 0.7);
+    let tilt = info.tilt().expect("Should have a tilt");
+    assert!(
+        (tilt.x - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-4 && tilt.y.abs() < 1e-4,
+        "Tilted 45° to the right: {tilt:?}"
+    );
+
+    // Lifted and gone:
+    frame(
+        &mut harness,
+        [
+            Event::Pen {
+                pos,
+                info: pen(0.0),
+            },
+            press(pos, false),
+            Event::PointerGone,
+        ],
+    );
+    assert_eq!(harness.state().pen, None, "The pen left");
+}
+
+#[test]
+fn mouse_after_pen() {
+    let mut harness = harness();
+    let pos = pos2(50.0, 50.0);
+    frame(
+        &mut harness,
+        [
+            Event::Pen {
+                pos,
+                info: pen(0.5),
+            },
+            Event::PointerMoved(pos),
+        ],
+    );
+    assert_eq!(harness.state().kind, PointerKind::Pen);
+
+    frame(&mut harness, [Event::PointerMoved(pos2(60.0, 60.0))]);
+    assert_eq!(harness.state().kind, PointerKind::Mouse);
+    assert_eq!(harness.state().pen, None, "The mouse has no pen info");
+
+    frame(&mut harness, [press(pos, true)]);
+    assert_eq!(
+        harness.state().pressure,
+        1.0,
+        "A pressed mouse has full pressure"
+    );
+}
+
+#[test]
+fn mouse_and_pen_in_the_same_frame() {
+    let mut harness = harness();
+    let pen_pos = pos2(50.0, 50.0);
+    let mouse_pos = pos2(60.0, 60.0);
+
+    frame(
+        &mut harness,
+        [
+            Event::Pen {
+                pos: pen_pos,
+                info: pen(0.5),
+            },
+            Event::PointerMoved(pen_pos),
+            Event::PointerMoved(mouse_pos),
+        ],
+    );
+    assert_eq!(
+        harness.state().kind,
+        PointerKind::Mouse,
+        "The last pointer event came from the mouse"
+    );
+    assert_eq!(harness.state().pen, None, "The mouse has no pen info");
+
+    frame(
+        &mut harness,
+        [
+            Event::PointerMoved(mouse_pos),
+            Event::Pen {
+                pos: pen_pos,
+                info: pen(0.5),
+            },
+            press(pen_pos, true),
+        ],
+    );
+    assert_eq!(
+        harness.state().kind,
+        PointerKind::Pen,
+        "The last pointer event came from the pen"
+    );
+    assert_eq!(harness.state().pressure, 0.5);
+}
+
+#[test]
+fn touch_is_not_a_pen() {
+    let mut harness = harness();
+    let pos = pos2(50.0, 50.0);
+    frame(
+        &mut harness,
+        [
+            Event::Touch {
+                device_id: egui::TouchDeviceId(0),
+                id: egui::TouchId(0),
+                phase: egui::TouchPhase::Start,
+                pos,
+                force: None,
+            },
+            Event::PointerMoved(pos),
+            press(pos, true),
+        ],
+    );
+    assert_eq!(harness.state().kind, PointerKind::Touch);
+    assert_eq!(harness.state().pen, None);
+}
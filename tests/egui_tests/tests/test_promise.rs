@@ -0,0 +1,166 @@
+use std::{
+    rc::Rc,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    time::Duration,
+};
+
+use egui::Id;
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+fn wait_for_output<T>(harness: &mut Harness<'_, Option<T>>) {
+    for _ in 0..100 {
+        std::thread::sleep(Duration::from_millis(10));
+        harness.run();
+        if harness.state().is_some() {
+            break;
+        }
+    }
+}
+
+#[test]
+fn blocking_promise_repaints_when_done() {
+    let (sender, receiver) = mpsc::channel::<u32>();
+    let receiver = egui::mutex::Mutex::new(Some(receiver));
+    let mut harness = Harness::new_ui_state(
+        move |ui, output: &mut Option<Result<u32, String>>| {
+            // Only the first call starts the thread:
+            let receiver = receiver.lock().take();
+            *output = ui.ctx().promise_blocking(Id::new("answer"), move || {
+                let receiver = receiver.expect("Should only start once");
+                receiver.recv().unwrap_or_default()
+            });
+        },
+        None,
+    );
+    harness.run();
+    assert_eq!(*harness.state(), None, "Should still be running");
+
+    sender.send(42).expect("The thread should be waiting");
+    wait_for_output(&mut harness);
+    assert_eq!(*harness.state(), Some(Ok(42)));
+}
+
+struct DropGuard(Arc<AtomicBool>);
+
+impl Drop for DropGuard {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn promise_is_cancelled_when_not_shown() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let guard_dropped = Arc::clone(&dropped);
+    let mut harness = Harness::new_ui_state(
+        move |ui, show: &mut bool| {
+            if *show {
+                let guard_dropped = Arc::clone(&guard_dropped);
+                let output = ui.ctx().promise(Id::new("forever"), move || {
+                    let guard = DropGuard(guard_dropped);
+                    async move {
+                        let _guard = guard;
+                        std::future::pending::<()>().await;
+                    }
+                });
+                assert_eq!(output, None, "Should never finish");
+            }
+        },
+        true,
+    );
+    harness.run();
+    assert!(!dropped.load(Ordering::Relaxed), "Should still be running");
+
+    *harness.state_mut() = false;
+    harness.run();
+    assert!(dropped.load(Ordering::Relaxed), "Should be cancelled");
+}
+
+#[test]
+fn promise_is_cancelled_when_the_id_is_reused_with_another_type() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let guard_dropped = Arc::clone(&dropped);
+    let mut harness = Harness::new_ui_state(
+        move |ui, number: &mut Option<i32>| {
+            let id = Id::new("promise");
+            if number.is_none() {
+                let guard_dropped = Arc::clone(&guard_dropped);
+                let output = ui.ctx().promise(id, move || {
+                    let guard = DropGuard(guard_dropped);
+                    async move {
+                        let _guard = guard;
+                        std::future::pending::<()>().await;
+                    }
+                });
+                assert_eq!(output, None, "Should never finish");
+            } else {
+                *number = ui.ctx().promise(id, || async { 42 });
+            }
+        },
+        None,
+    );
+    harness.run();
+    assert!(!dropped.load(Ordering::Relaxed), "Should still be running");
+
+    *harness.state_mut() = Some(0);
+    harness.run();
+    assert!(dropped.load(Ordering::Relaxed), "Should be cancelled");
+    assert_eq!(*harness.state(), Some(42));
+}
+
+#[test]
+fn async_value_shows_output_or_error() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.async_value(
+            "ok",
+            || async { Ok::<_, String>("Loaded".to_owned()) },
+            |ui, text| ui.label(text),
+        );
+        ui.async_value(
+            "err",
+            || async { Err::<String, _>("Failed to load".to_owned()) },
+            |ui, text| ui.label(text),
+        );
+    });
+    harness.run();
+
+    harness.get_by_label("Loaded");
+    harness.get_by_label("Failed to load");
+}
+
+#[test]
+fn blocking_promise_fails_when_the_thread_panics() {
+    let mut harness = Harness::new_ui_state(
+        |ui, output: &mut Option<Result<u32, String>>| {
+            *output = ui
+                .ctx()
+                .promise_blocking(Id::new("panics"), || panic!("Failed to compute"));
+        },
+        None,
+    );
+    harness.run();
+    wait_for_output(&mut harness);
+    assert!(
+        matches!(harness.state(), Some(Err(_))),
+        "Should fail instead of running forever"
+    );
+}
+
+#[test]
+fn local_promise_runs_futures_that_are_not_send() {
+    let mut harness = Harness::new_ui_state(
+        |ui, output: &mut Option<usize>| {
+            *output = ui.ctx().promise_local(Id::new("local"), || {
+                let not_send = Rc::new("Hello".to_owned());
+                async move { not_send.len() }
+            });
+        },
+        None,
+    );
+    harness.run();
+    assert_eq!(*harness.state(), Some(5));
+}
@@ -0,0 +1,144 @@
+use egui::{Key, Modifiers, Pos2, ReorderMove, ReorderableList, vec2};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[derive(Default)]
+struct Board {
+    columns: [Vec<&'static str>; 2],
+    moved: Option<ReorderMove>,
+}
+
+fn harness(columns: [Vec<&'static str>; 2]) -> Harness<'static, Board> {
+    Harness::builder()
+        .with_size(vec2(400.0, 300.0))
+        .with_step_dt(1.0 / 60.0)
+        .build_ui_state(
+            |ui, board: &mut Board| {
+                let [left, right] = &mut board.columns;
+                let moved = ui.columns(2, |uis| {
+                    let a = ReorderableList::new("left").group("board").show(
+                        &mut uis[0],
+                        left,
+                        |item| *item,
+                        |ui, item| {
+                            ui.label(*item);
+                        },
+                    );
+                    let b = ReorderableList::new("right").group("board").show(
+                        &mut uis[1],
+                        right,
+                        |item| *item,
+                        |ui, item| {
+                            ui.label(*item);
+                        },
+                    );
+                    a.moved.or(b.moved)
+                });
+
+                if let Some(moved) = moved {
+                    if !moved.is_within_list() {
+                        if moved.from_list == egui::Id::new("left") {
+                            moved.apply_between(left, right);
+                        } else {
+                            moved.apply_between(right, left);
+                        }
+                    }
+                    board.moved = Some(moved);
+                }
+            },
+            Board {
+                columns,
+                moved: None,
+            },
+        )
+}
+
+fn center(harness: &Harness<'_, Board>, label: &str) -> Pos2 {
+    harness.get_by_label(label).rect().center()
+}
+
+/// Drag with the primary button from `from` to `to`, moving in small steps.
+fn drag(harness: &mut Harness<'_, Board>, from: Pos2, to: Pos2) {
+    harness.drag_at(from);
+    harness.step();
+    for i in 1..=10 {
+        harness.hover_at(from + (to - from) * (i as f32 / 10.0));
+        harness.step();
+    }
+    harness.drop_at(to);
+    harness.step();
+    harness.run_ok();
+}
+
+#[test]
+fn drag_to_reorder_within_list() {
+    let mut harness = harness([vec!["A", "B", "C"], vec![]]);
+    harness.run();
+
+    let from = center(&harness, "A");
+    let to = center(&harness, "C") + vec2(0.0, 4.0);
+    drag(&mut harness, from, to);
+
+    assert_eq!(harness.state().columns[0], ["B", "C", "A"]);
+    assert_eq!(
+        harness.state().moved,
+        Some(ReorderMove {
+            from_list: egui::Id::new("left"),
+            from_index: 0,
+            to_list: egui::Id::new("left"),
+            to_index: 2,
+        })
+    );
+}
+
+#[test]
+fn drag_between_lists() {
+    let mut harness = harness([vec!["A", "B"], vec!["C", "D"]]);
+    harness.run();
+
+    let from = center(&harness, "B");
+    let to = center(&harness, "C") + vec2(0.0, 4.0);
+    drag(&mut harness, from, to);
+
+    assert_eq!(harness.state().columns[0], ["A"]);
+    assert_eq!(harness.state().columns[1], ["C", "B", "D"]);
+}
+
+#[test]
+fn dropped_outside_does_not_move() {
+    let mut harness = harness([vec!["A", "B"], vec![]]);
+    harness.run();
+
+    let from = center(&harness, "A");
+    drag(&mut harness, from, from + vec2(0.0, 200.0));
+
+    assert_eq!(harness.state().columns[0], ["A", "B"]);
+    assert_eq!(harness.state().moved, None);
+}
+
+#[test]
+fn keyboard_reorder() {
+    let mut harness = harness([vec!["A", "B", "C"], vec![]]);
+    harness.run();
+
+    // Click to focus:
+    let pos = center(&harness, "A");
+    harness.drag_at(pos);
+    harness.drop_at(pos);
+    harness.run();
+
+    harness.key_press_modifiers(Modifiers::ALT, Key::ArrowDown);
+    harness.run();
+    assert_eq!(harness.state().columns[0], ["B", "A", "C"]);
+
+    harness.key_press_modifiers(Modifiers::ALT, Key::ArrowDown);
+    harness.run();
+    assert_eq!(
+        harness.state().columns[0],
+        ["B", "C", "A"],
+        "The moved item should keep focus"
+    );
+
+    harness.key_press_modifiers(Modifiers::ALT, Key::ArrowUp);
+    harness.run();
+    assert_eq!(harness.state().columns[0], ["B", "A", "C"]);
+}
@@ -0,0 +1,134 @@
+use egui::ScrollArea;
+use egui_extras::{Column, TableBuilder};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+struct Items {
+    ids: Vec<u64>,
+    scroll_offset: Option<f32>,
+}
+
+fn items() -> Items {
+    Items {
+        ids: (100..200).collect(),
+        scroll_offset: Some(300.0),
+    }
+}
+
+fn item_top(harness: &Harness<'_, Items>, id: u64) -> f32 {
+    harness
+        .get_all_by_value(&format!("Item {id}"))
+        .next()
+        .expect("Item should be shown")
+        .rect()
+        .top()
+}
+
+/// Insert items at the top, and check that the item in view stays where it was.
+fn prepend_and_check(mut harness: Harness<'_, Items>) {
+    harness.run();
+    let id = 118;
+    let before = item_top(&harness, id);
+
+    harness.state_mut().ids.splice(0..0, 0..10);
+    harness.run();
+    let after = item_top(&harness, id);
+    assert!(
+        (after - before).abs() < 1.0,
+        "Item moved from {before} to {after}"
+    );
+}
+
+#[test]
+fn scroll_area_keeps_item_in_view() {
+    let harness = Harness::builder()
+        .with_size(egui::vec2(300.0, 200.0))
+        .with_max_steps(16)
+        .build_ui_state(
+            |ui, state: &mut Items| {
+                let mut scroll_area = ScrollArea::vertical().scroll_anchoring(true);
+                if let Some(offset) = state.scroll_offset.take() {
+                    scroll_area = scroll_area.vertical_scroll_offset(offset);
+                }
+                scroll_area.show(ui, |ui| {
+                    for id in &state.ids {
+                        ui.push_id(id, |ui| ui.label(format!("Item {id}")));
+                    }
+                });
+            },
+            items(),
+        );
+    prepend_and_check(harness);
+}
+
+#[test]
+fn table_keeps_row_in_view() {
+    let harness = Harness::builder()
+        .with_size(egui::vec2(300.0, 200.0))
+        .with_max_steps(16)
+        .build_ui_state(
+            |ui, state: &mut Items| {
+                let ids = &state.ids;
+                let mut table = TableBuilder::new(ui)
+                    .column(Column::remainder())
+                    .scroll_anchoring(true)
+                    .row_key(|row_index| ids[row_index]);
+                if let Some(offset) = state.scroll_offset.take() {
+                    table = table.vertical_scroll_offset(offset);
+                }
+                table.body(|body| {
+                    body.rows(20.0, ids.len(), |mut row| {
+                        let id = ids[row.index()];
+                        row.col(|ui| {
+                            ui.label(format!("Item {id}"));
+                        });
+                    });
+                });
+            },
+            items(),
+        );
+    prepend_and_check(harness);
+}
+
+#[test]
+fn table_does_not_look_up_every_row_key() {
+    const NUM_ROWS: usize = 1_000_000;
+
+    let key_lookups = std::cell::Cell::new(0);
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(300.0, 200.0))
+        .with_max_steps(16)
+        .build_ui_state(
+            |ui, scroll_offset: &mut Option<f32>| {
+                let mut table = TableBuilder::new(ui)
+                    .column(Column::remainder())
+                    .scroll_anchoring(true)
+                    .row_key(|row_index| {
+                        key_lookups.set(key_lookups.get() + 1);
+                        row_index
+                    });
+                if let Some(offset) = scroll_offset.take() {
+                    table = table.vertical_scroll_offset(offset);
+                }
+                table.body(|body| {
+                    body.rows(20.0, NUM_ROWS, |mut row| {
+                        let index = row.index();
+                        row.col(|ui| {
+                            ui.label(format!("Row {index}"));
+                        });
+                    });
+                });
+            },
+            Some(500_000.0),
+        );
+    harness.run();
+
+    // Jump to the top, so the anchor row is no longer shown:
+    *harness.state_mut() = Some(0.0);
+    key_lookups.set(0);
+    harness.step();
+    assert!(
+        key_lookups.get() < 1000,
+        "Looked up {} row keys in one frame",
+        key_lookups.get()
+    );
+}
@@ -0,0 +1,148 @@
+use egui::{Align, ScrollArea, VirtualList};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+struct Rows {
+    /// Unique id and number of lines of each row.
+    rows: Vec<(u64, usize)>,
+    scroll_to: Option<usize>,
+    shown: std::ops::Range<usize>,
+}
+
+fn rows(count: u64) -> Rows {
+    Rows {
+        rows: (0..count).map(|id| (id, 1 + id as usize % 4)).collect(),
+        scroll_to: None,
+        shown: 0..0,
+    }
+}
+
+fn harness(rows: Rows) -> Harness<'static, Rows> {
+    harness_with_scroll_area(rows, ScrollArea::vertical())
+}
+
+fn harness_with_scroll_area(rows: Rows, scroll_area: ScrollArea) -> Harness<'static, Rows> {
+    Harness::builder()
+        .with_size(egui::vec2(300.0, 200.0))
+        .with_max_steps(16)
+        .build_ui_state(
+            move |ui, state: &mut Rows| {
+                let mut list = VirtualList::new().scroll_area(scroll_area.clone());
+                if let Some(index) = state.scroll_to.take() {
+                    list = list.scroll_to_item(index, Some(Align::Min));
+                }
+                let rows = &state.rows;
+                state.shown = list
+                    .show(
+                        ui,
+                        rows.len(),
+                        |index| rows[index].0,
+                        |ui, index| {
+                            let (id, lines) = rows[index];
+                            ui.label(format!("Row {id}{}", "\nmore".repeat(lines - 1)));
+                        },
+                    )
+                    .inner;
+            },
+            rows,
+        )
+}
+
+fn row_top(harness: &Harness<'_, Rows>, id: u64) -> f32 {
+    harness
+        .get_all_by_value(&format!("Row {id}{}", "\nmore".repeat(id as usize % 4)))
+        .next()
+        .expect("Row should be shown")
+        .rect()
+        .top()
+}
+
+#[test]
+fn only_visible_rows_are_shown() {
+    let mut harness = harness(rows(10_000));
+    harness.run();
+
+    let shown = harness.state().shown.clone();
+    assert_eq!(shown.start, 0);
+    assert!(shown.len() < 20, "{shown:?}");
+    assert!(harness.query_all_by_value("Row 0").next().is_some());
+    assert!(harness.query_all_by_value("Row 5000").next().is_none());
+}
+
+#[test]
+fn scroll_to_item() {
+    let mut harness = harness(rows(10_000));
+    harness.run();
+
+    harness.state_mut().scroll_to = Some(5000);
+    harness.run();
+
+    assert!(harness.state().shown.contains(&5000));
+    let top = row_top(&harness, 5000);
+    assert!(
+        (0.0..20.0).contains(&top),
+        "Row 5000 should be at the top, but is at {top}"
+    );
+}
+
+#[test]
+fn rows_stay_in_place() {
+    check_rows_stay_in_place(harness(rows(1000)));
+}
+
+#[test]
+fn rows_stay_in_place_with_scroll_anchoring() {
+    // The list keeps rows in place itself, so this shouldn't correct the offset twice:
+    check_rows_stay_in_place(harness_with_scroll_area(
+        rows(1000),
+        ScrollArea::vertical().scroll_anchoring(true),
+    ));
+}
+
+fn check_rows_stay_in_place(mut harness: Harness<'_, Rows>) {
+    harness.run();
+    harness.state_mut().scroll_to = Some(500);
+    harness.run();
+    let top = row_top(&harness, 500);
+
+    // Scrolling back up measures rows above, which corrects the estimate:
+    harness.state_mut().scroll_to = Some(450);
+    harness.run();
+    harness.state_mut().scroll_to = Some(500);
+    harness.run();
+    assert!(
+        (row_top(&harness, 500) - top).abs() < 1.0,
+        "Measuring rows above should not move the visible rows"
+    );
+
+    // Inserting rows above doesn't move the visible rows:
+    harness
+        .state_mut()
+        .rows
+        .splice(0..0, (1000..1100).map(|id| (id, 4)));
+    harness.run();
+    assert!(
+        (row_top(&harness, 500) - top).abs() < 1.0,
+        "Inserting rows above should not move the visible rows"
+    );
+}
+
+#[test]
+fn rows_below_move_when_a_row_grows() {
+    let mut harness = harness(rows(1000));
+    harness.run();
+    let top = row_top(&harness, 2);
+
+    harness.state_mut().rows[1].1 = 4;
+    harness.run();
+    assert!(
+        top + 20.0 < row_top(&harness, 2),
+        "The row below should move down by two more lines"
+    );
+
+    harness.state_mut().rows[1].1 = 2;
+    harness.run();
+    assert!(
+        (row_top(&harness, 2) - top).abs() < 1.0,
+        "The row below should move back when the row shrinks"
+    );
+}